@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the approaching behavior of a ship's captain.
+///
+/// The `Approach` component is used to manage the approaching action of a captain in the game. The
+/// ship closes with the target until it is within broadside range, sailing as close to the wind as
+/// it must to reach it.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct Approach;
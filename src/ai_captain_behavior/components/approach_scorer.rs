@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating a captain's need to close with the target.
+///
+/// The `ApproachScorer` component is used to calculate a score from the captain's assessment of the
+/// engagement. The score is higher when the ship holds the weather gage, and lower the more damaged
+/// the ship is. It falls to zero once the target is within broadside range.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct ApproachScorer;
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the boarding behavior of a ship's captain.
+///
+/// The `Board` component is used to manage the boarding action of a captain in the game. The ship
+/// steers straight at the target under full canvas and lays the ship alongside it to board.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct Board;
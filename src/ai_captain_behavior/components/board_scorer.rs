@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating a captain's need to board the target.
+///
+/// The `BoardScorer` component is used to calculate a score from the captain's assessment of the
+/// engagement. The score is high once the ship is close to the target and outnumbers its crew, and
+/// higher the more damaged the target is.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct BoardScorer;
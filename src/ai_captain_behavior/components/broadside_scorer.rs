@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating a captain's need to hold broadside position.
+///
+/// The `BroadsideScorer` component is used to calculate a score from the captain's assessment of
+/// the engagement. The score is high while the target is within broadside range, and higher the
+/// less damaged the ship is.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct BroadsideScorer;
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the captain commanding a ship that is not sailed by the player.
+///
+/// The `Captain` component is added to a ship alongside the `Thinker` that decides how it
+/// fights. It holds the captain's assessment of the engagement with the nearest other ship,
+/// which the captain's scorers weigh up, and the orders the chosen action gives the helm and
+/// the sails.
+///
+/// # Fields
+/// - `target`: The ship being engaged, or `None` when no other ship is in sight.
+/// - `distance`: The distance to the target, in metres.
+/// - `bearing`: The horizontal unit direction from the ship to the target.
+/// - `weather_gage`: How far upwind of the target the ship is, from `-1.0` (dead downwind)
+///   to `1.0` (dead upwind).
+/// - `health`: How sound the ship is, from `0.0` (wrecked) to `1.0` (undamaged and dry).
+/// - `target_health`: How sound the target is, from `0.0` (wrecked) to `1.0` (undamaged and dry).
+/// - `crew_ratio`: The number of crew aboard the ship for each crew member aboard the target.
+/// - `heading`: The horizontal direction the helm is ordered to steer, or `None` to hold the
+///   rudder amidships.
+/// - `furl`: How much of the sails are ordered to be furled, from `0.0` (fully set) to `1.0`
+///   (fully furled).
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Captain {
+    pub target: Option<Entity>,
+    pub distance: f32,
+    pub bearing: Vec3,
+    pub weather_gage: f32,
+    pub health: f32,
+    pub target_health: f32,
+    pub crew_ratio: f32,
+    pub heading: Option<Vec3>,
+    pub furl: f32,
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the fleeing behavior of a ship's captain.
+///
+/// The `Flee` component is used to manage the fleeing action of a captain in the game. The ship
+/// turns away from the target and sails off under full canvas until the target is out of sight.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct Flee;
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating a captain's need to flee from the target.
+///
+/// The `FleeScorer` component is used to calculate a score from the captain's assessment of the
+/// engagement. The score is high once the ship is badly damaged or its crew is heavily outnumbered.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct FleeScorer;
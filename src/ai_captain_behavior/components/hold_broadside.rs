@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the holding broadside position behavior of a ship's captain.
+///
+/// The `HoldBroadside` component is used to manage the holding broadside position action of a
+/// captain in the game. The ship sails across the line to the target, keeping a broadside turned
+/// towards it and firing the guns that bear.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct HoldBroadside;
@@ -0,0 +1,14 @@
+pub use {
+    approach::*, approach_scorer::*, board::*, board_scorer::*, broadside_scorer::*, captain::*,
+    flee::*, flee_scorer::*, hold_broadside::*,
+};
+
+mod approach;
+mod approach_scorer;
+mod board;
+mod board_scorer;
+mod broadside_scorer;
+mod captain;
+mod flee;
+mod flee_scorer;
+mod hold_broadside;
@@ -0,0 +1,30 @@
+use std::f32::consts::FRAC_PI_4;
+
+/// The distance in metres within which a captain sights another ship and engages it.
+pub const SIGHTING_RANGE: f32 = 400.0;
+
+/// The distance in metres within which a captain holds broadside position and opens fire.
+pub const BROADSIDE_RANGE: f32 = 80.0;
+
+/// The distance in metres within which a ship is close enough alongside the target to board it.
+pub const BOARDING_RANGE: f32 = 12.0;
+
+/// The smallest angle in radians between a ship's heading and the direction the wind blows
+/// from that the ship can sail at. Courses closer to the wind than this have to be tacked.
+pub const NO_GO_ANGLE: f32 = FRAC_PI_4;
+
+/// The half-angle in radians either side of a cannon's barrel within which its gun captain
+/// fires at the target.
+pub const FIRING_ARC: f32 = 0.1745; // 10 degrees
+
+/// The health below which a captain breaks off the engagement and flees.
+pub const FLEE_HEALTH: f32 = 0.4;
+
+/// The crew ratio below which a captain breaks off the engagement and flees.
+pub const FLEE_CREW_RATIO: f32 = 0.5;
+
+/// The crew ratio at which a captain is fully confident of carrying the target by boarding.
+pub const BOARDING_CREW_RATIO: f32 = 1.5;
+
+/// How much of the sails a captain furls to fight under, steadying the ship as a gun platform.
+pub const FIGHTING_SAIL_FURL: f32 = 0.5;
@@ -0,0 +1,3 @@
+pub use captain_settings::*;
+
+mod captain_settings;
@@ -0,0 +1,3 @@
+pub use ship_boarded::*;
+
+mod ship_boarded;
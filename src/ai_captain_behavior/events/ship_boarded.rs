@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Event sent when a ship is laid alongside another to board it.
+///
+/// This event is sent by the `board_action` system when a captain brings their ship within
+/// boarding range of the target.
+///
+/// # Fields
+/// - `boarder`: The ship whose crew are boarding.
+/// - `target`: The ship being boarded.
+#[derive(Event, Debug, Clone)]
+pub struct ShipBoarded {
+    pub boarder: Entity,
+    pub target: Entity,
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+pub use events::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod events;
+mod systems;
+mod utils;
+
+/// Plugin for managing AI captain behavior within the game.
+///
+/// The AiCaptainBehaviorPlugin provides functionality for handling how ships not sailed by the
+/// player fight. Each such ship is commanded by a captain, whose thinker weighs up the relative
+/// wind, the damage to both ships and the strength of their crews to decide whether to approach
+/// the enemy, hold broadside position, flee or board. It registers the necessary components and
+/// sets up systems to assess the engagement, score and carry out each choice, and steer the ship.
+///
+/// # Components
+/// - Approach: Manages the approaching action of a captain, closing with the target.
+/// - ApproachScorer: Calculates a score indicating the urgency for a captain to close with the target.
+/// - Board: Manages the boarding action of a captain, laying the ship alongside the target.
+/// - BoardScorer: Calculates a score indicating the urgency for a captain to board the target.
+/// - BroadsideScorer: Calculates a score indicating the urgency for a captain to hold broadside position.
+/// - Captain: Holds a captain's assessment of the engagement and the orders for the helm and sails.
+/// - Flee: Manages the fleeing action of a captain, sailing away from the target.
+/// - FleeScorer: Calculates a score indicating the urgency for a captain to flee.
+/// - HoldBroadside: Manages the broadside action of a captain, turning the guns on the target.
+///
+/// # Events
+/// - ShipBoarded: Reports a ship being laid alongside another to board it.
+///
+/// # Systems
+/// - approach_action: Steers the ship towards the target under full sail.
+/// - assess_engagement: Updates each captain's assessment of the engagement with the nearest ship.
+/// - board_action: Steers the ship alongside the target and boards it.
+/// - calculate_approach_score: Calculates a score based on the range, weather gage and damage.
+/// - calculate_board_score: Calculates a score based on the range, crew strength and the target's damage.
+/// - calculate_broadside_score: Calculates a score based on the range and damage.
+/// - calculate_flee_score: Calculates a score based on the damage and crew strength.
+/// - flee_action: Steers the ship away from the target under full sail.
+/// - helm_ship: Carries out the captain's orders at the helm and on the sails.
+/// - hold_broadside_action: Keeps a broadside turned towards the target and fires the guns that bear.
+pub struct AiCaptainBehaviorPlugin;
+
+impl Plugin for AiCaptainBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Approach>()
+            .register_type::<ApproachScorer>()
+            .register_type::<Board>()
+            .register_type::<BoardScorer>()
+            .register_type::<BroadsideScorer>()
+            .register_type::<Captain>()
+            .register_type::<Flee>()
+            .register_type::<FleeScorer>()
+            .register_type::<HoldBroadside>()
+            .add_event::<ShipBoarded>()
+            .add_systems(Update, approach_action.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                assess_engagement.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, board_action.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                calculate_approach_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                calculate_board_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                calculate_broadside_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                calculate_flee_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, flee_action.run_if(in_state(AppStates::Running)))
+            .add_systems(Update, helm_ship.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                hold_broadside_action.run_if(in_state(AppStates::Running)),
+            );
+    }
+}
@@ -0,0 +1,61 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Query, Res, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_captain_behavior::components::{Approach, Captain};
+use crate::ai_captain_behavior::constants::BROADSIDE_RANGE;
+use crate::ai_captain_behavior::utils::calculate_sailable_heading;
+use crate::wind::Wind;
+
+/// This system manages the approaching action of captains. The captain orders full sail and a
+/// heading towards the target, beating up to windward when the target lies in the eye of the
+/// wind, until the target is within broadside range.
+///
+/// # Parameters
+/// - `wind`: Resource describing the wind the ship has to sail in.
+/// - `captains`: Query to fetch and modify the `Captain` components of ships.
+/// - `query`: Query to fetch and modify the `ActionState` and `ActionSpan` components of
+///   entities with the `Approach` component, as well as the `Actor` component to identify
+///   the ship.
+pub fn approach_action(
+    wind: Res<Wind>,
+    mut captains: Query<&mut Captain>,
+    mut query: Query<(&Actor, &mut ActionState, &ActionSpan), With<Approach>>,
+) {
+    for (Actor(actor), mut state, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok(mut captain) = captains.get_mut(*actor) else {
+            continue;
+        };
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Closing with the enemy!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                if captain.target.is_none() {
+                    debug!("Lost sight of the enemy.");
+                    *state = ActionState::Failure;
+                    continue;
+                }
+
+                trace!("Approaching... Distance: {}", captain.distance);
+                captain.heading = Some(calculate_sailable_heading(captain.bearing, wind.direction));
+                captain.furl = 0.0;
+
+                if captain.distance <= BROADSIDE_RANGE {
+                    debug!("Enemy within range!");
+                    *state = ActionState::Success;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
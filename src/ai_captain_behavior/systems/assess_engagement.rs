@@ -0,0 +1,103 @@
+use bevy::prelude::{Entity, Query, Res, Transform, Vec3, With};
+
+use crate::ai_captain_behavior::components::Captain;
+use crate::ai_captain_behavior::constants::SIGHTING_RANGE;
+use crate::ai_captain_behavior::utils::{calculate_ship_health, calculate_weather_gage};
+use crate::buoyancy_physics::Buoyancy;
+use crate::crew_management::{CrewMember, ShipCrew};
+use crate::damage::HullIntegrity;
+use crate::flooding::Flooding;
+use crate::ship::Ship;
+use crate::wind::Wind;
+
+/// This system updates each captain's assessment of the engagement with the nearest other ship.
+///
+/// The nearest ship within sighting range becomes the captain's target. The captain then weighs
+/// up the distance and bearing to the target, which ship holds the weather gage, how sound both
+/// ships are and how many of their crew are still alive. A captain with no ship in sight has no
+/// target.
+///
+/// # Parameters
+/// - `wind`: Resource describing the wind, used to decide which ship holds the weather gage.
+/// - `crew`: Query to check which crew members are still alive.
+/// - `ships`: Query to retrieve the transforms, hull integrity, flooding, voxels and crew of ships.
+/// - `captains`: Query to fetch and modify the `Captain` components of ships.
+#[allow(clippy::type_complexity)]
+pub fn assess_engagement(
+    wind: Res<Wind>,
+    crew: Query<(), With<CrewMember>>,
+    ships: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&HullIntegrity>,
+            Option<&Flooding>,
+            Option<&Buoyancy>,
+            Option<&ShipCrew>,
+        ),
+        With<Ship>,
+    >,
+    mut captains: Query<(Entity, &mut Captain)>,
+) {
+    let ship_states: Vec<(Entity, Vec3, f32, f32)> = ships
+        .iter()
+        .map(
+            |(ship, transform, hull_integrity, flooding, buoyancy, ship_crew)| {
+                let fill_level = flooding
+                    .zip(buoyancy)
+                    .and_then(|(flooding, buoyancy)| flooding.fill_level(None, &buoyancy.voxels))
+                    .unwrap_or(0.0);
+                let crew_aboard = ship_crew
+                    .map(|ship_crew| {
+                        ship_crew
+                            .members
+                            .iter()
+                            .filter(|member| crew.contains(**member))
+                            .count()
+                    })
+                    .unwrap_or(0);
+
+                (
+                    ship,
+                    transform.translation,
+                    calculate_ship_health(hull_integrity, fill_level),
+                    crew_aboard as f32,
+                )
+            },
+        )
+        .collect();
+
+    for (ship, mut captain) in captains.iter_mut() {
+        let Some(&(_, position, health, crew_aboard)) =
+            ship_states.iter().find(|(other, ..)| *other == ship)
+        else {
+            continue;
+        };
+
+        let target = ship_states
+            .iter()
+            .filter(|(other, ..)| *other != ship)
+            .map(|state| (state, state.1.distance(position)))
+            .filter(|(_, distance)| *distance <= SIGHTING_RANGE)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        let Some((&(target, target_position, target_health, target_crew), distance)) = target
+        else {
+            captain.target = None;
+            continue;
+        };
+
+        captain.target = Some(target);
+        captain.distance = distance;
+        captain.bearing = Vec3::new(
+            target_position.x - position.x,
+            0.0,
+            target_position.z - position.z,
+        )
+        .normalize_or_zero();
+        captain.weather_gage = calculate_weather_gage(position, target_position, wind.direction);
+        captain.health = health;
+        captain.target_health = target_health;
+        captain.crew_ratio = crew_aboard / target_crew.max(1.0);
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{EventWriter, Query, Res, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_captain_behavior::components::{Board, Captain};
+use crate::ai_captain_behavior::constants::BOARDING_RANGE;
+use crate::ai_captain_behavior::events::ShipBoarded;
+use crate::ai_captain_behavior::utils::calculate_sailable_heading;
+use crate::wind::Wind;
+
+/// This system manages the boarding action of captains. The captain orders full sail and a
+/// heading straight for the target, and sends a `ShipBoarded` event once the ship is close
+/// enough alongside to board it.
+///
+/// # Parameters
+/// - `wind`: Resource describing the wind the ship has to sail in.
+/// - `ship_boarded_events`: Event writer for reporting ships being boarded.
+/// - `captains`: Query to fetch and modify the `Captain` components of ships.
+/// - `query`: Query to fetch and modify the `ActionState` and `ActionSpan` components of
+///   entities with the `Board` component, as well as the `Actor` component to identify the ship.
+pub fn board_action(
+    wind: Res<Wind>,
+    mut ship_boarded_events: EventWriter<ShipBoarded>,
+    mut captains: Query<&mut Captain>,
+    mut query: Query<(&Actor, &mut ActionState, &ActionSpan), With<Board>>,
+) {
+    for (Actor(actor), mut state, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok(mut captain) = captains.get_mut(*actor) else {
+            continue;
+        };
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Prepare to board!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Some(target) = captain.target else {
+                    debug!("Lost sight of the enemy.");
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                trace!("Closing to board... Distance: {}", captain.distance);
+                captain.heading = Some(calculate_sailable_heading(captain.bearing, wind.direction));
+                captain.furl = 0.0;
+
+                if captain.distance <= BOARDING_RANGE {
+                    debug!("Boarders away!");
+                    ship_boarded_events.send(ShipBoarded {
+                        boarder: *actor,
+                        target,
+                    });
+                    *state = ActionState::Success;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use bevy::log::trace;
+use bevy::prelude::{Query, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_captain_behavior::components::{BoardScorer, Captain};
+use crate::ai_captain_behavior::utils::evaluate_board;
+
+/// This system calculates a score indicating how urgently a captain should board the target.
+///
+/// The system iterates over entities with the `BoardScorer` component and scores them from
+/// the assessment of the engagement held by the captain of the scoring ship.
+///
+/// # Parameters
+/// - `captains`: A query to fetch the `Captain` components of ships.
+/// - `query`: A query to fetch the `Actor`, `Score`, and `ScorerSpan` components of entities
+///   with the `BoardScorer` component.
+pub fn calculate_board_score(
+    captains: Query<&Captain>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<BoardScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        let new_score = captains.get(*actor).map(evaluate_board).unwrap_or(0.0);
+
+        span.span()
+            .in_scope(|| trace!("Board score: {}", new_score));
+        score.set(new_score);
+    }
+}
@@ -0,0 +1,54 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Query, Res, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_captain_behavior::components::{Captain, Flee};
+use crate::ai_captain_behavior::utils::calculate_sailable_heading;
+use crate::wind::Wind;
+
+/// This system manages the fleeing action of captains. The captain orders full sail and a
+/// heading away from the target until the target is out of sight, and then holds that course.
+///
+/// # Parameters
+/// - `wind`: Resource describing the wind the ship has to sail in.
+/// - `captains`: Query to fetch and modify the `Captain` components of ships.
+/// - `query`: Query to fetch and modify the `ActionState` and `ActionSpan` components of
+///   entities with the `Flee` component, as well as the `Actor` component to identify the ship.
+pub fn flee_action(
+    wind: Res<Wind>,
+    mut captains: Query<&mut Captain>,
+    mut query: Query<(&Actor, &mut ActionState, &ActionSpan), With<Flee>>,
+) {
+    for (Actor(actor), mut state, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok(mut captain) = captains.get_mut(*actor) else {
+            continue;
+        };
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Breaking off the engagement!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                if captain.target.is_none() {
+                    debug!("Escaped!");
+                    *state = ActionState::Success;
+                    continue;
+                }
+
+                trace!("Fleeing... Distance: {}", captain.distance);
+                captain.heading =
+                    Some(calculate_sailable_heading(-captain.bearing, wind.direction));
+                captain.furl = 0.0;
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
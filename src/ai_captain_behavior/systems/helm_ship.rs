@@ -0,0 +1,54 @@
+use bevy::prelude::{Children, Query, Res, Time, Transform};
+
+use crate::ai_captain_behavior::components::Captain;
+use crate::ai_captain_behavior::utils::{calculate_helm_angle, calculate_sail_trim};
+use crate::crew_management::Manning;
+use crate::hydrodynamics::Rudder;
+use crate::sailing::{Sail, SAIL_FURL_RATE, SAIL_TRIM_RATE};
+use crate::ship::SHIP_FORWARD;
+use crate::wind::Wind;
+
+/// This system carries out the orders of each ship's captain at the helm and on the sails.
+///
+/// The rudder is put over towards the ordered heading, or held amidships without one. The
+/// sails are trimmed to the wind on the ship's current heading, and set or furled towards the
+/// ordered amount, as quickly as the ship's sail handling stations are manned.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `wind`: Resource describing the wind the sails are trimmed to.
+/// - `ships`: Query to fetch the captain, transform, manning and children of ships and modify
+///   their rudders.
+/// - `sails`: Query to fetch and modify the `Sail` components.
+pub fn helm_ship(
+    time: Res<Time>,
+    wind: Res<Wind>,
+    mut ships: Query<(
+        &Captain,
+        &Transform,
+        &mut Rudder,
+        Option<&Manning>,
+        &Children,
+    )>,
+    mut sails: Query<&mut Sail>,
+) {
+    for (captain, transform, mut rudder, manning, children) in ships.iter_mut() {
+        let sail_handling = manning.map_or(1.0, |manning| manning.sails);
+        let trim_step = SAIL_TRIM_RATE * sail_handling * time.delta_seconds();
+        let furl_step = SAIL_FURL_RATE * sail_handling * time.delta_seconds();
+        let forward = transform.rotation * SHIP_FORWARD;
+
+        let helm_angle = captain
+            .heading
+            .map(|heading| calculate_helm_angle(forward, heading))
+            .unwrap_or(0.0);
+        rudder.set_target_angle(helm_angle);
+
+        let trim = calculate_sail_trim(forward, wind.direction);
+        let mut sail_iter = sails.iter_many_mut(children);
+        while let Some(mut sail) = sail_iter.fetch_next() {
+            sail.trim += (trim - sail.trim).clamp(-trim_step, trim_step);
+            sail.furl += (captain.furl - sail.furl).clamp(-furl_step, furl_step);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Entity, GlobalTransform, Parent, Query, Res, Transform, Vec3, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_captain_behavior::components::{Captain, HoldBroadside};
+use crate::ai_captain_behavior::constants::{FIGHTING_SAIL_FURL, FIRING_ARC};
+use crate::ai_captain_behavior::utils::{
+    calculate_broadside_heading, calculate_firing_elevation, calculate_sailable_heading,
+};
+use crate::buoyancy_physics::GRAVITY;
+use crate::gunnery::{
+    calculate_barrel_direction, calculate_shot_range, Cannon, CannonState, CANNON_ELEVATION_MAX,
+    CANNON_ELEVATION_MIN,
+};
+use crate::ship::SHIP_FORWARD;
+use crate::utils::find_top_level_entity;
+use crate::wind::Wind;
+
+/// This system manages the broadside action of captains. The captain shortens sail and steers
+/// square to the bearing of the target, keeping a broadside turned towards it. Each loaded gun
+/// aboard that bears on the target is laid for the range and fired, as long as the target is
+/// within the range the gun carries at its highest elevation. Guns that cannot reach the
+/// target hold their fire, rather than throwing their shot into the sea.
+///
+/// # Parameters
+/// - `wind`: Resource describing the wind the ship has to sail in.
+/// - `transforms`: Query to retrieve the transforms of ships.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each cannon.
+/// - `cannons`: Query to fetch and modify the cannons, along with their transforms.
+/// - `captains`: Query to fetch and modify the `Captain` components of ships.
+/// - `query`: Query to fetch and modify the `ActionState` and `ActionSpan` components of
+///   entities with the `HoldBroadside` component, as well as the `Actor` component to identify
+///   the ship.
+pub fn hold_broadside_action(
+    wind: Res<Wind>,
+    transforms: Query<&Transform>,
+    parent_query: Query<&Parent>,
+    mut cannons: Query<(Entity, &mut Cannon, &GlobalTransform)>,
+    mut captains: Query<&mut Captain>,
+    mut query: Query<(&Actor, &mut ActionState, &ActionSpan), With<HoldBroadside>>,
+) {
+    for (Actor(actor), mut state, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok(mut captain) = captains.get_mut(*actor) else {
+            continue;
+        };
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Beat to quarters!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Some(target) = captain.target else {
+                    debug!("Lost sight of the enemy.");
+                    *state = ActionState::Failure;
+                    continue;
+                };
+                let (Ok(ship_transform), Ok(target_transform)) =
+                    (transforms.get(*actor), transforms.get(target))
+                else {
+                    continue;
+                };
+
+                trace!("Holding broadside... Distance: {}", captain.distance);
+                let forward = ship_transform.rotation * SHIP_FORWARD;
+                captain.heading = Some(calculate_sailable_heading(
+                    calculate_broadside_heading(forward, captain.bearing),
+                    wind.direction,
+                ));
+                captain.furl = FIGHTING_SAIL_FURL;
+
+                for (entity, mut cannon, cannon_transform) in cannons.iter_mut() {
+                    if cannon.state != CannonState::Aiming {
+                        continue;
+                    }
+
+                    let ship = find_top_level_entity(entity, &parent_query);
+                    if ship != *actor {
+                        continue;
+                    }
+
+                    let offset = target_transform.translation - cannon_transform.translation();
+                    let line_of_fire = Vec3::new(offset.x, 0.0, offset.z);
+                    let barrel_direction = calculate_barrel_direction(
+                        cannon_transform.to_scale_rotation_translation().1,
+                        0.0,
+                    );
+                    let barrel_direction = Vec3::new(barrel_direction.x, 0.0, barrel_direction.z);
+
+                    let bears = barrel_direction.angle_between(line_of_fire) <= FIRING_ARC;
+                    if !bears {
+                        continue;
+                    }
+
+                    let muzzle_velocity = cannon.muzzle_velocity * cannon.shot.velocity_factor();
+                    let range = calculate_shot_range(
+                        muzzle_velocity,
+                        CANNON_ELEVATION_MAX,
+                        cannon_transform.translation().y,
+                        GRAVITY,
+                    );
+                    if line_of_fire.length() > range {
+                        trace!("Target out of range: {} > {}", line_of_fire.length(), range);
+                        continue;
+                    }
+
+                    debug!("Fire!");
+                    cannon.elevation =
+                        calculate_firing_elevation(muzzle_velocity, line_of_fire.length(), GRAVITY)
+                            .clamp(CANNON_ELEVATION_MIN, CANNON_ELEVATION_MAX);
+                    cannon.state = CannonState::Firing;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
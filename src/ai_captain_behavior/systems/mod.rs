@@ -0,0 +1,16 @@
+pub use {
+    approach_action::*, assess_engagement::*, board_action::*, calculate_approach_score::*,
+    calculate_board_score::*, calculate_broadside_score::*, calculate_flee_score::*,
+    flee_action::*, helm_ship::*, hold_broadside_action::*,
+};
+
+mod approach_action;
+mod assess_engagement;
+mod board_action;
+mod calculate_approach_score;
+mod calculate_board_score;
+mod calculate_broadside_score;
+mod calculate_flee_score;
+mod flee_action;
+mod helm_ship;
+mod hold_broadside_action;
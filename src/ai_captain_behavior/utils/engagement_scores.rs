@@ -0,0 +1,82 @@
+use crate::ai_captain_behavior::components::Captain;
+use crate::ai_captain_behavior::constants::{
+    BOARDING_CREW_RATIO, BROADSIDE_RANGE, FLEE_CREW_RATIO, FLEE_HEALTH,
+};
+
+/// Calculates how urgently a captain should close with the target.
+///
+/// # Arguments
+/// - `captain`: The captain's assessment of the engagement.
+///
+/// # Returns
+/// A score from `0.0` to `1.0`. Captains holding the weather gage are keener to close, while
+/// damaged ships are more reluctant. The score is zero without a target, once the target is
+/// within broadside range, or while the captain would rather flee.
+pub fn evaluate_approach(captain: &Captain) -> f32 {
+    if captain.target.is_none()
+        || captain.distance <= BROADSIDE_RANGE
+        || evaluate_flee(captain) > 0.0
+    {
+        return 0.0;
+    }
+
+    ((0.5 + 0.25 * captain.weather_gage) * captain.health).clamp(0.0, 1.0)
+}
+
+/// Calculates how urgently a captain should hold broadside position.
+///
+/// # Arguments
+/// - `captain`: The captain's assessment of the engagement.
+///
+/// # Returns
+/// A score from `0.5` to `0.8` while the target is within broadside range, higher the less
+/// damaged the ship is, and zero otherwise.
+pub fn evaluate_broadside(captain: &Captain) -> f32 {
+    if captain.target.is_none() || captain.distance > BROADSIDE_RANGE {
+        return 0.0;
+    }
+
+    0.5 + 0.3 * captain.health.clamp(0.0, 1.0)
+}
+
+/// Calculates how urgently a captain should flee from the target.
+///
+/// # Arguments
+/// - `captain`: The captain's assessment of the engagement.
+///
+/// # Returns
+/// A score from `0.8` to `1.0` once the ship's health falls below `FLEE_HEALTH` or its crew
+/// ratio below `FLEE_CREW_RATIO`, higher the further below, and zero otherwise.
+pub fn evaluate_flee(captain: &Captain) -> f32 {
+    if captain.target.is_none() {
+        return 0.0;
+    }
+
+    let damage = (FLEE_HEALTH - captain.health) / FLEE_HEALTH;
+    let outnumbered = (FLEE_CREW_RATIO - captain.crew_ratio) / FLEE_CREW_RATIO;
+    let urgency = damage.max(outnumbered);
+
+    if urgency > 0.0 {
+        0.8 + 0.2 * urgency.min(1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Calculates how urgently a captain should board the target.
+///
+/// # Arguments
+/// - `captain`: The captain's assessment of the engagement.
+///
+/// # Returns
+/// A score from `0.0` to `1.0` while the target is within broadside range. It grows as the
+/// ship's crew outnumbers the target's, up to `BOARDING_CREW_RATIO`, and as the target is
+/// battered, so boarding is preferred over the guns once the target has been softened up.
+pub fn evaluate_board(captain: &Captain) -> f32 {
+    if captain.target.is_none() || captain.distance > BROADSIDE_RANGE {
+        return 0.0;
+    }
+
+    let advantage = ((captain.crew_ratio - 1.0) / (BOARDING_CREW_RATIO - 1.0)).clamp(0.0, 1.0);
+    advantage * (0.5 + 0.5 * (1.0 - captain.target_health.clamp(0.0, 1.0)))
+}
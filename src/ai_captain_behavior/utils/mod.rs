@@ -0,0 +1,4 @@
+pub use {engagement_scores::*, seamanship_utils::*};
+
+mod engagement_scores;
+mod seamanship_utils;
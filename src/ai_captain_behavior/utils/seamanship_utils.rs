@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+
+use crate::ai_captain_behavior::constants::NO_GO_ANGLE;
+use crate::damage::HullIntegrity;
+use crate::sailing::{SAIL_TRIM_MAX, SAIL_TRIM_MIN};
+
+/// Calculates how far upwind of a target a ship is.
+///
+/// The ship upwind holds the weather gage: it can bear down on the target when it chooses,
+/// while the target has to beat up against the wind to reach it.
+///
+/// # Arguments
+/// - `position`: The world position of the ship.
+/// - `target_position`: The world position of the target.
+/// - `wind_direction`: The horizontal direction the wind blows towards.
+///
+/// # Returns
+/// A value from `-1.0`, with the ship dead downwind of the target, to `1.0`, with the ship dead
+/// upwind of it.
+pub fn calculate_weather_gage(position: Vec3, target_position: Vec3, wind_direction: Vec3) -> f32 {
+    let offset = Vec3::new(
+        target_position.x - position.x,
+        0.0,
+        target_position.z - position.z,
+    );
+
+    offset
+        .normalize_or_zero()
+        .dot(wind_direction.normalize_or_zero())
+}
+
+/// Calculates how sound a ship is from the state of its hull and the water it has shipped.
+///
+/// # Arguments
+/// - `hull_integrity`: The integrity of the ship's hull sections, if it has been set up.
+/// - `fill_level`: How full of water the hull is, from `0.0` (dry) to `1.0` (flooded).
+///
+/// # Returns
+/// The health of the ship, from `0.0` (wrecked) to `1.0` (undamaged and dry).
+pub fn calculate_ship_health(hull_integrity: Option<&HullIntegrity>, fill_level: f32) -> f32 {
+    let hull = hull_integrity
+        .filter(|hull_integrity| !hull_integrity.sections.is_empty())
+        .map(|hull_integrity| {
+            hull_integrity.sections.iter().sum::<f32>() / hull_integrity.sections.len() as f32
+        })
+        .unwrap_or(1.0);
+
+    (hull * (1.0 - fill_level.clamp(0.0, 1.0))).clamp(0.0, 1.0)
+}
+
+/// Calculates the heading closest to a desired one that a ship can sail.
+///
+/// A ship cannot sail closer to the wind than `NO_GO_ANGLE`. Desired headings inside the no-go
+/// zone are turned out to its nearer edge, putting the ship close-hauled on the tack that comes
+/// closest to the desired heading.
+///
+/// # Arguments
+/// - `desired`: The horizontal direction the ship would like to sail in.
+/// - `wind_direction`: The horizontal direction the wind blows towards.
+///
+/// # Returns
+/// The horizontal unit direction the ship should steer.
+pub fn calculate_sailable_heading(desired: Vec3, wind_direction: Vec3) -> Vec3 {
+    let desired = Vec3::new(desired.x, 0.0, desired.z).normalize_or_zero();
+    let upwind = -Vec3::new(wind_direction.x, 0.0, wind_direction.z).normalize_or_zero();
+
+    if desired == Vec3::ZERO || upwind == Vec3::ZERO || desired.angle_between(upwind) >= NO_GO_ANGLE
+    {
+        return desired;
+    }
+
+    let side = if upwind.cross(desired).y < 0.0 {
+        -1.0
+    } else {
+        1.0
+    };
+
+    Quat::from_rotation_y(side * NO_GO_ANGLE) * upwind
+}
+
+/// Calculates the heading that turns a broadside towards a target.
+///
+/// Of the two headings square to the bearing of the target, the one closer to the ship's
+/// current heading is chosen so the ship does not have to go about.
+///
+/// # Arguments
+/// - `forward`: The horizontal direction the ship is heading in.
+/// - `bearing`: The horizontal direction from the ship to the target.
+///
+/// # Returns
+/// The horizontal unit direction the ship should steer.
+pub fn calculate_broadside_heading(forward: Vec3, bearing: Vec3) -> Vec3 {
+    let bearing = Vec3::new(bearing.x, 0.0, bearing.z).normalize_or_zero();
+    let to_port = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2) * bearing;
+
+    if to_port.dot(forward) >= 0.0 {
+        to_port
+    } else {
+        -to_port
+    }
+}
+
+/// Calculates the rudder angle that turns a ship towards a heading.
+///
+/// # Arguments
+/// - `forward`: The horizontal direction the ship is heading in.
+/// - `heading`: The horizontal direction the ship should steer.
+///
+/// # Returns
+/// The angle in radians between the ship's heading and the ordered one, positive when the ship
+/// has to turn to starboard, to be used as the target angle of the rudder.
+pub fn calculate_helm_angle(forward: Vec3, heading: Vec3) -> f32 {
+    let forward = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
+    let heading = Vec3::new(heading.x, 0.0, heading.z).normalize_or_zero();
+
+    -forward.cross(heading).y.atan2(forward.dot(heading))
+}
+
+/// Calculates the sheet angle that sets the sails to a wind.
+///
+/// Sails are trimmed to about half the angle between the bow and the direction the wind
+/// blows from, hauled in when sailing close to the wind and eased out when running before it.
+///
+/// # Arguments
+/// - `forward`: The horizontal direction the ship is heading in.
+/// - `wind_direction`: The horizontal direction the wind blows towards.
+///
+/// # Returns
+/// The sheet angle in radians, within the limits the sails can be trimmed to.
+pub fn calculate_sail_trim(forward: Vec3, wind_direction: Vec3) -> f32 {
+    let upwind = -Vec3::new(wind_direction.x, 0.0, wind_direction.z).normalize_or_zero();
+    let forward = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
+
+    if upwind == Vec3::ZERO || forward == Vec3::ZERO {
+        return SAIL_TRIM_MIN;
+    }
+
+    (forward.angle_between(upwind) / 2.0).clamp(SAIL_TRIM_MIN, SAIL_TRIM_MAX)
+}
+
+/// Calculates the elevation that carries a shot to a target on the water.
+///
+/// # Arguments
+/// - `muzzle_velocity`: The speed of the shot leaving the barrel, in metres per second.
+/// - `distance`: The horizontal distance to the target, in metres.
+/// - `gravity`: The acceleration due to gravity, in metres per second squared.
+///
+/// # Returns
+/// The lower of the two elevations in radians that reach the target, or the elevation of
+/// greatest range when the target is out of reach.
+pub fn calculate_firing_elevation(muzzle_velocity: f32, distance: f32, gravity: f32) -> f32 {
+    if muzzle_velocity <= 0.0 {
+        return 0.0;
+    }
+
+    let reach = (gravity * distance / muzzle_velocity.powi(2)).clamp(0.0, 1.0);
+    reach.asin() / 2.0
+}
@@ -1,5 +0,0 @@
-pub use {eat::*, hunger::*, hunger_scorer::*};
-
-mod eat;
-mod hunger;
-mod hunger_scorer;
@@ -1,5 +0,0 @@
-pub use {calculate_hunger_score::*, eat_action::*, increase_hunger::*};
-
-mod calculate_hunger_score;
-mod eat_action;
-mod increase_hunger;
@@ -0,0 +1,4 @@
+pub use {reload_cannon::*, reload_scorer::*};
+
+mod reload_cannon;
+mod reload_scorer;
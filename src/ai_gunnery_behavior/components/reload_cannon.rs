@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the reloading behavior of an entity.
+///
+/// The `ReloadCannon` component is used to manage the reloading action of an entity in the
+/// game. The entity takes station at the nearest empty cannon within reach and stays there
+/// until the cannon is loaded and ready to fire.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct ReloadCannon;
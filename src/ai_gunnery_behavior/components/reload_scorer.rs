@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating the entity's need to reload the ship's guns.
+///
+/// The `ReloadScorer` component is used to calculate a score that indicates the urgency for an
+/// entity to perform the reloading action. The score is high while there is an empty cannon
+/// that no one else is loading, and the entity is a gunner without a station of their own.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct ReloadScorer;
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+use crate::claims::hold_claims;
+
+mod components;
+mod systems;
+
+/// Plugin for managing AI gunnery behavior within the game.
+///
+/// The AiGunneryBehaviorPlugin provides functionality for handling how crew serve the ship's
+/// guns. It registers the necessary components and sets up systems to score the need to reload
+/// and to man the cannons until they are loaded.
+///
+/// # Components
+/// - ReloadCannon: Manages the reloading action of an entity, stationing it at an empty cannon.
+/// - ReloadScorer: Calculates a score indicating the urgency for an entity to reload a cannon.
+///
+/// # Systems
+/// - calculate_reload_score: Calculates a score based on whether cannons are waiting to be loaded.
+/// - hold_claims: Holds on to the claims of entities on their cannons while they reload,
+///   releasing them once they stop.
+/// - reload_cannon_action: Manages the reloading action of entities, manning and leaving cannons.
+pub struct AiGunneryBehaviorPlugin;
+
+impl Plugin for AiGunneryBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ReloadCannon>()
+            .register_type::<ReloadScorer>()
+            .add_systems(
+                Update,
+                calculate_reload_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                reload_cannon_action.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                hold_claims::<ReloadCannon>
+                    .after(reload_cannon_action)
+                    .run_if(in_state(AppStates::Running)),
+            );
+    }
+}
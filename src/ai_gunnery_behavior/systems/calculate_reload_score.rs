@@ -0,0 +1,57 @@
+use bevy::log::trace;
+use bevy::prelude::{Entity, Parent, Query, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_gunnery_behavior::components::ReloadScorer;
+use crate::crew_management::{is_aboard_own_ship, CrewRole, ShipCrew, Station};
+use crate::gunnery::{Cannon, CannonState};
+
+/// This system calculates a score based on whether there are cannons waiting to be loaded.
+///
+/// The system iterates over entities with the `ReloadScorer` component and gives them a full
+/// score while any cannon aboard their own ship is not yet loaded and has no one else stationed
+/// at it, and no score otherwise. Cannons whose gunner is manning them need no extra hands.
+/// Cannons aboard other ships are left to their own crews.
+///
+/// Only gunners who have not been given a station by the duty roster are scored, so spare
+/// gunners lend a hand at the guns while everyone with a post stays at it.
+///
+/// # Parameters
+/// - `cannons`: A query to fetch the `Cannon` components and any `Station` they are.
+/// - `parent_query`: A query to navigate up the hierarchy to find the ship carrying each cannon.
+/// - `ships`: A query to fetch the crew of ships.
+/// - `roles`: A query to fetch the role of each crew member.
+/// - `stations`: A query to fetch the stations the duty roster has assigned crew to.
+/// - `query`: A query to fetch the `Actor`, `Score`, and `ScorerSpan` components of entities
+///   with the `ReloadScorer` component.
+pub fn calculate_reload_score(
+    cannons: Query<(Entity, &Cannon, Option<&Station>)>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
+    roles: Query<&CrewRole>,
+    stations: Query<&Station>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<ReloadScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        let is_spare_gunner = roles.get(*actor) == Ok(&CrewRole::Gunner)
+            && !stations.iter().any(|station| station.crew == Some(*actor));
+        if !is_spare_gunner {
+            score.set(0.0);
+            continue;
+        }
+
+        let needs_loading = cannons.iter().any(|(entity, cannon, station)| {
+            is_aboard_own_ship(*actor, entity, &parent_query, &ships)
+                && matches!(cannon.state, CannonState::Empty | CannonState::Loading)
+                && !station.is_some_and(|station| station.manned)
+                && (cannon.operator.is_none() || cannon.operator == Some(*actor))
+        });
+
+        if needs_loading {
+            span.span().in_scope(|| trace!("A cannon needs loading!"));
+            score.set(1.0);
+        } else {
+            score.set(0.0);
+        }
+    }
+}
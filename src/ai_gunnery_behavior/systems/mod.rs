@@ -0,0 +1,4 @@
+pub use {calculate_reload_score::*, reload_cannon_action::*};
+
+mod calculate_reload_score;
+mod reload_cannon_action;
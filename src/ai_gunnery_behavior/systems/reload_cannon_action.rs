@@ -0,0 +1,109 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Entity, GlobalTransform, Parent, Query, Transform, With, Without};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_gunnery_behavior::components::ReloadCannon;
+use crate::claims::Claimable;
+use crate::crew_management::{is_aboard_own_ship, ShipCrew, Station};
+use crate::gunnery::{Cannon, CannonState, CANNON_STATION_DISTANCE};
+
+/// This system manages the reloading action of entities. The entity takes station at the
+/// nearest empty cannon aboard its own ship within reach that its gunner is not manning and no
+/// one else has claimed, which loads while it is stationed there, and leaves the cannon once it
+/// is loaded.
+///
+/// # Parameters
+/// - `actors`: Query to fetch the transforms of the acting entities.
+/// - `cannons`: Query to fetch and modify the cannons, along with their transforms, stations and
+///   claims.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each cannon.
+/// - `ships`: Query to fetch the crew of ships.
+/// - `query`: Query to fetch and modify the `ActionState` and `ActionSpan` components of
+///   entities with the `ReloadCannon` component, as well as the `Actor` component to identify
+///   the entity.
+#[allow(clippy::type_complexity)]
+pub fn reload_cannon_action(
+    actors: Query<&Transform, Without<Cannon>>,
+    mut cannons: Query<(
+        Entity,
+        &mut Cannon,
+        &GlobalTransform,
+        Option<&Station>,
+        Option<&Claimable>,
+    )>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
+    mut query: Query<(&Actor, &mut ActionState, &ActionSpan), With<ReloadCannon>>,
+) {
+    for (Actor(actor), mut state, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok(actor_transform) = actors.get(*actor) else {
+            continue;
+        };
+
+        match *state {
+            ActionState::Requested => {
+                let nearest_empty_cannon = cannons
+                    .iter()
+                    .filter(|(entity, cannon, _, station, claimable)| {
+                        cannon.operator.is_none()
+                            && is_aboard_own_ship(*actor, *entity, &parent_query, &ships)
+                            && matches!(cannon.state, CannonState::Empty | CannonState::Loading)
+                            && !station.is_some_and(|station| station.manned)
+                            && claimable.is_none_or(|claimable| claimable.has_room(*actor))
+                    })
+                    .map(|(cannon, _, transform, _, _)| {
+                        (
+                            cannon,
+                            transform
+                                .translation()
+                                .distance(actor_transform.translation),
+                        )
+                    })
+                    .filter(|(_, distance)| *distance <= CANNON_STATION_DISTANCE)
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(cannon, _)| cannon);
+
+                match nearest_empty_cannon.and_then(|cannon| cannons.get_mut(cannon).ok()) {
+                    Some((_, mut cannon, _, _, _)) => {
+                        debug!("Taking station at the cannon!");
+                        cannon.operator = Some(*actor);
+                        *state = ActionState::Executing;
+                    }
+                    None => {
+                        debug!("No empty cannon within reach.");
+                        *state = ActionState::Failure;
+                    }
+                }
+            }
+            ActionState::Executing => {
+                let Some((_, mut cannon, _, _, _)) = cannons
+                    .iter_mut()
+                    .find(|(_, cannon, _, _, _)| cannon.operator == Some(*actor))
+                else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                trace!("Loading...");
+                if !matches!(cannon.state, CannonState::Empty | CannonState::Loading) {
+                    debug!("Cannon loaded!");
+                    cannon.operator = None;
+                    *state = ActionState::Success;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                for (_, mut cannon, _, _, _) in cannons.iter_mut() {
+                    if cannon.operator == Some(*actor) {
+                        cannon.operator = None;
+                    }
+                }
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating the entity's need for treatment based on
+/// its injuries.
+///
+/// The `InjuryScorer` component is used to determine the priority of going to the sickbay for
+/// an entity by calculating a score that reflects how badly it is hurt.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct InjuryScorer;
@@ -0,0 +1,4 @@
+pub use {injury_scorer::*, recover::*};
+
+mod injury_scorer;
+mod recover;
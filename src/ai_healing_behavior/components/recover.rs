@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the recovery of an injured entity in the sickbay.
+///
+/// The `Recover` component is used to manage the treatment of an entity in the game. The
+/// entity is treated in the sickbay it has reached until its health has recovered to the
+/// given level.
+///
+/// # Fields
+/// - `until`: The health, from `0.0` to `1.0`, at which the entity leaves the sickbay.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct Recover {
+    /// The health at which the entity leaves the sickbay.
+    pub until: f32,
+}
@@ -0,0 +1,13 @@
+/// The health, from `0.0` to `1.0`, at or below which the need for treatment reaches its
+/// highest score.
+pub const SICKBAY_HEALTH_LEVEL: f32 = 0.7;
+
+/// The distance in metres within which a crew member is in the sickbay and can be treated.
+pub const SICKBAY_DISTANCE: f32 = 2.0;
+
+/// The health per second a crew member recovers in the sickbay with no surgeon at work.
+pub const SICKBAY_RECOVERY_RATE: f32 = 0.002;
+
+/// The extra health per second a fully effective surgeon restores to a crew member in the
+/// sickbay.
+pub const SURGEON_RECOVERY_RATE: f32 = 0.02;
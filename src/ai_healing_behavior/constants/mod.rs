@@ -0,0 +1,3 @@
+pub use healing_settings::*;
+
+mod healing_settings;
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod systems;
+mod utils;
+
+/// Plugin for managing AI healing behavior within the game.
+///
+/// The AiHealingBehaviorPlugin provides functionality for handling how injured crew seek
+/// treatment. It registers the necessary components and sets up systems to score the need for
+/// treatment and to recover in the sickbay.
+///
+/// # Components
+/// - InjuryScorer: Calculates a score indicating how badly an entity needs treatment.
+/// - Recover: Manages the recovery of an entity in the sickbay, defining the health at which
+///   it leaves.
+///
+/// # Systems
+/// - calculate_injury_score: Calculates a score based on how badly an entity is injured.
+/// - recover_action: Manages the recovery of entities in the sickbay, treating them there.
+pub struct AiHealingBehaviorPlugin;
+
+impl Plugin for AiHealingBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<InjuryScorer>()
+            .register_type::<Recover>()
+            .add_systems(
+                Update,
+                calculate_injury_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, recover_action.run_if(in_state(AppStates::Running)));
+    }
+}
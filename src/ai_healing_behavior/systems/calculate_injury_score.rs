@@ -0,0 +1,40 @@
+use bevy::log::trace;
+use bevy::prelude::{Query, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_healing_behavior::components::InjuryScorer;
+use crate::ai_healing_behavior::constants::SICKBAY_HEALTH_LEVEL;
+use crate::crew_health::Health;
+
+/// This system calculates a score based on how badly an entity is injured. The more health
+/// the entity has lost, the higher the score, reaching its highest once health has dropped to
+/// `SICKBAY_HEALTH_LEVEL`.
+///
+/// An entity that is being treated keeps the highest score until its treatment is over, and an
+/// entity that carries no injuries has no need for treatment.
+///
+/// # Parameters
+/// - `healths`: A query to fetch the `Health` component of entities.
+/// - `query`: A query to fetch the `Actor`, `Score`, and `ScorerSpan` components of entities
+///   with the `InjuryScorer` component.
+pub fn calculate_injury_score(
+    healths: Query<&Health>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<InjuryScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        let Ok(health) = healths.get(*actor) else {
+            continue;
+        };
+
+        if health.under_treatment {
+            score.set(1.0);
+        } else if health.is_injured() {
+            let new_score = ((1.0 - health.level) / (1.0 - SICKBAY_HEALTH_LEVEL)).clamp(0.0, 1.0);
+            span.span()
+                .in_scope(|| trace!("Injured! Health: {}, score: {}", health.level, new_score));
+            score.set(new_score);
+        } else {
+            score.set(0.0);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use {calculate_injury_score::*, recover_action::*};
+
+mod calculate_injury_score;
+mod recover_action;
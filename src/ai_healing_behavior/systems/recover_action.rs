@@ -0,0 +1,119 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{GlobalTransform, Query, Res, Time, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor, HasThinker};
+
+use crate::ai_healing_behavior::components::Recover;
+use crate::ai_healing_behavior::constants::SICKBAY_DISTANCE;
+use crate::ai_healing_behavior::utils::calculate_recovery_rate;
+use crate::crew_health::Health;
+use crate::crew_management::{CrewSkills, Skill, Station};
+use crate::ship_items::Sickbay;
+
+/// This system manages the recovery of injured entities in the sickbay. The entity is treated
+/// while it stays within reach of a sickbay, and recovers much faster while a surgeon is at
+/// work there, the more so the more skilled and healthy the surgeon is.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `actors`: Query to fetch the transforms of the acting entities.
+/// - `sickbays`: Query to fetch the sickbays, along with their transforms and stations.
+/// - `surgeons`: Query to fetch the skills of surgeons.
+/// - `healths`: Query to fetch and modify the `Health` of the acting entities and surgeons.
+/// - `query`: Query to fetch and modify the `ActionState`, `Recover`, and `ActionSpan`
+///   components of entities, as well as the `Actor` component to identify the entity.
+pub fn recover_action(
+    time: Res<Time>,
+    actors: Query<&GlobalTransform, With<HasThinker>>,
+    sickbays: Query<(&GlobalTransform, Option<&Station>), With<Sickbay>>,
+    surgeons: Query<&CrewSkills>,
+    mut healths: Query<&mut Health>,
+    mut query: Query<(&Actor, &mut ActionState, &Recover, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, recover, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok(actor_transform) = actors.get(*actor) else {
+            continue;
+        };
+
+        let sickbay_in_reach = sickbays
+            .iter()
+            .filter(|(transform, _)| {
+                transform
+                    .translation()
+                    .distance(actor_transform.translation())
+                    <= SICKBAY_DISTANCE
+            })
+            .min_by(|(a, _), (b, _)| {
+                a.translation()
+                    .distance_squared(actor_transform.translation())
+                    .total_cmp(
+                        &b.translation()
+                            .distance_squared(actor_transform.translation()),
+                    )
+            });
+
+        match *state {
+            ActionState::Requested => {
+                let Ok(mut health) = healths.get_mut(*actor) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                if sickbay_in_reach.is_some() {
+                    debug!("Reporting to the sickbay!");
+                    health.under_treatment = true;
+                    *state = ActionState::Executing;
+                } else {
+                    debug!("No sickbay within reach.");
+                    *state = ActionState::Failure;
+                }
+            }
+            ActionState::Executing => {
+                let Some((_, station)) = sickbay_in_reach else {
+                    debug!("Left the sickbay.");
+                    if let Ok(mut health) = healths.get_mut(*actor) {
+                        health.under_treatment = false;
+                    }
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                let surgeon_effectiveness = station
+                    .filter(|station| station.manned)
+                    .and_then(|station| station.crew)
+                    .map(|surgeon| {
+                        surgeons
+                            .get(surgeon)
+                            .map_or(1.0, |skills| skills.effectiveness(Skill::Surgery))
+                            * healths
+                                .get(surgeon)
+                                .map_or(1.0, |health| health.work_rate())
+                    });
+
+                let Ok(mut health) = healths.get_mut(*actor) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                trace!("Recovering...");
+                health.treat(calculate_recovery_rate(surgeon_effectiveness) * time.delta_seconds());
+
+                if health.level >= recover.until {
+                    debug!("Fit for duty!");
+                    health.under_treatment = false;
+                    *state = ActionState::Success;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                if let Ok(mut health) = healths.get_mut(*actor) {
+                    health.under_treatment = false;
+                }
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::ai_healing_behavior::constants::{SICKBAY_RECOVERY_RATE, SURGEON_RECOVERY_RATE};
+
+/// Calculates how fast a crew member recovers in the sickbay.
+///
+/// # Arguments
+/// - `surgeon_effectiveness`: How effective the surgeon at work in the sickbay is, taking both
+///   their skill and their own health into account, or `None` if no surgeon is at work.
+///
+/// # Returns
+/// The health the crew member recovers per second.
+pub fn calculate_recovery_rate(surgeon_effectiveness: Option<f32>) -> f32 {
+    SICKBAY_RECOVERY_RATE
+        + surgeon_effectiveness.map_or(0.0, |effectiveness| {
+            SURGEON_RECOVERY_RATE * effectiveness.max(0.0)
+        })
+}
@@ -0,0 +1,3 @@
+pub use healing_utils::*;
+
+mod healing_utils;
@@ -0,0 +1,4 @@
+pub use {morale_scorer::*, shirk::*};
+
+mod morale_scorer;
+mod shirk;
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating the entity's urge to shirk its duties based
+/// on its morale.
+///
+/// The `MoraleScorer` component is used to determine the priority of slacking off for an
+/// entity by calculating a score that reflects how disgruntled it is.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct MoraleScorer;
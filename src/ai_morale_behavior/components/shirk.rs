@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents a disgruntled entity shirking its duties.
+///
+/// The `Shirk` component is used to manage the shirking action of an entity in the game. The
+/// entity idles where it stands, slowly recovering its morale, until its morale has risen to
+/// the given level.
+///
+/// # Fields
+/// - `until`: The morale at which the entity goes back to work.
+/// - `per_second`: The rate at which morale recovers while the entity shirks.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct Shirk {
+    /// The morale at which the entity goes back to work.
+    pub until: f32,
+    /// The rate at which morale recovers while shirking.
+    pub per_second: f32,
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod systems;
+
+/// Plugin for managing AI morale behavior within the game.
+///
+/// The AiMoraleBehaviorPlugin provides functionality for handling how disgruntled crew shirk
+/// their duties, and how the crew of a ship in mutiny down tools. It registers the necessary
+/// components and sets up systems to score the urge to shirk and to idle while morale recovers.
+///
+/// # Components
+/// - MoraleScorer: Calculates a score indicating how disgruntled an entity is.
+/// - Shirk: Manages the shirking of an entity, defining the morale at which it goes back to
+///   work and how fast its morale recovers meanwhile.
+///
+/// # Systems
+/// - calculate_morale_score: Calculates a score based on how low an entity's morale is, and
+///   whether its ship is in mutiny.
+/// - shirk_action: Manages the shirking of entities, recovering their morale while they idle.
+pub struct AiMoraleBehaviorPlugin;
+
+impl Plugin for AiMoraleBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<MoraleScorer>()
+            .register_type::<Shirk>()
+            .add_systems(
+                Update,
+                calculate_morale_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, shirk_action.run_if(in_state(AppStates::Running)));
+    }
+}
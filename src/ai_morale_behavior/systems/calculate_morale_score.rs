@@ -0,0 +1,48 @@
+use bevy::log::trace;
+use bevy::prelude::{Query, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_morale_behavior::components::MoraleScorer;
+use crate::crew_management::ShipCrew;
+use crate::crew_morale::{is_in_mutiny, Morale, ShipMorale, CONTENT_MORALE, DISCONTENT_MORALE};
+
+/// This system calculates a score based on an entity's morale. Content entities have no urge
+/// to shirk, and the score rises as morale falls below `CONTENT_MORALE`, reaching its highest
+/// once the entity is discontented. The whole crew of a ship in mutiny down tools, however
+/// content they are themselves.
+///
+/// # Parameters
+/// - `morales`: A query to fetch the `Morale` component of entities.
+/// - `ships`: A query to fetch the crew of ships and their `ShipMorale`.
+/// - `query`: A query to fetch the `Actor`, `Score`, and `ScorerSpan` components of entities
+///   with the `MoraleScorer` component.
+pub fn calculate_morale_score(
+    morales: Query<&Morale>,
+    ships: Query<(&ShipCrew, &ShipMorale)>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<MoraleScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        let Ok(morale) = morales.get(*actor) else {
+            continue;
+        };
+
+        if is_in_mutiny(*actor, &ships) {
+            span.span().in_scope(|| trace!("Mutiny!"));
+            score.set(1.0);
+            continue;
+        }
+
+        let new_score = ((CONTENT_MORALE - morale.level) / (CONTENT_MORALE - DISCONTENT_MORALE))
+            .clamp(0.0, 1.0);
+        if new_score > 0.0 {
+            span.span().in_scope(|| {
+                trace!(
+                    "Disgruntled! Morale: {}, score: {}",
+                    morale.level,
+                    new_score
+                )
+            });
+        }
+        score.set(new_score);
+    }
+}
@@ -0,0 +1,4 @@
+pub use {calculate_morale_score::*, shirk_action::*};
+
+mod calculate_morale_score;
+mod shirk_action;
@@ -0,0 +1,55 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Query, Res, Time};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_morale_behavior::components::Shirk;
+use crate::crew_management::ShipCrew;
+use crate::crew_morale::{is_in_mutiny, Morale, ShipMorale};
+
+/// This system manages the shirking action of entities. The entity leaves its work to idle
+/// where it stands, and its morale slowly recovers until it reaches the level set by the
+/// `Shirk` component. The crew of a ship in mutiny keep idling until the mutiny is over.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `morales`: Query to fetch and modify the `Morale` component of entities.
+/// - `ships`: Query to fetch the crew of ships and their `ShipMorale`.
+/// - `query`: Query to fetch and modify the `ActionState`, `Shirk`, and `ActionSpan`
+///   components of entities, as well as the `Actor` component to identify the entity.
+pub fn shirk_action(
+    time: Res<Time>,
+    mut morales: Query<&mut Morale>,
+    ships: Query<(&ShipCrew, &ShipMorale)>,
+    mut query: Query<(&Actor, &mut ActionState, &Shirk, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, shirk, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok(mut morale) = morales.get_mut(*actor) else {
+            *state = ActionState::Failure;
+            continue;
+        };
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Not lifting a finger!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                trace!("Shirking...");
+                morale.raise(shirk.per_second * time.delta_seconds());
+
+                if morale.level >= shirk.until && !is_in_mutiny(*actor, &ships) {
+                    debug!("Back to work, then.");
+                    *state = ActionState::Success;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,6 +1,10 @@
 pub use navigation_path::*;
 pub use seek_behavior::*;
+pub use seek_order_behavior::*;
+pub use seek_station_behavior::*;
 
 mod navigation_path;
 
 mod seek_behavior;
+mod seek_order_behavior;
+mod seek_station_behavior;
@@ -4,15 +4,24 @@ use bevy::prelude::*;
 use big_brain::prelude::*;
 use serde::{Deserialize, Serialize};
 
-pub use {seek_food_behavior::*, seek_sleep_area_behavior::*};
+pub use {
+    seek_bilge_pump_behavior::*, seek_cannon_behavior::*, seek_damaged_behavior::*,
+    seek_food_behavior::*, seek_sickbay_behavior::*, seek_sleep_area_behavior::*,
+};
 
+mod seek_bilge_pump_behavior;
+mod seek_cannon_behavior;
+mod seek_damaged_behavior;
 mod seek_food_behavior;
+mod seek_sickbay_behavior;
 mod seek_sleep_area_behavior;
 
 /// Component for entities that seek the nearest target of type `T`.
 ///
 /// This component is used in conjunction with an `ActionBuilder` to create actions
 /// where an entity will navigate towards and seek the nearest target of a specified type `T`.
+/// Targets that are `Claimable` are claimed by the entity on the way, and targets that others
+/// have claimed to capacity are passed over.
 ///
 /// # Type Parameters
 /// - `T`: The type of the target component that the entity will seek. It must implement
@@ -21,7 +30,7 @@ mod seek_sleep_area_behavior;
 /// # Fields
 /// - `_marker`: A phantom data marker to hold the type `T`.
 /// - `speed`: The movement speed of the entity.
-#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize)]
 #[reflect(Component, Serialize, Deserialize)]
 pub struct SeekBehavior<T: Component + std::fmt::Debug + Clone> {
     #[reflect(ignore)]
@@ -29,6 +38,15 @@ pub struct SeekBehavior<T: Component + std::fmt::Debug + Clone> {
     pub speed: f32,
 }
 
+impl<T: Component + std::fmt::Debug + Clone> Default for SeekBehavior<T> {
+    fn default() -> Self {
+        Self {
+            _marker: PhantomData,
+            speed: 0.0,
+        }
+    }
+}
+
 impl<T> ActionBuilder for SeekBehavior<T>
 where
     T: Component + std::fmt::Debug + Clone,
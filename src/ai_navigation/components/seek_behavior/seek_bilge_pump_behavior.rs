@@ -0,0 +1,6 @@
+use crate::ai_navigation::SeekBehavior;
+use crate::ship_items::BilgePump;
+
+/// Enables an entity to navigate towards the nearest `BilgePump` target, claiming it on the way
+/// if it is `Claimable`.
+pub type SeekBilgePumpBehavior = SeekBehavior<BilgePump>;
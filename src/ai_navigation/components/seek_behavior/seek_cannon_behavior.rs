@@ -0,0 +1,6 @@
+use crate::ai_navigation::SeekBehavior;
+use crate::gunnery::Cannon;
+
+/// Enables an entity to navigate towards the nearest `Cannon` target, claiming it on the way
+/// if it is `Claimable`.
+pub type SeekCannonBehavior = SeekBehavior<Cannon>;
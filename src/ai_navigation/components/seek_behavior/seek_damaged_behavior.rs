@@ -0,0 +1,6 @@
+use crate::ai_navigation::SeekBehavior;
+use crate::damage::Damaged;
+
+/// Enables an entity to navigate towards the nearest `Damaged` target, claiming it on the way
+/// if it is `Claimable`.
+pub type SeekDamagedBehavior = SeekBehavior<Damaged>;
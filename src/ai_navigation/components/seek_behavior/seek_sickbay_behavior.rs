@@ -0,0 +1,6 @@
+use crate::ai_navigation::SeekBehavior;
+use crate::ship_items::Sickbay;
+
+/// Enables an entity to navigate towards the nearest `Sickbay` target, claiming it on the way
+/// if it is `Claimable`.
+pub type SeekSickbayBehavior = SeekBehavior<Sickbay>;
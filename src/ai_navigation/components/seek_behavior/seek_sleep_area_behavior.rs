@@ -6,10 +6,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::ship_items::SleepArea;
 
-/// Component for entities that seek the nearest `SleepArea` target.
+/// Component for entities that seek a free berth in the nearest `SleepArea`.
 ///
 /// This component is used in conjunction with an `ActionBuilder` to create actions
-/// where an entity will navigate towards the nearest `SleepArea` target.
+/// where an entity will reserve a berth in the nearest `SleepArea` with room and navigate
+/// towards it.
 ///
 /// # Fields
 /// - `_marker`: A phantom data marker to hold the type `SleepArea`.
@@ -26,7 +27,7 @@ impl ActionBuilder for SeekSleepAreaBehavior {
     /// Attaches the `SeekSleepAreaBehavior` component to the specified actor entity.
     ///
     /// This method is used by the `ActionBuilder` trait to add the `SeekSleepAreaBehavior` component
-    /// to an entity, enabling it to move towards the nearest `SleepArea` target.
+    /// to an entity, enabling it to move towards a free berth in the nearest `SleepArea`.
     ///
     /// # Parameters
     /// - `cmd`: The `Commands` object used to issue commands to the ECS.
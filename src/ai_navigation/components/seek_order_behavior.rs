@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Component for entities that seek the target of the order the player gave them.
+///
+/// This component is used as an action where an entity will navigate towards the station,
+/// compartment or task named by its `CrewOrder`.
+///
+/// # Fields
+/// - `speed`: The movement speed of the entity.
+#[derive(
+    Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ActionBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct SeekOrderBehavior {
+    pub speed: f32,
+}
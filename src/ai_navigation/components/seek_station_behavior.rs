@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Component for entities that seek the station the duty roster assigned them to.
+///
+/// This component is used as an action where an entity will navigate towards its own
+/// `Station`, rather than the nearest one.
+///
+/// # Fields
+/// - `speed`: The movement speed of the entity.
+#[derive(
+    Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ActionBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct SeekStationBehavior {
+    pub speed: f32,
+}
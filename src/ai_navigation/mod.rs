@@ -3,8 +3,10 @@ use bevy::prelude::*;
 pub use components::*;
 use systems::*;
 
+use crate::damage::Damaged;
 use crate::food::Food;
-use crate::ship_items::SleepArea;
+use crate::gunnery::Cannon;
+use crate::ship_items::{BilgePump, Sickbay};
 
 mod components;
 mod constants;
@@ -15,29 +17,59 @@ mod utils;
 ///
 /// The AiNavigationPlugin provides functionality for handling the navigation
 /// behaviors of entities. It registers the necessary components and sets up
-/// systems to manage navigation towards food and sleep areas.
+/// systems to manage navigation towards food, sleep areas, bilge pumps, cannons, damage, the
+/// sickbay, each crew member's own station and the targets of the orders the player gives.
 ///
 /// # Components
+/// - SeekBilgePumpBehavior: Enables an entity to navigate towards the nearest `BilgePump` target,
+///   defining the movement speed.
+/// - SeekCannonBehavior: Enables an entity to navigate towards the nearest `Cannon` target,
+///   defining the movement speed.
+/// - SeekDamagedBehavior: Enables an entity to navigate towards the nearest `Damaged` target,
+///   defining the movement speed.
 /// - SeekFoodBehavior: Enables an entity to navigate towards the nearest `Food` target,
 ///   defining the movement speed.
-/// - SeekSleepAreaBehavior: Enables an entity to navigate towards the nearest `SleepArea` target,
+/// - SeekSickbayBehavior: Enables an entity to navigate towards the nearest `Sickbay` target,
+///   defining the movement speed.
+/// - SeekSleepAreaBehavior: Enables an entity to navigate towards a free berth in the nearest
+///   `SleepArea`, defining the movement speed.
+/// - SeekOrderBehavior: Enables an entity to navigate towards the target of its `CrewOrder`,
+///   defining the movement speed.
+/// - SeekStationBehavior: Enables an entity to navigate towards the `Station` it is assigned to,
 ///   defining the movement speed.
 /// - NavigationPath: Stores a sequence of points that define a navigation path in 3D space.
 ///
 /// # Systems
-/// - navigate_to_nearest: Manages the navigation of entities towards the nearest target of a specified type.
+/// - navigate_to_nearest: Manages the navigation of entities towards the nearest target of a specified type,
+///   claiming it and passing over targets that others have claimed.
+/// - navigate_to_order: Manages the navigation of entities towards the targets of their orders.
+/// - navigate_to_sleep_area: Manages the navigation of entities towards a berth they claim in
+///   the nearest sleep area with room, or has them turn in on deck when every berth is taken.
+/// - navigate_to_station: Manages the navigation of entities towards their assigned station.
 pub struct AiNavigationPlugin;
 
 impl Plugin for AiNavigationPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<SeekFoodBehavior>()
+        app.register_type::<SeekBilgePumpBehavior>()
+            .register_type::<SeekCannonBehavior>()
+            .register_type::<SeekDamagedBehavior>()
+            .register_type::<SeekFoodBehavior>()
+            .register_type::<SeekSickbayBehavior>()
             .register_type::<SeekSleepAreaBehavior>()
+            .register_type::<SeekOrderBehavior>()
+            .register_type::<SeekStationBehavior>()
             .register_type::<NavigationPath>()
             .add_systems(
                 PreUpdate,
                 (
-                    navigate_to_nearest::<SleepArea>,
                     navigate_to_nearest::<Food>,
+                    navigate_to_nearest::<BilgePump>,
+                    navigate_to_nearest::<Cannon>,
+                    navigate_to_nearest::<Damaged>,
+                    navigate_to_nearest::<Sickbay>,
+                    navigate_to_sleep_area,
+                    navigate_to_station,
+                    navigate_to_order,
                 ),
             );
     }
@@ -1,3 +1,8 @@
-pub use navigate_to_nearest::*;
+pub use {
+    navigate_to_nearest::*, navigate_to_order::*, navigate_to_sleep_area::*, navigate_to_station::*,
+};
 
 mod navigate_to_nearest;
+mod navigate_to_order;
+mod navigate_to_sleep_area;
+mod navigate_to_station;
@@ -1,12 +1,15 @@
 use bevy::log::debug;
-use bevy::prelude::{Component, Query, Res, Time, Transform, With, Without};
+use bevy::prelude::{
+    Component, Entity, GlobalTransform, Mut, Parent, Query, Res, Time, Transform, With, Without,
+};
 use big_brain::actions::ActionState;
 use big_brain::prelude::{ActionSpan, Actor, HasThinker};
 use oxidized_navigation::{NavMesh, NavMeshSettings};
 
-use crate::ai_navigation::constants::REACHED_POINT_THRESHOLD;
-use crate::ai_navigation::utils::find_navigation_path;
+use crate::ai_navigation::utils::{find_navigation_path, follow_navigation_path};
 use crate::ai_navigation::{NavigationPath, SeekBehavior};
+use crate::claims::{release_claims, Claimable, CLAIM_TIMEOUT};
+use crate::crew_management::{is_aboard_own_ship, ShipCrew};
 
 /// System to navigate actors towards the nearest target of a specified type.
 ///
@@ -14,22 +17,35 @@ use crate::ai_navigation::{NavigationPath, SeekBehavior};
 /// towards the nearest entity of type `T`. The system is integrated with the
 /// `big_brain` crate to handle the state transitions and pathfinding logic.
 ///
+/// Targets that are `Claimable` are claimed by the actor as it heads for them, so that other
+/// actors pass them over once they are full, and the claim is renewed for as long as the
+/// actor keeps moving. The claim is released if the actor fails to get there or the movement
+/// is cancelled. Once the actor arrives, the action that follows holds on to the claim.
+///
+/// Targets aboard a ship are only sought out by that ship's own crew, so the crews of different
+/// ships never go looking for each other's cannons, pumps or hammocks.
+///
 /// # Parameters
 /// - `time`: Resource to access the delta time between frames.
 /// - `nav_mesh`: Resource containing the navigation mesh for pathfinding.
 /// - `nav_mesh_settings`: Resource with settings for the navigation mesh.
-/// - `goal_query`: Query to retrieve the transforms of target entities of type `T`.
+/// - `goal_query`: Query to retrieve the entities, global transforms and claims of target
+///   entities of type `T`, which may be nested inside other entities such as a ship.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each target.
+/// - `ships`: Query to retrieve the crew of ships.
 /// - `thinker_query`: Query to retrieve the navigation path and transform of the actor entities with the `HasThinker` component, excluding target entities.
 /// - `action_query`: Query to retrieve the actors and their action states, along with the `MoveToNearest` component and the action span.
 ///
 /// # Type Parameters
 /// - `T`: Component type that represents the target entities to navigate to.
-#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn navigate_to_nearest<T: Component + std::fmt::Debug + Clone>(
     time: Res<Time>,
     nav_mesh: Res<NavMesh>,
     nav_mesh_settings: Res<NavMeshSettings>,
-    goal_query: Query<&Transform, With<T>>,
+    mut goal_query: Query<(Entity, &GlobalTransform, Option<&mut Claimable>), With<T>>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
     mut thinker_query: Query<(&mut NavigationPath, &mut Transform), (With<HasThinker>, Without<T>)>,
     mut action_query: Query<(&Actor, &mut ActionState, &SeekBehavior<T>, &ActionSpan)>,
 ) {
@@ -43,58 +59,83 @@ pub fn navigate_to_nearest<T: Component + std::fmt::Debug + Clone>(
                     *action_state = ActionState::Executing;
                 }
                 ActionState::Executing => {
-                    let goal_transform = goal_query
-                        .iter()
-                        .map(|t| (t.translation, t))
-                        .min_by(|(a, _), (b, _)| {
-                            a.distance_squared(actor_transform.translation)
-                                .partial_cmp(&b.distance_squared(actor_transform.translation))
-                                .unwrap()
+                    let actor_translation = actor_transform.translation;
+                    let is_claimed_by_actor = |claimable: &Option<Mut<Claimable>>| {
+                        claimable
+                            .as_ref()
+                            .is_some_and(|claimable| claimable.is_claimed_by(*actor))
+                    };
+
+                    // Head for the target already claimed, or else the nearest one with room
+                    let Some((_, goal_transform, claimable)) = goal_query
+                        .iter_mut()
+                        .filter(|(goal, _, claimable)| {
+                            claimable
+                                .as_ref()
+                                .is_none_or(|claimable| claimable.has_room(*actor))
+                                && is_aboard_own_ship(*actor, *goal, &parent_query, &ships)
                         })
-                        .map(|(_, t)| t);
+                        .min_by(|(_, a, a_claimable), (_, b, b_claimable)| {
+                            is_claimed_by_actor(b_claimable)
+                                .cmp(&is_claimed_by_actor(a_claimable))
+                                .then(
+                                    a.translation()
+                                        .distance_squared(actor_translation)
+                                        .total_cmp(
+                                            &b.translation().distance_squared(actor_translation),
+                                        ),
+                                )
+                        })
+                    else {
+                        debug!("There is no free {:?} left.", std::any::type_name::<T>());
+                        *action_state = ActionState::Failure;
+                        continue;
+                    };
+
+                    let goal_translation = goal_transform.translation();
+                    if let Some(mut claimable) = claimable {
+                        claimable.claim(*actor, CLAIM_TIMEOUT);
+                    }
 
                     if navigation_path.points.is_empty() {
                         if let Some(new_path) = find_navigation_path(
                             &nav_mesh,
                             &nav_mesh_settings,
                             actor_transform.translation,
-                            goal_transform.unwrap().translation,
+                            goal_translation,
                         ) {
                             debug!("Updating navigation path.");
                             navigation_path.points = new_path;
                         } else {
+                            release_claims(
+                                *actor,
+                                goal_query
+                                    .iter_mut()
+                                    .filter_map(|(_, _, claimable)| claimable),
+                            );
                             *action_state = ActionState::Failure;
                             continue;
                         }
                     }
 
-                    // Check if we have a path to follow
-                    if let Some(next_point) = navigation_path.points.first() {
-                        let direction = (*next_point - actor_transform.translation).normalize();
-                        let distance_to_next_point =
-                            (*next_point - actor_transform.translation).length();
-
-                        // Check if we are close enough to the next point to consider it reached
-                        if distance_to_next_point < REACHED_POINT_THRESHOLD {
-                            // Remove the reached point from the navigation path
-                            navigation_path.points.remove(0);
-
-                            // If after removing the point, the path is empty, we've reached the end
-                            if navigation_path.points.is_empty() {
-                                debug!("Reached end of path.");
-                                *action_state = ActionState::Success;
-                                continue;
-                            }
-                        } else {
-                            // Move towards the next point
-                            let step_size = time.delta_seconds() * move_to.speed;
-                            let step = direction * step_size.min(distance_to_next_point);
-                            actor_transform.translation += step;
-                        }
+                    if follow_navigation_path(
+                        &mut navigation_path,
+                        &mut actor_transform,
+                        move_to.speed,
+                        time.delta_seconds(),
+                    ) {
+                        debug!("Reached end of path.");
+                        *action_state = ActionState::Success;
                     }
                 }
                 ActionState::Cancelled => {
                     debug!("Moving to is cancelled.");
+                    release_claims(
+                        *actor,
+                        goal_query
+                            .iter_mut()
+                            .filter_map(|(_, _, claimable)| claimable),
+                    );
                     *action_state = ActionState::Failure;
                 }
                 _ => {}
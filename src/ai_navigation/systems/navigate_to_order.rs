@@ -0,0 +1,112 @@
+use bevy::log::debug;
+use bevy::prelude::{DetectChanges, GlobalTransform, Query, Ref, Res, Time, Transform, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor, HasThinker};
+use oxidized_navigation::{NavMesh, NavMeshSettings};
+
+use crate::ai_navigation::utils::{find_navigation_path, follow_navigation_path};
+use crate::ai_navigation::{NavigationPath, SeekOrderBehavior};
+use crate::buoyancy_physics::Buoyancy;
+use crate::crew_management::STATION_DISTANCE;
+use crate::crew_orders::{calculate_order_destination, CrewOrder};
+use crate::flooding::Flooding;
+
+/// System to navigate actors towards the target of the order the player gave them.
+///
+/// This system uses navigation meshes to calculate paths for actors to move towards the
+/// station, compartment or task named by their `CrewOrder`. The path is recalculated when the
+/// order changes on the way. An actor with no order, or whose target no longer exists, fails
+/// to navigate.
+///
+/// # Parameters
+/// - `time`: Resource to access the delta time between frames.
+/// - `nav_mesh`: Resource containing the navigation mesh for pathfinding.
+/// - `nav_mesh_settings`: Resource with settings for the navigation mesh.
+/// - `orders`: Query to retrieve the orders of the actors.
+/// - `transforms`: Query to retrieve the global transforms of order targets.
+/// - `hulls`: Query to retrieve the flooding state and voxels of hulls.
+/// - `thinker_query`: Query to retrieve the navigation path and transform of the actor entities with the `HasThinker` component.
+/// - `action_query`: Query to retrieve the actors and their action states, along with the `SeekOrderBehavior` component and the action span.
+#[allow(clippy::too_many_arguments)]
+pub fn navigate_to_order(
+    time: Res<Time>,
+    nav_mesh: Res<NavMesh>,
+    nav_mesh_settings: Res<NavMeshSettings>,
+    orders: Query<Ref<CrewOrder>>,
+    transforms: Query<&GlobalTransform>,
+    hulls: Query<(&Flooding, &Buoyancy)>,
+    mut thinker_query: Query<(&mut NavigationPath, &mut Transform), With<HasThinker>>,
+    mut action_query: Query<(&Actor, &mut ActionState, &SeekOrderBehavior, &ActionSpan)>,
+) {
+    for (Actor(actor), mut action_state, move_to, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        let Ok((mut navigation_path, mut actor_transform)) = thinker_query.get_mut(*actor) else {
+            continue;
+        };
+
+        match *action_state {
+            ActionState::Requested => {
+                debug!("Following orders.");
+                navigation_path.points.clear();
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Ok(order) = orders.get(*actor) else {
+                    debug!("No orders to follow.");
+                    *action_state = ActionState::Failure;
+                    continue;
+                };
+
+                let Some(destination) =
+                    calculate_order_destination(&order.target, &transforms, &hulls)
+                else {
+                    debug!("The target of my orders is gone.");
+                    *action_state = ActionState::Failure;
+                    continue;
+                };
+
+                if order.is_changed() {
+                    navigation_path.points.clear();
+                }
+
+                if actor_transform.translation.distance(destination) <= STATION_DISTANCE {
+                    debug!("Reached the target of my orders.");
+                    navigation_path.points.clear();
+                    *action_state = ActionState::Success;
+                    continue;
+                }
+
+                if navigation_path.points.is_empty() {
+                    if let Some(new_path) = find_navigation_path(
+                        &nav_mesh,
+                        &nav_mesh_settings,
+                        actor_transform.translation,
+                        destination,
+                    ) {
+                        debug!("Updating navigation path.");
+                        navigation_path.points = new_path;
+                    } else {
+                        *action_state = ActionState::Failure;
+                        continue;
+                    }
+                }
+
+                if follow_navigation_path(
+                    &mut navigation_path,
+                    &mut actor_transform,
+                    move_to.speed,
+                    time.delta_seconds(),
+                ) {
+                    debug!("Reached end of path.");
+                    *action_state = ActionState::Success;
+                }
+            }
+            ActionState::Cancelled => {
+                debug!("Following orders is cancelled.");
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::log::debug;
+use bevy::prelude::{Entity, GlobalTransform, Parent, Query, Res, Time, Transform, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor, HasThinker};
+use oxidized_navigation::{NavMesh, NavMeshSettings};
+
+use crate::ai_navigation::utils::{find_navigation_path, follow_navigation_path};
+use crate::ai_navigation::{NavigationPath, SeekSleepAreaBehavior};
+use crate::claims::{release_claims, Claimable, CLAIM_TIMEOUT};
+use crate::crew_management::{is_aboard_own_ship, ShipCrew};
+use crate::ship_items::{SleepArea, SLEEP_AREA_DISTANCE};
+
+/// System to navigate actors towards a free berth in the nearest `SleepArea`.
+///
+/// When an actor sets off to sleep, it claims a berth in the nearest sleep area aboard its own
+/// ship that still has room, falling back to the next nearest area when the nearest is full,
+/// and navigates towards it using navigation meshes, renewing its claim on the way. An actor
+/// that finds every berth taken turns in on deck where it stands. The claim is released if the
+/// actor fails to get there or the movement is cancelled.
+///
+/// # Parameters
+/// - `time`: Resource to access the delta time between frames.
+/// - `nav_mesh`: Resource containing the navigation mesh for pathfinding.
+/// - `nav_mesh_settings`: Resource with settings for the navigation mesh.
+/// - `sleep_areas`: Query to retrieve and modify the claims on the sleep areas, along with their global transforms.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each sleep area.
+/// - `ships`: Query to retrieve the crew of ships.
+/// - `thinker_query`: Query to retrieve the navigation path and transform of the actor entities with the `HasThinker` component.
+/// - `action_query`: Query to retrieve the actors and their action states, along with the `SeekSleepAreaBehavior` component and the action span.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn navigate_to_sleep_area(
+    time: Res<Time>,
+    nav_mesh: Res<NavMesh>,
+    nav_mesh_settings: Res<NavMeshSettings>,
+    mut sleep_areas: Query<(Entity, &mut Claimable, &GlobalTransform), With<SleepArea>>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
+    mut thinker_query: Query<(&mut NavigationPath, &mut Transform), With<HasThinker>>,
+    mut action_query: Query<(
+        &Actor,
+        &mut ActionState,
+        &SeekSleepAreaBehavior,
+        &ActionSpan,
+    )>,
+) {
+    for (Actor(actor), mut action_state, move_to, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        if !thinker_query.contains(*actor) {
+            continue;
+        }
+
+        match *action_state {
+            ActionState::Requested => {
+                let Ok((mut navigation_path, actor_transform)) = thinker_query.get_mut(*actor)
+                else {
+                    continue;
+                };
+                navigation_path.points.clear();
+
+                let actor_translation = actor_transform.translation;
+                let nearest = sleep_areas
+                    .iter_mut()
+                    .filter(|(sleep_area, claimable, _)| {
+                        claimable.has_room(*actor)
+                            && is_aboard_own_ship(*actor, *sleep_area, &parent_query, &ships)
+                    })
+                    .min_by(|(_, _, a), (_, _, b)| {
+                        a.translation()
+                            .distance_squared(actor_translation)
+                            .total_cmp(&b.translation().distance_squared(actor_translation))
+                    });
+
+                match nearest {
+                    Some((_, mut claimable, _)) => {
+                        debug!("Heading for my berth.");
+                        claimable.claim(*actor, CLAIM_TIMEOUT);
+                        *action_state = ActionState::Executing;
+                    }
+                    None => {
+                        debug!("Every berth is taken, turning in on deck.");
+                        *action_state = ActionState::Success;
+                    }
+                }
+            }
+            ActionState::Executing => {
+                let Ok((mut navigation_path, mut actor_transform)) = thinker_query.get_mut(*actor)
+                else {
+                    continue;
+                };
+
+                let Some((_, mut claimable, berth_transform)) = sleep_areas
+                    .iter_mut()
+                    .find(|(_, claimable, _)| claimable.is_claimed_by(*actor))
+                else {
+                    debug!("My berth is gone.");
+                    *action_state = ActionState::Failure;
+                    continue;
+                };
+                claimable.renew(*actor, CLAIM_TIMEOUT);
+                let berth_translation = berth_transform.translation();
+
+                if actor_transform.translation.distance(berth_translation) <= SLEEP_AREA_DISTANCE {
+                    debug!("Reached my berth.");
+                    navigation_path.points.clear();
+                    *action_state = ActionState::Success;
+                    continue;
+                }
+
+                if navigation_path.points.is_empty() {
+                    if let Some(new_path) = find_navigation_path(
+                        &nav_mesh,
+                        &nav_mesh_settings,
+                        actor_transform.translation,
+                        berth_translation,
+                    ) {
+                        debug!("Updating navigation path.");
+                        navigation_path.points = new_path;
+                    } else {
+                        release_claims(
+                            *actor,
+                            sleep_areas.iter_mut().map(|(_, claimable, _)| claimable),
+                        );
+                        *action_state = ActionState::Failure;
+                        continue;
+                    }
+                }
+
+                if follow_navigation_path(
+                    &mut navigation_path,
+                    &mut actor_transform,
+                    move_to.speed,
+                    time.delta_seconds(),
+                ) {
+                    debug!("Reached end of path.");
+                    *action_state = ActionState::Success;
+                }
+            }
+            ActionState::Cancelled => {
+                debug!("Moving to my berth is cancelled.");
+                release_claims(
+                    *actor,
+                    sleep_areas.iter_mut().map(|(_, claimable, _)| claimable),
+                );
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use bevy::log::debug;
+use bevy::prelude::{GlobalTransform, Query, Res, Time, Transform, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor, HasThinker};
+use oxidized_navigation::{NavMesh, NavMeshSettings};
+
+use crate::ai_navigation::utils::{find_navigation_path, follow_navigation_path};
+use crate::ai_navigation::{NavigationPath, SeekStationBehavior};
+use crate::crew_management::{Station, STATION_DISTANCE};
+
+/// System to navigate actors towards the station the duty roster assigned them to.
+///
+/// This system uses navigation meshes to calculate paths for actors to move towards the
+/// `Station` whose crew they are. An actor already at its station arrives straight away, and
+/// an actor with no station assigned fails to navigate.
+///
+/// # Parameters
+/// - `time`: Resource to access the delta time between frames.
+/// - `nav_mesh`: Resource containing the navigation mesh for pathfinding.
+/// - `nav_mesh_settings`: Resource with settings for the navigation mesh.
+/// - `stations`: Query to retrieve the stations and their global transforms.
+/// - `thinker_query`: Query to retrieve the navigation path and transform of the actor entities with the `HasThinker` component.
+/// - `action_query`: Query to retrieve the actors and their action states, along with the `SeekStationBehavior` component and the action span.
+#[allow(clippy::type_complexity)]
+pub fn navigate_to_station(
+    time: Res<Time>,
+    nav_mesh: Res<NavMesh>,
+    nav_mesh_settings: Res<NavMeshSettings>,
+    stations: Query<(&Station, &GlobalTransform)>,
+    mut thinker_query: Query<(&mut NavigationPath, &mut Transform), With<HasThinker>>,
+    mut action_query: Query<(&Actor, &mut ActionState, &SeekStationBehavior, &ActionSpan)>,
+) {
+    for (Actor(actor), mut action_state, move_to, span) in &mut action_query {
+        let _guard = span.span().enter();
+
+        let Ok((mut navigation_path, mut actor_transform)) = thinker_query.get_mut(*actor) else {
+            continue;
+        };
+
+        match *action_state {
+            ActionState::Requested => {
+                debug!("Heading to my station.");
+                navigation_path.points.clear();
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Some(station_translation) = stations
+                    .iter()
+                    .find(|(station, _)| station.crew == Some(*actor))
+                    .map(|(_, transform)| transform.translation())
+                else {
+                    debug!("No station assigned.");
+                    *action_state = ActionState::Failure;
+                    continue;
+                };
+
+                if actor_transform.translation.distance(station_translation) <= STATION_DISTANCE {
+                    debug!("Reached my station.");
+                    navigation_path.points.clear();
+                    *action_state = ActionState::Success;
+                    continue;
+                }
+
+                if navigation_path.points.is_empty() {
+                    if let Some(new_path) = find_navigation_path(
+                        &nav_mesh,
+                        &nav_mesh_settings,
+                        actor_transform.translation,
+                        station_translation,
+                    ) {
+                        debug!("Updating navigation path.");
+                        navigation_path.points = new_path;
+                    } else {
+                        *action_state = ActionState::Failure;
+                        continue;
+                    }
+                }
+
+                if follow_navigation_path(
+                    &mut navigation_path,
+                    &mut actor_transform,
+                    move_to.speed,
+                    time.delta_seconds(),
+                ) {
+                    debug!("Reached end of path.");
+                    *action_state = ActionState::Success;
+                }
+            }
+            ActionState::Cancelled => {
+                debug!("Moving to station is cancelled.");
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
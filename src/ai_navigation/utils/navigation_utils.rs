@@ -1,7 +1,11 @@
 use bevy::math::Vec3;
+use bevy::prelude::Transform;
 use oxidized_navigation::query::{find_polygon_path, perform_string_pulling_on_path};
 use oxidized_navigation::{NavMesh, NavMeshSettings};
 
+use crate::ai_navigation::constants::REACHED_POINT_THRESHOLD;
+use crate::ai_navigation::NavigationPath;
+
 /// Finds a navigation path from the start position to the goal position using the navigation mesh.
 ///
 /// This function integrates with the `oxidized_navigation` crate to perform pathfinding
@@ -50,3 +54,46 @@ pub fn find_navigation_path(
         Err(_) => None,
     }
 }
+
+/// Moves an actor one step along its navigation path.
+///
+/// The actor moves towards the next point of the path at the given speed. Points are removed
+/// from the path as they are reached.
+///
+/// # Parameters
+/// - `navigation_path`: The path the actor is following.
+/// - `transform`: The transform of the actor, moved along the path.
+/// - `speed`: The movement speed of the actor.
+/// - `delta_seconds`: The time elapsed since the last step.
+///
+/// # Returns
+/// `true` once the last point of the path has been reached, `false` otherwise.
+pub fn follow_navigation_path(
+    navigation_path: &mut NavigationPath,
+    transform: &mut Transform,
+    speed: f32,
+    delta_seconds: f32,
+) -> bool {
+    // Check if we have a path to follow
+    let Some(next_point) = navigation_path.points.first() else {
+        return false;
+    };
+
+    let direction = (*next_point - transform.translation).normalize();
+    let distance_to_next_point = (*next_point - transform.translation).length();
+
+    // Check if we are close enough to the next point to consider it reached
+    if distance_to_next_point < REACHED_POINT_THRESHOLD {
+        // Remove the reached point from the navigation path
+        navigation_path.points.remove(0);
+
+        // If after removing the point, the path is empty, we've reached the end
+        navigation_path.points.is_empty()
+    } else {
+        // Move towards the next point
+        let step_size = delta_seconds * speed;
+        let step = direction * step_size.min(distance_to_next_point);
+        transform.translation += step;
+        false
+    }
+}
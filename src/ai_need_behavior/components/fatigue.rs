@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ai_need_behavior::{NeedKind, NeedScorer, SatisfyNeed};
+
+/// Marker for the need to sleep.
+///
+/// Fatigue grows steadily and is satisfied by sleeping in a berth of the nearest `SleepArea`
+/// with room, or less well on deck when every berth is taken.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct Fatigue;
+
+impl NeedKind for Fatigue {
+    const NAME: &'static str = "Fatigue";
+    const PRESSING_LEVEL: f32 = 80.0;
+}
+
+/// Calculates a score indicating the urgency for an entity to sleep.
+pub type FatigueScorer = NeedScorer<Fatigue>;
+
+/// Manages the sleeping action of an entity.
+pub type Sleep = SatisfyNeed<Fatigue>;
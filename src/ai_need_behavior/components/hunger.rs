@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ai_need_behavior::{NeedKind, NeedScorer, SatisfyNeed};
+
+/// Marker for the need to eat.
+///
+/// Hunger grows steadily and is satisfied by eating from the food stores aboard the ship. Once
+/// the stores have run out the urge to eat is set aside, and the crew starve.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+pub struct Hunger;
+
+impl NeedKind for Hunger {
+    const NAME: &'static str = "Hunger";
+    const PRESSING_LEVEL: f32 = 100.0;
+}
+
+/// Calculates a score indicating the urgency for an entity to eat.
+pub type HungerScorer = NeedScorer<Hunger>;
+
+/// Manages the eating action of an entity.
+pub type Eat = SatisfyNeed<Hunger>;
@@ -0,0 +1,8 @@
+pub use {fatigue::*, hunger::*, need::*, need_kind::*, need_scorer::*, satisfy_need::*};
+
+mod fatigue;
+mod hunger;
+mod need;
+mod need_kind;
+mod need_scorer;
+mod satisfy_need;
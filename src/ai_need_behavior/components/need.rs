@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::ai_need_behavior::{NeedKind, MAX_NEED_LEVEL};
+
+/// Represents the level of a need of an entity, such as its hunger or fatigue.
+///
+/// The `Need` component is used to manage a need of an entity in the game. It indicates
+/// whether the entity is currently satisfying the need, how quickly the need grows per second,
+/// and the current level of the need, from `0.0` to `MAX_NEED_LEVEL`.
+///
+/// # Type Parameters
+/// - `T`: The kind of need, a marker type implementing `NeedKind`.
+///
+/// # Fields
+/// - `is_satisfying`: A boolean indicating whether the entity is currently satisfying the need.
+/// - `per_second`: The rate at which the need grows per second.
+/// - `level`: The current level of the need.
+/// - `_marker`: A phantom data marker to hold the type `T`.
+///
+/// # Methods
+/// - `new(per_second: f32) -> Self`: Creates a need that starts out fully satisfied.
+/// - `grow(&mut self, seconds: f32)`: Grows the need over the given time, up to `MAX_NEED_LEVEL`.
+/// - `relieve(&mut self, amount: f32)`: Relieves the need by the given amount, down to `0.0`.
+#[derive(Component, Debug, Reflect, Default)]
+#[reflect(Component)]
+pub struct Need<T: NeedKind> {
+    /// A boolean indicating whether the entity is currently satisfying the need.
+    pub is_satisfying: bool,
+    /// The rate at which the need grows per second.
+    pub per_second: f32,
+    /// The current level of the need.
+    pub level: f32,
+    #[reflect(ignore)]
+    pub _marker: PhantomData<T>,
+}
+
+impl<T: NeedKind> Need<T> {
+    /// Creates a need that starts out fully satisfied and grows at the given rate.
+    pub fn new(per_second: f32) -> Self {
+        Self {
+            is_satisfying: false,
+            per_second,
+            level: 0.0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Grows the need over the given number of seconds, up to `MAX_NEED_LEVEL`.
+    pub fn grow(&mut self, seconds: f32) {
+        self.level = (self.level + self.per_second * seconds).min(MAX_NEED_LEVEL);
+    }
+
+    /// Relieves the need by the given amount, down to `0.0`.
+    pub fn relieve(&mut self, amount: f32) {
+        self.level = (self.level - amount).max(0.0);
+    }
+}
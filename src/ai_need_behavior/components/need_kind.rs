@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// Describes a kind of need that entities have, such as hunger or fatigue.
+///
+/// Each kind of need is a marker type implementing this trait. The marker is the type
+/// parameter of the generic `Need`, `NeedScorer` and `SatisfyNeed` components, and the
+/// `NeedPlugin` for it sets up the systems that grow, score and satisfy the need.
+///
+/// # Constants
+/// - `NAME`: The name of the need, as it appears in logs.
+/// - `PRESSING_LEVEL`: The level at or above which the need is pressing.
+pub trait NeedKind: Reflect + TypePath + Default + Clone + std::fmt::Debug {
+    /// The name of the need, as it appears in logs.
+    const NAME: &'static str;
+    /// The level at or above which the need is pressing.
+    const PRESSING_LEVEL: f32;
+}
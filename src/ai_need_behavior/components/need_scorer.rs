@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ai_need_behavior::NeedKind;
+use crate::utils::ScoreHysteresis;
+
+/// This component serves as a scorer for evaluating the entity's urge to satisfy a need based
+/// on the level of the need.
+///
+/// The `NeedScorer` component is used to calculate a score that indicates the urgency for an
+/// entity to satisfy its `Need` of kind `T`. A higher score means a more pressing need. Once
+/// the need is pressing enough, the score is held until the need has been satisfied, so that
+/// the entity is not drawn away as soon as the need starts to ease.
+///
+/// # Type Parameters
+/// - `T`: The kind of need, a marker type implementing `NeedKind`.
+///
+/// # Fields
+/// - `hysteresis`: The thresholds at which the score starts and stops being held, along with
+///   the score held for the entity.
+/// - `_marker`: A phantom data marker to hold the type `T`.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct NeedScorer<T: NeedKind> {
+    pub hysteresis: ScoreHysteresis,
+    #[reflect(ignore)]
+    pub _marker: PhantomData<T>,
+}
+
+impl<T: NeedKind> NeedScorer<T> {
+    /// Creates a scorer whose score is held from when it reaches `enter` until it falls to
+    /// `exit`.
+    pub fn new(enter: f32, exit: f32) -> Self {
+        Self {
+            hysteresis: ScoreHysteresis::new(enter, exit),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: NeedKind> ScorerBuilder for NeedScorer<T> {
+    /// Attaches the `NeedScorer` component to the specified scorer entity.
+    ///
+    /// # Parameters
+    /// - `cmd`: The `Commands` object used to issue commands to the ECS.
+    /// - `scorer`: The entity representing the scorer.
+    /// - `_actor`: The entity the scorer scores for.
+    fn build(&self, cmd: &mut Commands, scorer: Entity, _actor: Entity) {
+        cmd.entity(scorer).insert(self.clone());
+    }
+}
@@ -0,0 +1,67 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::ai_need_behavior::NeedKind;
+
+/// Represents an entity satisfying one of its needs, such as by eating or sleeping.
+///
+/// The `SatisfyNeed` component is used to manage the action of satisfying a `Need` of kind `T`.
+/// It defines when the entity will stop based on the level of the need and how quickly the
+/// need is relieved meanwhile. How well the entity is seeing to its need, such as what it eats
+/// or where it sleeps, is set as the `rate` by the systems that decide it, before the action
+/// relieves the need.
+///
+/// # Type Parameters
+/// - `T`: The kind of need, a marker type implementing `NeedKind`.
+///
+/// # Fields
+/// - `until`: The level of the need at which the entity will stop. When the level of the need
+///   drops to or below this value, the need is satisfied.
+/// - `per_second`: The rate at which the level of the need decreases while it is satisfied.
+/// - `rate`: The share of `per_second` the need is currently relieved at, `1.0` unless what the
+///   entity eats or where it sleeps makes it more or less.
+/// - `_marker`: A phantom data marker to hold the type `T`.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct SatisfyNeed<T: NeedKind> {
+    /// The level of the need at which the entity will stop.
+    pub until: f32,
+    /// The rate at which the level of the need decreases.
+    pub per_second: f32,
+    /// The share of `per_second` the need is currently relieved at.
+    pub rate: f32,
+    #[reflect(ignore)]
+    pub _marker: PhantomData<T>,
+}
+
+impl<T: NeedKind> SatisfyNeed<T> {
+    /// Creates an action that relieves the need at the given rate until it drops to `until`.
+    pub fn new(until: f32, per_second: f32) -> Self {
+        Self {
+            until,
+            per_second,
+            rate: 1.0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: NeedKind> Default for SatisfyNeed<T> {
+    fn default() -> Self {
+        Self::new(0.0, 0.0)
+    }
+}
+
+impl<T: NeedKind> ActionBuilder for SatisfyNeed<T> {
+    /// Attaches the `SatisfyNeed` component to the specified action entity.
+    ///
+    /// # Parameters
+    /// - `cmd`: The `Commands` object used to issue commands to the ECS.
+    /// - `action`: The entity representing the action.
+    /// - `_actor`: The entity to which the action will be attached.
+    fn build(&self, cmd: &mut Commands, action: Entity, _actor: Entity) {
+        cmd.entity(action).insert(self.clone());
+    }
+}
@@ -0,0 +1,3 @@
+pub use need_settings::*;
+
+mod need_settings;
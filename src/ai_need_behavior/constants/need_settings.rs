@@ -0,0 +1,5 @@
+/// The highest level a need can grow to, at which its score is highest.
+pub const MAX_NEED_LEVEL: f32 = 100.0;
+
+/// The share of its rest an entity goes without when it sleeps on deck instead of in a berth.
+pub const DECK_REST_PENALTY: f32 = 0.5;
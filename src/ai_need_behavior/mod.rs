@@ -0,0 +1,114 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy::reflect::GetTypeRegistration;
+
+pub use components::*;
+pub use constants::*;
+pub use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+use crate::claims::hold_claims;
+
+mod components;
+mod constants;
+mod systems;
+
+/// Plugin for managing AI need behavior within the game.
+///
+/// The AiNeedBehaviorPlugin provides functionality for handling the needs of entities, such as
+/// hunger and fatigue, and the behaviors that satisfy them. Each kind of need is a marker type
+/// implementing `NeedKind`, and is set up by its own `NeedPlugin`. Adding a new need only
+/// takes a new marker type and a `NeedPlugin` for it here.
+///
+/// # Components
+/// - Need: Tracks the level of a need of an entity, including whether it is being satisfied and
+///   how quickly the need grows.
+/// - NeedScorer: Calculates a score indicating the urgency for an entity to satisfy a need.
+/// - SatisfyNeed: Manages the action of satisfying a need, defining when it stops and how
+///   quickly the need is relieved.
+///
+/// # Needs
+/// - Fatigue: The need to sleep.
+/// - Hunger: The need to eat.
+///
+/// # Systems
+/// - eat_from_food_stores: Feeds eating entities from the food stores, setting the rate of the
+///   eating action by what they eat and failing it once the stores have run out.
+/// - rest_in_sleep_areas: Lets sleeping entities rest at the full rate in their berths, or at a
+///   lower rate on deck.
+/// - suppress_hunger_without_food: Takes away the urge to eat from entities with no food left
+///   aboard their ship.
+pub struct AiNeedBehaviorPlugin;
+
+impl Plugin for AiNeedBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            NeedPlugin::<Fatigue>::default(),
+            NeedPlugin::<Hunger>::default(),
+        ))
+        .add_systems(
+            Update,
+            eat_from_food_stores
+                .before(satisfy_need_action::<Hunger>)
+                .run_if(in_state(AppStates::Running)),
+        )
+        .add_systems(
+            Update,
+            suppress_hunger_without_food
+                .after(calculate_need_score::<Hunger>)
+                .run_if(in_state(AppStates::Running)),
+        )
+        .add_systems(
+            Update,
+            rest_in_sleep_areas
+                .before(satisfy_need_action::<Fatigue>)
+                .run_if(in_state(AppStates::Running)),
+        );
+    }
+}
+
+/// Plugin for a single kind of need.
+///
+/// The NeedPlugin registers the components for the need of kind `T` and sets up systems to
+/// manage its scoring, the action that satisfies it, and its growth over time.
+///
+/// # Type Parameters
+/// - `T`: The kind of need, a marker type implementing `NeedKind`.
+///
+/// # Systems
+/// - calculate_need_score: Calculates a score based on the level of an entity's need.
+/// - hold_claims: Holds on to the claims of entities, such as their berths, while they satisfy
+///   the need, releasing them once they stop.
+/// - increase_need: Increases the level of an entity's need over time.
+/// - satisfy_need_action: Manages the action of satisfying an entity's need, relieving it.
+#[derive(Default)]
+pub struct NeedPlugin<T: NeedKind> {
+    _marker: PhantomData<T>,
+}
+
+impl<T: NeedKind + GetTypeRegistration> Plugin for NeedPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Need<T>>()
+            .register_type::<NeedScorer<T>>()
+            .register_type::<SatisfyNeed<T>>()
+            .add_systems(
+                Update,
+                calculate_need_score::<T>.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                increase_need::<T>.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                satisfy_need_action::<T>.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                hold_claims::<SatisfyNeed<T>>
+                    .after(satisfy_need_action::<T>)
+                    .run_if(in_state(AppStates::Running)),
+            );
+    }
+}
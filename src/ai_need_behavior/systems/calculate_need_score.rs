@@ -0,0 +1,43 @@
+use bevy::log::trace;
+use bevy::prelude::{Query, Res};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_need_behavior::{Need, NeedKind, NeedScorer, MAX_NEED_LEVEL};
+use crate::crew_watches::{Watch, WatchSchedule};
+
+/// This system calculates a score based on the level of an entity's need of kind `T`. The
+/// higher the level, the higher the score, indicating a greater urge for the entity to satisfy
+/// the need.
+///
+/// The system iterates over entities with the `NeedScorer` component, fetches their `Need`
+/// component, and updates their `Score` component. The score is passed through the scorer's
+/// hysteresis, so that once the need is pressing the score is held until it has been
+/// satisfied. Each entity has its own scorer, so the score held for one entity never affects
+/// another.
+///
+/// The score is biased by the entity's `Watch`, so that needs feel more urgent while its watch
+/// is below and less urgent while its watch is on duty.
+///
+/// # Parameters
+/// - `schedule`: Resource describing which watch is on duty.
+/// - `needs`: A query to fetch the `Need` and `Watch` components of entities.
+/// - `query`: A query to fetch the `Actor`, `Score`, `NeedScorer`, and `ScorerSpan` components
+///   of scorer entities.
+pub fn calculate_need_score<T: NeedKind>(
+    schedule: Res<WatchSchedule>,
+    needs: Query<(&Need<T>, Option<&Watch>)>,
+    mut query: Query<(&Actor, &mut Score, &mut NeedScorer<T>, &ScorerSpan)>,
+) {
+    for (Actor(actor), mut score, mut scorer, span) in &mut query {
+        if let Ok((need, watch)) = needs.get(*actor) {
+            let urgency = need.level / MAX_NEED_LEVEL * schedule.need_bias(watch);
+            let new_score = scorer.hysteresis.apply(urgency.min(1.0));
+            score.set(new_score);
+
+            if need.level >= T::PRESSING_LEVEL {
+                span.span()
+                    .in_scope(|| trace!("{} above threshold! Score: {}", T::NAME, new_score));
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{GlobalTransform, Query, Res, Time, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor, HasThinker};
+
+use crate::ai_need_behavior::{Eat, Hunger, Need};
+use crate::crew_morale::{Morale, HOT_MEAL_MORALE_GAIN};
+use crate::food::{calculate_nourishment, eat_from_stockpile, FOOD_STORE_DISTANCE};
+use crate::production::{Goods, Stockpile};
+
+/// This system feeds eating entities from the stockpiles of food. While an entity eats, it
+/// takes the portions it eats from the most nourishing stockpile within reach that still has
+/// food, so a hot meal from the galley is eaten before raw rations. The entity eats portions
+/// at a steady pace, and the eating action relieves its hunger by the nourishment of what it
+/// eats rather than at its usual rate, so a meal satisfies more hunger than raw rations and
+/// also lifts the entity's morale. If there is no food within reach, the stores have run out
+/// and the eating action fails.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `actors`: Query to fetch the transforms of the acting entities.
+/// - `stockpiles`: Query to fetch and modify the stockpiles, along with their transforms.
+/// - `crew`: Query to fetch and modify the hunger and morale of the acting entities.
+/// - `query`: Query to fetch and modify the `ActionState`, `Eat`, and `ActionSpan` components
+///   of entities, as well as the `Actor` component to identify the entity.
+pub fn eat_from_food_stores(
+    time: Res<Time>,
+    actors: Query<&GlobalTransform, With<HasThinker>>,
+    mut stockpiles: Query<(&mut Stockpile, &GlobalTransform)>,
+    mut crew: Query<(&mut Need<Hunger>, Option<&mut Morale>)>,
+    mut query: Query<(&Actor, &mut ActionState, &mut Eat, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, mut eat, span) in &mut query {
+        let _guard = span.span().enter();
+
+        if *state != ActionState::Executing {
+            continue;
+        }
+
+        let Ok(actor_transform) = actors.get(*actor) else {
+            continue;
+        };
+        let Ok((mut hunger, morale)) = crew.get_mut(*actor) else {
+            continue;
+        };
+
+        let food = stockpiles
+            .iter_mut()
+            .filter(|(stockpile, transform)| {
+                !stockpile.is_empty()
+                    && transform
+                        .translation()
+                        .distance(actor_transform.translation())
+                        <= FOOD_STORE_DISTANCE
+            })
+            .filter_map(|(stockpile, _)| {
+                calculate_nourishment(stockpile.goods).map(|nourishment| (stockpile, nourishment))
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        match food {
+            Some((mut stockpile, nourishment)) => {
+                let appetite = eat.per_second * time.delta_seconds();
+                let relieved = eat_from_stockpile(&mut stockpile, appetite);
+                let portions = relieved / nourishment;
+                trace!("Eating {} portions of {:?}.", portions, stockpile.goods);
+
+                // The eating action relieves the hunger satisfied by what was eaten
+                if appetite > 0.0 {
+                    eat.rate = relieved / appetite;
+                }
+
+                if stockpile.goods == Goods::Meal {
+                    if let Some(mut morale) = morale {
+                        morale.raise(portions * HOT_MEAL_MORALE_GAIN);
+                    }
+                }
+            }
+            None => {
+                debug!("The stores are empty!");
+                hunger.is_satisfying = false;
+                *state = ActionState::Failure;
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::log::trace;
+use bevy::prelude::{Query, Res, Time};
+
+use crate::ai_need_behavior::{Need, NeedKind};
+
+/// Increases the need of kind `T` of each entity over time.
+///
+/// This system grows the level of each entity's need based on the `per_second` rate specified
+/// in their `Need` component. The level is capped at `MAX_NEED_LEVEL`.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `needs`: Query to fetch and modify the `Need` component of entities.
+pub fn increase_need<T: NeedKind>(time: Res<Time>, mut needs: Query<&mut Need<T>>) {
+    for mut need in &mut needs {
+        need.grow(time.delta_seconds());
+        trace!("{}: {}", T::NAME, need.level);
+    }
+}
@@ -0,0 +1,11 @@
+pub use {
+    calculate_need_score::*, eat_from_food_stores::*, increase_need::*, rest_in_sleep_areas::*,
+    satisfy_need_action::*, suppress_hunger_without_food::*,
+};
+
+mod calculate_need_score;
+mod eat_from_food_stores;
+mod increase_need;
+mod rest_in_sleep_areas;
+mod satisfy_need_action;
+mod suppress_hunger_without_food;
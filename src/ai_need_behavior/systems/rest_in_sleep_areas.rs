@@ -0,0 +1,50 @@
+use bevy::log::trace;
+use bevy::prelude::{GlobalTransform, Query, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor, HasThinker};
+
+use crate::ai_need_behavior::{Sleep, DECK_REST_PENALTY};
+use crate::claims::Claimable;
+use crate::ship_items::{SleepArea, SLEEP_AREA_DISTANCE};
+
+/// This system decides how well sleeping entities rest. An entity sleeping in the berth it
+/// claimed rests at the full rate of the sleeping action, while an entity without a berth
+/// within reach sleeps on deck and rests at a rate lowered by `DECK_REST_PENALTY`.
+///
+/// # Parameters
+/// - `actors`: Query to fetch the transforms of the acting entities.
+/// - `sleep_areas`: Query to fetch the claims on the sleep areas, along with their transforms.
+/// - `query`: Query to fetch the `ActionState` and `ActionSpan` components of entities and to
+///   modify their `Sleep` component, as well as the `Actor` component to identify the entity.
+pub fn rest_in_sleep_areas(
+    actors: Query<&GlobalTransform, With<HasThinker>>,
+    sleep_areas: Query<(&Claimable, &GlobalTransform), With<SleepArea>>,
+    mut query: Query<(&Actor, &ActionState, &mut Sleep, &ActionSpan)>,
+) {
+    for (Actor(actor), state, mut sleep, span) in &mut query {
+        let _guard = span.span().enter();
+
+        if *state != ActionState::Executing {
+            continue;
+        }
+
+        let Ok(actor_transform) = actors.get(*actor) else {
+            continue;
+        };
+
+        let in_berth = sleep_areas.iter().any(|(claimable, transform)| {
+            claimable.is_claimed_by(*actor)
+                && transform
+                    .translation()
+                    .distance(actor_transform.translation())
+                    <= SLEEP_AREA_DISTANCE
+        });
+
+        if in_berth {
+            sleep.rate = 1.0;
+        } else {
+            trace!("Sleeping on deck...");
+            sleep.rate = 1.0 - DECK_REST_PENALTY;
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Query, Res, Time};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_need_behavior::{Need, NeedKind, SatisfyNeed};
+
+/// This system manages the action of entities satisfying their need of kind `T`. It relieves
+/// the need of the entity at the action's rate while it acts, and updates the entity's state
+/// based on the `SatisfyNeed` component's parameters once the need has been relieved.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `needs`: Query to fetch and modify the `Need` component of entities.
+/// - `query`: Query to fetch and modify the `ActionState`, `SatisfyNeed`, and `ActionSpan`
+///   components of entities, as well as the `Actor` component to identify the entity.
+pub fn satisfy_need_action<T: NeedKind>(
+    time: Res<Time>,
+    mut needs: Query<&mut Need<T>>,
+    mut query: Query<(&Actor, &mut ActionState, &SatisfyNeed<T>, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, satisfy_need, span) in &mut query {
+        let _guard = span.span().enter();
+
+        if let Ok(mut need) = needs.get_mut(*actor) {
+            match *state {
+                ActionState::Requested => {
+                    debug!("Time to see to {}!", T::NAME);
+                    need.is_satisfying = true;
+                    *state = ActionState::Executing;
+                }
+                ActionState::Executing => {
+                    trace!("Seeing to {}...", T::NAME);
+                    need.relieve(
+                        satisfy_need.per_second * satisfy_need.rate * time.delta_seconds(),
+                    );
+
+                    if need.level <= satisfy_need.until {
+                        debug!("{} satisfied!", T::NAME);
+                        need.is_satisfying = false;
+                        *state = ActionState::Success;
+                    }
+                }
+                // All actions should make sure to handle cancellations
+                ActionState::Cancelled => {
+                    need.is_satisfying = false;
+                    *state = ActionState::Failure;
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::log::trace;
+use bevy::prelude::{Entity, Parent, Query, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_need_behavior::HungerScorer;
+use crate::crew_management::{is_aboard_own_ship, ShipCrew};
+use crate::food::Food;
+
+/// This system takes away the urge to eat from entities that have no food within reach.
+///
+/// Once the food aboard an entity's ship has run out, seeking food and eating would only fail,
+/// so the hunger score of the entity is set to nothing and it gets on with other work while it
+/// starves. The system runs after the hunger scores have been calculated, and the urge returns
+/// as soon as there is food aboard again.
+///
+/// # Parameters
+/// - `food`: A query to fetch the entities marked as `Food`.
+/// - `parent_query`: A query to navigate up the hierarchy to find the ship carrying the food.
+/// - `ships`: A query to fetch the crew of ships.
+/// - `query`: A query to fetch the `Actor`, `Score`, and `ScorerSpan` components of entities
+///   with the `HungerScorer` component.
+pub fn suppress_hunger_without_food(
+    food: Query<Entity, With<Food>>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<HungerScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        let has_food = food
+            .iter()
+            .any(|food| is_aboard_own_ship(*actor, food, &parent_query, &ships));
+
+        if !has_food && score.get() > 0.0 {
+            span.span().in_scope(|| trace!("No food left aboard!"));
+            score.set(0.0);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::crew_orders::OrderTarget;
+
+/// Represents a crew member carrying out the order the player gave them.
+///
+/// The `FollowOrder` component is used to manage the order-following action of an entity in
+/// the game. Once the entity has reached the target of its order, it takes over the station,
+/// holds its position in the compartment, or works the bilge pump or cannon it was sent to.
+///
+/// # Fields
+/// - `target`: The target of the order being carried out, recorded when the action starts.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct FollowOrder {
+    /// The target of the order being carried out.
+    pub target: Option<OrderTarget>,
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating whether an entity has an order to follow.
+///
+/// The `FollowOrderScorer` component is used to calculate a score that indicates whether an
+/// entity should drop what it is doing to carry out an order from the player. The score is
+/// high for as long as the entity has an order.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct FollowOrderScorer;
@@ -0,0 +1,4 @@
+pub use {follow_order::*, follow_order_scorer::*};
+
+mod follow_order;
+mod follow_order_scorer;
@@ -0,0 +1,3 @@
+pub use order_settings::*;
+
+mod order_settings;
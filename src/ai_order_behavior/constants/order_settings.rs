@@ -0,0 +1,3 @@
+/// The fill level, from `0.0` (dry) to `1.0` (flooded), at which a crew member ordered to a
+/// bilge pump considers the compartment pumped dry.
+pub const ORDER_PUMPED_DRY_LEVEL: f32 = 0.01;
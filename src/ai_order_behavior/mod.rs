@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+use crate::claims::hold_claims;
+
+mod components;
+mod constants;
+mod systems;
+mod utils;
+
+/// Plugin for managing AI order-following behavior within the game.
+///
+/// The AiOrderBehaviorPlugin provides functionality for handling how crew carry out the orders
+/// the player gives them. It registers the necessary components and sets up systems to score
+/// the need to follow an order, ahead of everything else, and to carry it out.
+///
+/// # Components
+/// - FollowOrder: Manages the order-following action of an entity, recording the target of the
+///   order being carried out.
+/// - FollowOrderScorer: Calculates a score indicating whether an entity has an order to follow.
+///
+/// # Systems
+/// - calculate_follow_order_score: Calculates a score based on whether an entity has been given an order,
+///   which the crew of a ship in mutiny refuse.
+/// - follow_order_action: Manages the order-following action of entities, carrying out their orders.
+/// - hold_claims: Holds on to the claims of entities on the pumps and cannons they were ordered
+///   to while they work them, and releases them once the order ends.
+pub struct AiOrderBehaviorPlugin;
+
+impl Plugin for AiOrderBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FollowOrder>()
+            .register_type::<FollowOrderScorer>()
+            .add_systems(
+                Update,
+                calculate_follow_order_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                follow_order_action.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                hold_claims::<FollowOrder>
+                    .after(follow_order_action)
+                    .run_if(in_state(AppStates::Running)),
+            );
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::log::trace;
+use bevy::prelude::{Query, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_order_behavior::components::FollowOrderScorer;
+use crate::crew_management::ShipCrew;
+use crate::crew_morale::{is_in_mutiny, ShipMorale};
+use crate::crew_orders::CrewOrder;
+
+/// This system calculates a score based on whether an entity has been given an order.
+///
+/// The system iterates over entities with the `FollowOrderScorer` component and gives them a
+/// full score while they have an order from the player, and no score otherwise. The crew of a
+/// ship in mutiny refuse their orders until the mutiny is over.
+///
+/// # Parameters
+/// - `orders`: A query to fetch the `CrewOrder` components.
+/// - `ships`: A query to fetch the crew of ships and their `ShipMorale`.
+/// - `query`: A query to fetch the `Actor`, `Score`, and `ScorerSpan` components of entities
+///   with the `FollowOrderScorer` component.
+pub fn calculate_follow_order_score(
+    orders: Query<&CrewOrder>,
+    ships: Query<(&ShipCrew, &ShipMorale)>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<FollowOrderScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        if !orders.contains(*actor) {
+            score.set(0.0);
+        } else if is_in_mutiny(*actor, &ships) {
+            span.span().in_scope(|| trace!("Refusing orders!"));
+            score.set(0.0);
+        } else {
+            span.span().in_scope(|| trace!("Following orders."));
+            score.set(1.0);
+        }
+    }
+}
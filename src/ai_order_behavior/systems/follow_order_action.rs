@@ -0,0 +1,155 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Commands, Entity, Parent, Query, Transform};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_order_behavior::components::FollowOrder;
+use crate::ai_order_behavior::constants::ORDER_PUMPED_DRY_LEVEL;
+use crate::ai_order_behavior::utils::release_order_task;
+use crate::ai_pumping_behavior::calculate_pump_fill_level;
+use crate::buoyancy_physics::Buoyancy;
+use crate::claims::{Claimable, CLAIM_TIMEOUT};
+use crate::crew_orders::{CrewOrder, OrderTarget};
+use crate::flooding::Flooding;
+use crate::gunnery::{Cannon, CannonState};
+use crate::ship_items::BilgePump;
+
+/// This system manages the order-following action of entities, once they have reached the
+/// target of their order.
+///
+/// - A station order is carried out on arrival, and the entity goes on to work the station as
+///   its own.
+/// - A compartment order holds the entity in the compartment until the order is cancelled.
+/// - A task order has the entity operate the bilge pump until its compartment is pumped dry,
+///   or load the cannon until it is ready to fire. The entity claims the pump or cannon while
+///   it works it. A pump or cannon someone else is already operating, or has claimed, is left
+///   to them, and the order is dropped.
+///
+/// Carried out orders are removed from the entity. An order that is replaced while it is being
+/// carried out fails, so that the entity sets off towards its new target.
+///
+/// # Parameters
+/// - `commands`: Commands for removing orders that have been carried out.
+/// - `orders`: Query to fetch the orders of the acting entities.
+/// - `pumps`: Query to fetch and modify the bilge pumps, along with their hulls.
+/// - `cannons`: Query to fetch and modify the cannons.
+/// - `hulls`: Query to fetch the flooding state, voxels and transforms of hulls.
+/// - `claimables`: Query to fetch and modify the claims on bilge pumps and cannons.
+/// - `query`: Query to fetch and modify the `ActionState`, `FollowOrder`, and `ActionSpan`
+///   components of entities, as well as the `Actor` component to identify the entity.
+pub fn follow_order_action(
+    mut commands: Commands,
+    orders: Query<&CrewOrder>,
+    mut pumps: Query<(&mut BilgePump, &Parent)>,
+    mut cannons: Query<&mut Cannon>,
+    hulls: Query<(Entity, &Flooding, &Buoyancy, &Transform)>,
+    mut claimables: Query<&mut Claimable>,
+    mut query: Query<(&Actor, &mut ActionState, &mut FollowOrder, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, mut follow_order, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let order = orders.get(*actor).ok().map(|order| order.target);
+
+        match *state {
+            ActionState::Requested => {
+                follow_order.target = order;
+
+                match order {
+                    Some(OrderTarget::Task(task)) => {
+                        let is_free = |operator: Option<Entity>| {
+                            operator.is_none_or(|operator| operator == *actor)
+                                && claimables
+                                    .get(task)
+                                    .ok()
+                                    .is_none_or(|claimable| claimable.has_room(*actor))
+                        };
+
+                        let took_charge = if let Ok((mut pump, _)) = pumps.get_mut(task) {
+                            let took_charge = is_free(pump.operator);
+                            if took_charge {
+                                debug!("Manning the bilge pump as ordered!");
+                                pump.operator = Some(*actor);
+                            }
+                            took_charge
+                        } else if let Ok(mut cannon) = cannons.get_mut(task) {
+                            let took_charge = is_free(cannon.operator);
+                            if took_charge {
+                                debug!("Manning the cannon as ordered!");
+                                cannon.operator = Some(*actor);
+                            }
+                            took_charge
+                        } else {
+                            false
+                        };
+
+                        if !took_charge {
+                            debug!("Nothing left to do here.");
+                            commands.entity(*actor).remove::<CrewOrder>();
+                            *state = ActionState::Failure;
+                            continue;
+                        }
+
+                        if let Ok(mut claimable) = claimables.get_mut(task) {
+                            claimable.claim(*actor, CLAIM_TIMEOUT);
+                        }
+                        *state = ActionState::Executing;
+                    }
+                    Some(_) => *state = ActionState::Executing,
+                    None => *state = ActionState::Failure,
+                }
+            }
+            ActionState::Executing => {
+                if order != follow_order.target {
+                    debug!("New orders.");
+                    release_order_task(follow_order.target, *actor, &mut pumps, &mut cannons);
+                    *state = ActionState::Failure;
+                    continue;
+                }
+
+                let is_carried_out = match order {
+                    Some(OrderTarget::Station(_)) => {
+                        debug!("Reported to my station.");
+                        true
+                    }
+                    Some(OrderTarget::Compartment { ship, .. }) => {
+                        if !hulls.contains(ship) {
+                            debug!("The ship is gone.");
+                            commands.entity(*actor).remove::<CrewOrder>();
+                            *state = ActionState::Failure;
+                            continue;
+                        }
+                        trace!("Standing by in the compartment...");
+                        false
+                    }
+                    Some(OrderTarget::Task(task)) => {
+                        if let Ok((pump, parent)) = pumps.get(task) {
+                            trace!("Pumping...");
+                            calculate_pump_fill_level(pump, parent.get(), &hulls)
+                                <= ORDER_PUMPED_DRY_LEVEL
+                        } else if let Ok(cannon) = cannons.get(task) {
+                            trace!("Loading...");
+                            matches!(cannon.state, CannonState::Aiming | CannonState::Firing)
+                        } else {
+                            true
+                        }
+                    }
+                    None => true,
+                };
+
+                if is_carried_out {
+                    debug!("Order carried out!");
+                    release_order_task(follow_order.target, *actor, &mut pumps, &mut cannons);
+                    commands.entity(*actor).remove::<CrewOrder>();
+                    *state = ActionState::Success;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                release_order_task(follow_order.target, *actor, &mut pumps, &mut cannons);
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use {calculate_follow_order_score::*, follow_order_action::*};
+
+mod calculate_follow_order_score;
+mod follow_order_action;
@@ -0,0 +1,3 @@
+pub use order_task_utils::*;
+
+mod order_task_utils;
@@ -0,0 +1,37 @@
+use bevy::prelude::{Entity, Parent, Query};
+
+use crate::crew_orders::OrderTarget;
+use crate::gunnery::Cannon;
+use crate::ship_items::BilgePump;
+
+/// Stands a crew member down from the bilge pump or cannon they were ordered to.
+///
+/// # Arguments
+/// - `target`: The target of the order the crew member was carrying out, if any.
+/// - `crew_member`: The crew member standing down.
+/// - `pumps`: Query to retrieve and modify the bilge pumps.
+/// - `cannons`: Query to retrieve and modify the cannons.
+///
+/// Orders that are not tasks, and tasks the crew member is no longer operating, are left alone.
+pub fn release_order_task(
+    target: Option<OrderTarget>,
+    crew_member: Entity,
+    pumps: &mut Query<(&mut BilgePump, &Parent)>,
+    cannons: &mut Query<&mut Cannon>,
+) {
+    let Some(OrderTarget::Task(task)) = target else {
+        return;
+    };
+
+    if let Ok((mut pump, _)) = pumps.get_mut(task) {
+        if pump.operator == Some(crew_member) {
+            pump.operator = None;
+        }
+    }
+
+    if let Ok(mut cannon) = cannons.get_mut(task) {
+        if cannon.operator == Some(crew_member) {
+            cannon.operator = None;
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use {produce::*, production_scorer::*};
+
+mod produce;
+mod production_scorer;
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the production behavior of a crew member.
+///
+/// The `Produce` component is used to manage the working action of an entity at a workshop,
+/// such as a cook at the galley. The entity starts a job of the workshop's recipe and works it
+/// until the job is done, or something more pressing calls it away.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct Produce;
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating the entity's need to work its workshop.
+///
+/// The `ProductionScorer` component is used to calculate a score that indicates whether an
+/// entity has work to do at the workshop the duty roster has assigned it to. The score is high
+/// while a job is under way or a new one can be started.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct ProductionScorer;
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod systems;
+
+/// Plugin for managing AI production behavior within the game.
+///
+/// The AiProductionBehaviorPlugin provides functionality for handling how crew work the
+/// workshops aboard their ship, such as the cook at the galley. It registers the necessary
+/// components and sets up systems to score whether there is work to do and to do it.
+///
+/// # Components
+/// - Produce: Manages the production action of an entity at its workshop.
+/// - ProductionScorer: Calculates a score indicating whether an entity has work to do at its
+///   workshop.
+///
+/// # Systems
+/// - calculate_production_score: Calculates a score based on whether an entity has work to do
+///   at its workshop.
+/// - produce_action: Manages the production action of entities, working the jobs of their
+///   workshops.
+pub struct AiProductionBehaviorPlugin;
+
+impl Plugin for AiProductionBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Produce>()
+            .register_type::<ProductionScorer>()
+            .add_systems(
+                Update,
+                calculate_production_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, produce_action.run_if(in_state(AppStates::Running)));
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::log::trace;
+use bevy::prelude::{Entity, Parent, Query, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_production_behavior::components::ProductionScorer;
+use crate::crew_management::Station;
+use crate::production::{can_start_job, Stockpile, Workshop};
+use crate::utils::find_top_level_entity;
+
+/// This system calculates a score based on whether an entity has work to do at a workshop.
+///
+/// The system iterates over entities with the `ProductionScorer` component and gives them a
+/// full score while the duty roster has assigned them to a workshop that has a job under way,
+/// or whose ship has the stockpiles to start a new one. Otherwise the score is zero.
+///
+/// # Parameters
+/// - `workshops`: A query to fetch the workshops, along with their stations.
+/// - `stockpiles`: A query to fetch the stockpiles.
+/// - `parent_query`: A query to navigate up the hierarchy to find the ship carrying each entity.
+/// - `query`: A query to fetch the `Actor`, `Score`, and `ScorerSpan` components of entities
+///   with the `ProductionScorer` component.
+pub fn calculate_production_score(
+    workshops: Query<(Entity, &Station, &Workshop)>,
+    stockpiles: Query<(Entity, &Stockpile)>,
+    parent_query: Query<&Parent>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<ProductionScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        let has_work = workshops
+            .iter()
+            .filter(|(_, station, _)| station.crew == Some(*actor))
+            .any(|(entity, _, workshop)| {
+                let ship = find_top_level_entity(entity, &parent_query);
+                let ship_stockpiles: Vec<&Stockpile> = stockpiles
+                    .iter()
+                    .filter(|(stockpile, _)| {
+                        find_top_level_entity(*stockpile, &parent_query) == ship
+                    })
+                    .map(|(_, stockpile)| stockpile)
+                    .collect();
+
+                workshop.is_working() || can_start_job(&workshop.recipe, &ship_stockpiles)
+            });
+
+        if has_work {
+            span.span()
+                .in_scope(|| trace!("Work to do at the workshop."));
+            score.set(1.0);
+        } else {
+            score.set(0.0);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use {calculate_production_score::*, produce_action::*};
+
+mod calculate_production_score;
+mod produce_action;
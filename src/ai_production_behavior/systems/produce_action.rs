@@ -0,0 +1,150 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Entity, GlobalTransform, Parent, Query, Res, Time, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor, HasThinker};
+
+use crate::ai_production_behavior::components::Produce;
+use crate::crew_health::Health;
+use crate::crew_management::{CrewSkills, Station, STATION_DISTANCE};
+use crate::crew_morale::Morale;
+use crate::production::{can_start_job, Stockpile, Workshop};
+use crate::utils::find_top_level_entity;
+
+/// This system manages the production action of entities. The entity works the workshop the
+/// duty roster assigned it to while it stands within reach of it, manning its station.
+///
+/// - A new job is started by drawing the inputs of the workshop's recipe from the stockpiles
+///   aboard the ship. The action fails if the ship does not have them, or has no room for the
+///   output.
+/// - The job progresses faster the more skilled in the recipe's skill, the healthier and the
+///   more content the entity is.
+/// - Once the job is done, the output is put into the stockpiles aboard the ship.
+///
+/// A job that is interrupted is left where it was, and picked up again when the entity returns.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `actors`: Query to fetch the transforms of the acting entities.
+/// - `crew`: Query to fetch the skills, health and morale of the acting entities.
+/// - `workshops`: Query to fetch and modify the workshops and their stations, along with their
+///   transforms.
+/// - `stockpiles`: Query to fetch and modify the stockpiles.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each entity.
+/// - `query`: Query to fetch and modify the `ActionState` and `ActionSpan` components of
+///   entities with the `Produce` component, as well as the `Actor` component to identify the
+///   entity.
+#[allow(clippy::type_complexity)]
+pub fn produce_action(
+    time: Res<Time>,
+    actors: Query<&GlobalTransform, With<HasThinker>>,
+    crew: Query<(Option<&CrewSkills>, Option<&Health>, Option<&Morale>)>,
+    mut workshops: Query<(Entity, &mut Station, &mut Workshop, &GlobalTransform)>,
+    mut stockpiles: Query<(Entity, &mut Stockpile)>,
+    parent_query: Query<&Parent>,
+    mut query: Query<(&Actor, &mut ActionState, &ActionSpan), With<Produce>>,
+) {
+    for (Actor(actor), mut state, span) in &mut query {
+        let _guard = span.span().enter();
+
+        if *state == ActionState::Cancelled {
+            // All actions should make sure to handle cancellations
+            for (_, mut station, _, _) in workshops.iter_mut() {
+                if station.crew == Some(*actor) {
+                    station.manned = false;
+                }
+            }
+            *state = ActionState::Failure;
+            continue;
+        }
+
+        if !matches!(*state, ActionState::Requested | ActionState::Executing) {
+            continue;
+        }
+
+        let Ok(actor_transform) = actors.get(*actor) else {
+            continue;
+        };
+
+        let Some((entity, mut station, mut workshop, workshop_transform)) = workshops
+            .iter_mut()
+            .find(|(_, station, _, _)| station.crew == Some(*actor))
+        else {
+            debug!("Relieved of my workshop.");
+            *state = ActionState::Success;
+            continue;
+        };
+
+        let at_workshop = workshop_transform
+            .translation()
+            .distance(actor_transform.translation())
+            <= STATION_DISTANCE;
+        if !at_workshop {
+            debug!("Not at my workshop.");
+            station.manned = false;
+            *state = ActionState::Failure;
+            continue;
+        }
+
+        let ship = find_top_level_entity(entity, &parent_query);
+        let ship_stockpiles: Vec<Entity> = stockpiles
+            .iter()
+            .filter(|(stockpile, _)| find_top_level_entity(*stockpile, &parent_query) == ship)
+            .map(|(stockpile, _)| stockpile)
+            .collect();
+
+        if *state == ActionState::Requested {
+            if !workshop.is_working() {
+                let available: Vec<&Stockpile> = ship_stockpiles
+                    .iter()
+                    .filter_map(|stockpile| stockpiles.get(*stockpile).ok())
+                    .map(|(_, stockpile)| stockpile)
+                    .collect();
+                if !can_start_job(&workshop.recipe, &available) {
+                    debug!("Nothing to work with.");
+                    *state = ActionState::Failure;
+                    continue;
+                }
+
+                for (goods, quantity) in workshop.recipe.inputs.iter() {
+                    let mut needed = *quantity;
+                    for stockpile in ship_stockpiles.iter() {
+                        if let Ok((_, mut stockpile)) = stockpiles.get_mut(*stockpile) {
+                            if stockpile.goods == *goods {
+                                needed -= stockpile.take(needed);
+                            }
+                        }
+                    }
+                }
+                workshop.start();
+            }
+
+            debug!("Getting to work!");
+            station.manned = true;
+            *state = ActionState::Executing;
+            continue;
+        }
+
+        let effectiveness = crew.get(*actor).map_or(1.0, |(skills, health, morale)| {
+            skills.map_or(1.0, |skills| skills.effectiveness(workshop.recipe.skill))
+                * health.map_or(1.0, |health| health.work_rate())
+                * morale.map_or(1.0, |morale| morale.work_rate())
+        });
+
+        trace!("Working...");
+        station.manned = true;
+        if workshop.work(effectiveness * time.delta_seconds()) {
+            let mut remaining = workshop.recipe.output_quantity;
+            for stockpile in ship_stockpiles.iter() {
+                if let Ok((_, mut stockpile)) = stockpiles.get_mut(*stockpile) {
+                    if stockpile.goods == workshop.recipe.output {
+                        remaining -= stockpile.put(remaining);
+                    }
+                }
+            }
+
+            debug!("Job done!");
+            workshop.finish();
+            *state = ActionState::Success;
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::ScoreHysteresis;
+
+/// This component serves as a scorer for evaluating the entity's need to pump out the bilge
+/// based on how flooded the ship is.
+///
+/// The `FloodingScorer` component is used to calculate a score that indicates the urgency for an
+/// entity to perform the pumping action. A higher score means a higher need to pump. Once the
+/// flooding is serious enough, the score is held until the water has been pumped out.
+///
+/// # Fields
+/// - `hysteresis`: The thresholds at which the score starts and stops being held, along with
+///   the score held for the entity.
+#[derive(
+    Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct FloodingScorer {
+    pub hysteresis: ScoreHysteresis,
+}
+
+impl FloodingScorer {
+    /// Creates a scorer whose score is held from when it reaches `enter` until it falls to
+    /// `exit`.
+    pub fn new(enter: f32, exit: f32) -> Self {
+        Self {
+            hysteresis: ScoreHysteresis::new(enter, exit),
+        }
+    }
+}
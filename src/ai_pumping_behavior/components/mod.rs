@@ -0,0 +1,4 @@
+pub use {flooding_scorer::*, pump_bilge::*};
+
+mod flooding_scorer;
+mod pump_bilge;
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the pumping behavior of an entity.
+///
+/// The `PumpBilge` component is used to manage the pumping action of an entity in the game.
+/// The entity takes charge of the nearest free bilge pump and keeps operating it until the
+/// water it draws from has dropped far enough.
+///
+/// # Fields
+/// - `until`: The fill level, from `0.0` (dry) to `1.0` (flooded), at which the entity will
+///   stop pumping. When the water drawn by the pump drops to or below this level, the entity
+///   leaves the pump.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct PumpBilge {
+    /// The fill level at which the entity will stop pumping.
+    pub until: f32,
+}
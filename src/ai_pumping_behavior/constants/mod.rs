@@ -0,0 +1,3 @@
+pub use pumping_settings::*;
+
+mod pumping_settings;
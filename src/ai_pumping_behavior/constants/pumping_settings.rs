@@ -0,0 +1,3 @@
+/// The fill level, from `0.0` (dry) to `1.0` (flooded), at which the need to pump reaches its
+/// highest score.
+pub const FLOODING_ALARM_LEVEL: f32 = 0.1;
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+use crate::claims::hold_claims;
+
+mod components;
+mod constants;
+mod systems;
+mod utils;
+
+/// Plugin for managing AI pumping behavior within the game.
+///
+/// The AiPumpingBehaviorPlugin provides functionality for handling how crew respond to a
+/// flooding ship. It registers the necessary components and sets up systems to score the need
+/// to pump and to man the bilge pumps until the water has been pumped out.
+///
+/// # Components
+/// - FloodingScorer: Calculates a score indicating the urgency for an entity to pump out the bilge.
+/// - PumpBilge: Manages the pumping action of an entity, defining the fill level at which it
+///   stops pumping.
+///
+/// # Systems
+/// - calculate_flooding_score: Calculates a score based on how flooded the ship's pumps are.
+/// - hold_claims: Holds on to the claims of entities on their bilge pumps while they pump,
+///   releasing them once they stop.
+/// - pump_bilge_action: Manages the pumping action of entities, manning and leaving bilge pumps.
+pub struct AiPumpingBehaviorPlugin;
+
+impl Plugin for AiPumpingBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FloodingScorer>()
+            .register_type::<PumpBilge>()
+            .add_systems(
+                Update,
+                calculate_flooding_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                pump_bilge_action.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                hold_claims::<PumpBilge>
+                    .after(pump_bilge_action)
+                    .run_if(in_state(AppStates::Running)),
+            );
+    }
+}
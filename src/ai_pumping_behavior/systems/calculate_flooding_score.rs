@@ -0,0 +1,67 @@
+use bevy::log::trace;
+use bevy::prelude::{Entity, Parent, Query, Transform};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_pumping_behavior::components::FloodingScorer;
+use crate::ai_pumping_behavior::constants::FLOODING_ALARM_LEVEL;
+use crate::ai_pumping_behavior::utils::calculate_pump_fill_level;
+use crate::buoyancy_physics::Buoyancy;
+use crate::crew_management::{is_aboard_own_ship, ShipCrew};
+use crate::flooding::Flooding;
+use crate::ship_items::BilgePump;
+
+/// This system calculates a score based on how flooded the ship is. The more water there is
+/// at the bilge pumps an entity could operate, the higher the score, indicating a greater need
+/// for the entity to pump.
+///
+/// The system iterates over entities with the `FloodingScorer` component and scores them by the
+/// most flooded pump aboard their own ship that is free or already operated by them. The score
+/// is passed through the scorer's hysteresis, so that once the flooding is serious the score is
+/// held until the water has been pumped out. Each entity has its own scorer, so the score held
+/// for one entity never affects another.
+///
+/// # Parameters
+/// - `pumps`: A query to fetch the bilge pumps and the hulls they are fitted to.
+/// - `hulls`: A query to fetch the flooding state, voxels and transforms of hulls.
+/// - `parent_query`: A query to navigate up the hierarchy to find the ship carrying each pump.
+/// - `ships`: A query to fetch the crew of ships.
+/// - `query`: A query to fetch the `Actor`, `Score`, `FloodingScorer`, and `ScorerSpan`
+///   components of scorer entities.
+pub fn calculate_flooding_score(
+    pumps: Query<(Entity, &BilgePump, &Parent)>,
+    hulls: Query<(Entity, &Flooding, &Buoyancy, &Transform)>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
+    mut query: Query<(&Actor, &mut Score, &mut FloodingScorer, &ScorerSpan)>,
+) {
+    let pump_fill_levels: Vec<(Entity, Option<Entity>, f32)> = pumps
+        .iter()
+        .map(|(entity, pump, parent)| {
+            (
+                entity,
+                pump.operator,
+                calculate_pump_fill_level(pump, parent.get(), &hulls),
+            )
+        })
+        .collect();
+
+    for (Actor(actor), mut score, mut scorer, span) in &mut query {
+        let fill_level = pump_fill_levels
+            .iter()
+            .filter(|(entity, operator, _)| {
+                (operator.is_none() || *operator == Some(*actor))
+                    && is_aboard_own_ship(*actor, *entity, &parent_query, &ships)
+            })
+            .map(|(_, _, fill_level)| *fill_level)
+            .fold(0.0, f32::max);
+        let new_score = scorer
+            .hysteresis
+            .apply((fill_level / FLOODING_ALARM_LEVEL).min(1.0));
+        score.set(new_score);
+
+        if fill_level >= FLOODING_ALARM_LEVEL {
+            span.span()
+                .in_scope(|| trace!("Flooding above threshold! Fill level: {}", fill_level));
+        }
+    }
+}
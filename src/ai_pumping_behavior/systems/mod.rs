@@ -0,0 +1,4 @@
+pub use {calculate_flooding_score::*, pump_bilge_action::*};
+
+mod calculate_flooding_score;
+mod pump_bilge_action;
@@ -0,0 +1,128 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Entity, GlobalTransform, Mut, Parent, Query, Transform, Without};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_pumping_behavior::components::PumpBilge;
+use crate::ai_pumping_behavior::utils::calculate_pump_fill_level;
+use crate::buoyancy_physics::Buoyancy;
+use crate::claims::{Claimable, CLAIM_TIMEOUT};
+use crate::crew_management::{is_aboard_own_ship, ShipCrew};
+use crate::flooding::Flooding;
+use crate::ship_items::{BilgePump, BILGE_PUMP_DISTANCE};
+
+/// This system manages the pumping action of entities. The entity takes charge of a free bilge
+/// pump aboard its own ship within reach, preferring the one it claimed on the way there, which
+/// removes water while it is operated, and leaves the pump once the water has dropped to the
+/// level given by the `PumpBilge` component. An entity with no free pump within reach fails, so
+/// that it sets off to find one.
+///
+/// # Parameters
+/// - `actors`: Query to fetch the transforms of the acting entities.
+/// - `pumps`: Query to fetch and modify the bilge pumps, along with their hulls, global
+///   transforms and claims.
+/// - `hulls`: Query to fetch the flooding state, voxels and transforms of hulls.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each pump.
+/// - `ships`: Query to fetch the crew of ships.
+/// - `query`: Query to fetch and modify the `ActionState`, `PumpBilge`, and `ActionSpan`
+///   components of entities, as well as the `Actor` component to identify the entity.
+#[allow(clippy::type_complexity)]
+pub fn pump_bilge_action(
+    actors: Query<&Transform, Without<BilgePump>>,
+    mut pumps: Query<(
+        Entity,
+        &mut BilgePump,
+        &Parent,
+        &GlobalTransform,
+        Option<&mut Claimable>,
+    )>,
+    hulls: Query<(Entity, &Flooding, &Buoyancy, &Transform)>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
+    mut query: Query<(&Actor, &mut ActionState, &PumpBilge, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, pump_bilge, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok(actor_transform) = actors.get(*actor) else {
+            continue;
+        };
+
+        match *state {
+            ActionState::Requested => {
+                let is_claimed_by_actor = |claimable: &Option<Mut<Claimable>>| {
+                    claimable
+                        .as_ref()
+                        .is_some_and(|claimable| claimable.is_claimed_by(*actor))
+                };
+
+                let nearest_free_pump = pumps
+                    .iter_mut()
+                    .filter(|(entity, pump, _, pump_transform, claimable)| {
+                        pump.operator.is_none()
+                            && is_aboard_own_ship(*actor, *entity, &parent_query, &ships)
+                            && claimable
+                                .as_ref()
+                                .is_none_or(|claimable| claimable.has_room(*actor))
+                            && pump_transform
+                                .translation()
+                                .distance(actor_transform.translation)
+                                <= BILGE_PUMP_DISTANCE
+                    })
+                    .min_by(|(_, _, _, a, a_claimable), (_, _, _, b, b_claimable)| {
+                        is_claimed_by_actor(b_claimable)
+                            .cmp(&is_claimed_by_actor(a_claimable))
+                            .then(
+                                a.translation()
+                                    .distance_squared(actor_transform.translation)
+                                    .total_cmp(
+                                        &b.translation()
+                                            .distance_squared(actor_transform.translation),
+                                    ),
+                            )
+                    });
+
+                match nearest_free_pump {
+                    Some((_, mut pump, _, _, claimable)) => {
+                        debug!("Manning the bilge pump!");
+                        pump.operator = Some(*actor);
+                        if let Some(mut claimable) = claimable {
+                            claimable.claim(*actor, CLAIM_TIMEOUT);
+                        }
+                        *state = ActionState::Executing;
+                    }
+                    None => {
+                        debug!("No free bilge pump within reach.");
+                        *state = ActionState::Failure;
+                    }
+                }
+            }
+            ActionState::Executing => {
+                let Some((_, mut pump, parent, _, _)) = pumps
+                    .iter_mut()
+                    .find(|(_, pump, _, _, _)| pump.operator == Some(*actor))
+                else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                trace!("Pumping...");
+                if calculate_pump_fill_level(&pump, parent.get(), &hulls) <= pump_bilge.until {
+                    debug!("Bilge pumped out!");
+                    pump.operator = None;
+                    *state = ActionState::Success;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                for (_, mut pump, _, _, _) in pumps.iter_mut() {
+                    if pump.operator == Some(*actor) {
+                        pump.operator = None;
+                    }
+                }
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
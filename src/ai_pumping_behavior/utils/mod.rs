@@ -0,0 +1,3 @@
+pub use pumping_utils::*;
+
+mod pumping_utils;
@@ -0,0 +1,29 @@
+use bevy::prelude::{Entity, Query, Transform};
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::flooding::Flooding;
+use crate::ship_items::BilgePump;
+
+/// Calculates how full of water the compartment a bilge pump draws from is.
+///
+/// # Arguments
+/// - `pump`: The bilge pump.
+/// - `hull`: The hull the pump is fitted to.
+/// - `hulls`: Query to retrieve the flooding state, voxels and transforms of hulls.
+///
+/// # Returns
+/// The fill level of the pumped compartment, from `0.0` (dry) to `1.0` (flooded). A pump whose
+/// hull cannot flood, or whose compartment does not exist, reports `0.0`.
+pub fn calculate_pump_fill_level(
+    pump: &BilgePump,
+    hull: Entity,
+    hulls: &Query<(Entity, &Flooding, &Buoyancy, &Transform)>,
+) -> f32 {
+    hulls
+        .get(hull)
+        .ok()
+        .and_then(|(_, flooding, buoyancy, _)| {
+            flooding.fill_level(pump.compartment.as_deref(), &buoyancy.voxels)
+        })
+        .unwrap_or(0.0)
+}
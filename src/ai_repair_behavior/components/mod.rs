@@ -0,0 +1,4 @@
+pub use {repair::*, repair_scorer::*};
+
+mod repair;
+mod repair_scorer;
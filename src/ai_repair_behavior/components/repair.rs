@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the repairing behavior of an entity.
+///
+/// The `Repair` component is used to manage the repairing action of an entity in the game.
+/// The entity sets to work on the nearest damaged ship system within reach, or on the hull
+/// of its ship, and keeps at it until it is fully repaired.
+///
+/// # Fields
+/// - `per_second`: The integrity the entity restores per second of work.
+/// - `target`: The system or hull being repaired, chosen when the action starts.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct Repair {
+    /// The integrity restored per second of work.
+    pub per_second: f32,
+    /// The system or hull being repaired.
+    pub target: Option<Entity>,
+}
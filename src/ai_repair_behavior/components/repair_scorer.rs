@@ -2,13 +2,13 @@ use bevy::prelude::*;
 use big_brain::prelude::*;
 use serde::{Deserialize, Serialize};
 
-/// This component serves as a scorer for evaluating the entity's need to eat based on its hunger
-/// level.
+/// This component serves as a scorer for evaluating the entity's need to repair the ship based
+/// on how badly it is damaged.
 ///
-/// The `HungerScorer` component is used to calculate a score that indicates the urgency for an
-/// entity to perform the eating action. A higher score means a higher need to eat.
+/// The `RepairScorer` component is used to calculate a score that indicates the urgency for an
+/// entity to perform the repairing action. A higher score means a higher need to repair.
 #[derive(
     Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
 )]
 #[reflect(Component, Serialize, Deserialize)]
-pub struct HungerScorer;
+pub struct RepairScorer;
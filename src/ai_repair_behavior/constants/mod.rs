@@ -0,0 +1,3 @@
+pub use repair_settings::*;
+
+mod repair_settings;
@@ -0,0 +1,2 @@
+/// The loss of integrity at which the need to repair reaches its highest score.
+pub const REPAIR_ALARM_DAMAGE: f32 = 0.25;
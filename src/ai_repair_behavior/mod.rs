@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod systems;
+
+/// Plugin for managing AI repair behavior within the game.
+///
+/// The AiRepairBehaviorPlugin provides functionality for handling how crew keep a damaged ship
+/// working. It registers the necessary components and sets up systems to score the need for
+/// repairs and to restore damaged systems and hull sections.
+///
+/// # Components
+/// - Repair: Manages the repairing action of an entity, defining how quickly it restores integrity.
+/// - RepairScorer: Calculates a score indicating the urgency for an entity to make repairs.
+///
+/// # Systems
+/// - calculate_repair_score: Calculates a score based on how badly damaged the ship is.
+/// - repair_action: Manages the repairing action of entities, restoring integrity and plugging holes.
+pub struct AiRepairBehaviorPlugin;
+
+impl Plugin for AiRepairBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Repair>()
+            .register_type::<RepairScorer>()
+            .add_systems(
+                Update,
+                calculate_repair_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, repair_action.run_if(in_state(AppStates::Running)));
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::log::trace;
+use bevy::prelude::{Entity, Parent, Query, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_repair_behavior::components::RepairScorer;
+use crate::ai_repair_behavior::constants::REPAIR_ALARM_DAMAGE;
+use crate::crew_management::{is_aboard_own_ship, ShipCrew};
+use crate::damage::{HullIntegrity, Integrity};
+
+/// This system calculates a score based on how badly damaged the ship is. The more integrity
+/// the most damaged system or hull section has lost, the higher the score, indicating a greater
+/// need for the entity to repair it. Only the damage aboard the entity's own ship counts.
+///
+/// # Parameters
+/// - `systems`: A query to fetch the `Integrity` of ship systems.
+/// - `hulls`: A query to fetch the `HullIntegrity` of hulls.
+/// - `parent_query`: A query to navigate up the hierarchy to find the ship carrying each system.
+/// - `ships`: A query to fetch the crew of ships.
+/// - `query`: A query to fetch the `Actor`, `Score` and `ScorerSpan` components of entities
+///   with the `RepairScorer` component.
+pub fn calculate_repair_score(
+    systems: Query<(Entity, &Integrity)>,
+    hulls: Query<(Entity, &HullIntegrity)>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<RepairScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        let is_aboard =
+            |entity: &Entity| is_aboard_own_ship(*actor, *entity, &parent_query, &ships);
+        let worst_damage = systems
+            .iter()
+            .filter(|(entity, _)| is_aboard(entity))
+            .map(|(_, integrity)| integrity.level)
+            .chain(
+                hulls
+                    .iter()
+                    .filter(|(entity, _)| is_aboard(entity))
+                    .flat_map(|(_, hull)| hull.sections.iter().copied()),
+            )
+            .map(|level| 1.0 - level)
+            .fold(0.0, f32::max);
+
+        score.set((worst_damage / REPAIR_ALARM_DAMAGE).min(1.0));
+        if worst_damage >= REPAIR_ALARM_DAMAGE {
+            span.span()
+                .in_scope(|| trace!("Damage above threshold! Damage: {}", worst_damage));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use {calculate_repair_score::*, repair_action::*};
+
+mod calculate_repair_score;
+mod repair_action;
@@ -0,0 +1,132 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{Entity, GlobalTransform, Has, Parent, Query, Res, Time, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor, HasThinker};
+
+use crate::ai_repair_behavior::components::Repair;
+use crate::crew_health::Health;
+use crate::crew_management::{is_aboard_own_ship, CrewSkills, ShipCrew, Skill};
+use crate::crew_morale::Morale;
+use crate::damage::{
+    repair_damage, Damaged, HullIntegrity, Integrity, HULL_REPAIR_DISTANCE, REPAIR_DISTANCE,
+};
+use crate::flooding::Flooding;
+
+/// This system manages the repairing action of entities. The entity picks the nearest damaged
+/// system aboard its own ship within reach, or the hull of its ship, and restores its integrity
+/// while it works. A holed hull is patched before any system on the same entity, such as the
+/// rudder. Repairing a hull section also plugs the holes in it, and a fully repaired section is
+/// watertight again. The more skilled at carpentry, the healthier and the more content the
+/// entity is the faster it works, and it gains carpentry experience for the repairs it actually
+/// makes, one second of experience for each second's worth of repairs at the base rate.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `actors`: Query to fetch the transforms of the acting entities.
+/// - `crew`: Query to fetch and modify the skills, and fetch the health and morale, of the acting
+///   entities.
+/// - `damaged`: Query to fetch the damaged systems and hulls, along with their transforms.
+/// - `targets`: Query to fetch and modify the `Integrity` of ship systems, and the
+///   `HullIntegrity` and `Flooding` of hulls.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each system.
+/// - `ships`: Query to fetch the crew of ships.
+/// - `query`: Query to fetch and modify the `ActionState`, `Repair`, and `ActionSpan`
+///   components of entities, as well as the `Actor` component to identify the entity.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn repair_action(
+    time: Res<Time>,
+    actors: Query<&GlobalTransform, With<HasThinker>>,
+    mut crew: Query<(Option<&mut CrewSkills>, Option<&Health>, Option<&Morale>)>,
+    damaged: Query<(Entity, &GlobalTransform, Has<HullIntegrity>), With<Damaged>>,
+    mut targets: Query<(
+        Option<&mut Integrity>,
+        Option<(&mut HullIntegrity, &mut Flooding)>,
+    )>,
+    parent_query: Query<&Parent>,
+    ships: Query<&ShipCrew>,
+    mut query: Query<(&Actor, &mut ActionState, &mut Repair, &ActionSpan)>,
+) {
+    for (Actor(actor), mut state, mut repair, span) in &mut query {
+        let _guard = span.span().enter();
+
+        match *state {
+            ActionState::Requested => {
+                let Ok(actor_transform) = actors.get(*actor) else {
+                    continue;
+                };
+
+                repair.target = damaged
+                    .iter()
+                    .filter(|(entity, _, _)| {
+                        is_aboard_own_ship(*actor, *entity, &parent_query, &ships)
+                    })
+                    .map(|(entity, transform, is_hull)| {
+                        let distance = transform
+                            .translation()
+                            .distance(actor_transform.translation());
+                        let reach = if is_hull {
+                            HULL_REPAIR_DISTANCE
+                        } else {
+                            REPAIR_DISTANCE
+                        };
+                        (entity, distance, distance <= reach)
+                    })
+                    .filter(|(_, _, in_reach)| *in_reach)
+                    .min_by(|(_, a, _), (_, b, _)| a.total_cmp(b))
+                    .map(|(entity, _, _)| entity);
+
+                if repair.target.is_some() {
+                    debug!("Time to make repairs!");
+                    *state = ActionState::Executing;
+                } else {
+                    debug!("Nothing within reach to repair.");
+                    *state = ActionState::Failure;
+                }
+            }
+            ActionState::Executing => {
+                let Some(target) = repair.target else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+
+                trace!("Repairing...");
+                let mut amount = repair.per_second * time.delta_seconds();
+                if let Ok((skills, health, morale)) = crew.get(*actor) {
+                    amount *= skills.map_or(1.0, |skills| skills.effectiveness(Skill::Carpentry));
+                    amount *= health.map_or(1.0, |health| health.work_rate());
+                    amount *= morale.map_or(1.0, |morale| morale.work_rate());
+                }
+
+                let Ok((integrity, hull)) = targets.get_mut(target) else {
+                    debug!("Nothing left to repair.");
+                    *state = ActionState::Success;
+                    continue;
+                };
+                let progress = repair_damage(
+                    integrity.map(|integrity| integrity.into_inner()),
+                    hull.map(|(hull_integrity, flooding)| {
+                        (hull_integrity.into_inner(), flooding.into_inner())
+                    }),
+                    amount,
+                );
+
+                if let Ok((Some(mut skills), _, _)) = crew.get_mut(*actor) {
+                    skills.gain_experience(
+                        Skill::Carpentry,
+                        progress.repaired / repair.per_second.max(f32::EPSILON),
+                    );
+                }
+
+                if progress.is_repaired {
+                    debug!("Repairs done!");
+                    *state = ActionState::Success;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,5 +0,0 @@
-pub use {fatigue::*, fatigue_scorer::*, sleep::*};
-
-mod fatigue;
-mod fatigue_scorer;
-mod sleep;
@@ -1,5 +0,0 @@
-pub use {calculate_fatigue_score::*, increase_fatigue::*, sleep_action::*};
-
-mod calculate_fatigue_score;
-mod increase_fatigue;
-mod sleep_action;
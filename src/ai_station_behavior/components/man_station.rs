@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+/// Represents the station-keeping behavior of a crew member.
+///
+/// The `ManStation` component is used to manage the working action of an entity in the game.
+/// The entity works the station the duty roster assigned it to for as long as it stays there,
+/// until something more pressing calls it away.
+#[derive(Clone, Component, Debug, ActionBuilder, Reflect, Default)]
+#[reflect(Component)]
+pub struct ManStation;
@@ -0,0 +1,4 @@
+pub use {man_station::*, station_scorer::*};
+
+mod man_station;
+mod station_scorer;
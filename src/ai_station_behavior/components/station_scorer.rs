@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// This component serves as a scorer for evaluating the entity's need to man its station.
+///
+/// The `StationScorer` component is used to calculate a score that indicates whether an entity
+/// should be at work at its station. The score is high whenever the duty roster has assigned
+/// the entity a station.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default, ScorerBuilder,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct StationScorer;
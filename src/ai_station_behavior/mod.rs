@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod systems;
+
+/// Plugin for managing AI station-keeping behavior within the game.
+///
+/// The AiStationBehaviorPlugin provides functionality for handling how crew work the stations
+/// the duty roster assigns them to. It registers the necessary components and sets up systems
+/// to score the need to be at a station and to man it while there.
+///
+/// # Components
+/// - ManStation: Manages the working action of an entity, manning its assigned station.
+/// - StationScorer: Calculates a score indicating whether an entity should be at its station.
+///
+/// # Systems
+/// - calculate_station_score: Calculates a score based on whether an entity has been assigned a station.
+/// - man_station_action: Manages the working action of entities, manning and leaving their stations.
+pub struct AiStationBehaviorPlugin;
+
+impl Plugin for AiStationBehaviorPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ManStation>()
+            .register_type::<StationScorer>()
+            .add_systems(
+                Update,
+                calculate_station_score.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                man_station_action.run_if(in_state(AppStates::Running)),
+            );
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::log::trace;
+use bevy::prelude::{Query, Res, With};
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+
+use crate::ai_station_behavior::components::StationScorer;
+use crate::crew_management::Station;
+use crate::crew_watches::{Watch, WatchSchedule, OFF_WATCH_STATION_SCORE};
+
+/// This system calculates a score based on whether an entity has a station to man.
+///
+/// The system iterates over entities with the `StationScorer` component and gives them a full
+/// score while the duty roster has assigned them a station and their watch is on duty, and no
+/// score without a station. While their watch is below, the score falls short of the threshold
+/// the thinker acts on, so the entity stands down from its station.
+///
+/// # Parameters
+/// - `schedule`: Resource describing which watch is on duty.
+/// - `stations`: A query to fetch the `Station` components.
+/// - `watches`: A query to fetch the `Watch` components of entities.
+/// - `query`: A query to fetch the `Actor`, `Score`, and `ScorerSpan` components of entities
+///   with the `StationScorer` component.
+pub fn calculate_station_score(
+    schedule: Res<WatchSchedule>,
+    stations: Query<&Station>,
+    watches: Query<&Watch>,
+    mut query: Query<(&Actor, &mut Score, &ScorerSpan), With<StationScorer>>,
+) {
+    for (Actor(actor), mut score, span) in &mut query {
+        let has_station = stations.iter().any(|station| station.crew == Some(*actor));
+
+        if !has_station {
+            score.set(0.0);
+        } else if schedule.is_on_duty(watches.get(*actor).ok()) {
+            span.span().in_scope(|| trace!("On duty at a station."));
+            score.set(1.0);
+        } else {
+            span.span().in_scope(|| trace!("My watch is below."));
+            score.set(OFF_WATCH_STATION_SCORE);
+        }
+    }
+}
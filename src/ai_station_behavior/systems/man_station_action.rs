@@ -0,0 +1,84 @@
+use bevy::log::{debug, trace};
+use bevy::prelude::{GlobalTransform, Query, Res, Transform, With, Without};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::ai_station_behavior::components::ManStation;
+use crate::crew_management::{Station, STATION_DISTANCE};
+use crate::crew_watches::{Watch, WatchSchedule};
+
+/// This system manages the station-keeping action of entities. The station the entity is
+/// assigned to is manned while the entity stands within reach of it, and left unmanned when
+/// the entity walks away, loses the assignment or is called away by something more pressing.
+/// Once the entity's watch is relieved, it stands down from the station and leaves it to the
+/// watch on duty.
+///
+/// # Parameters
+/// - `schedule`: Resource describing which watch is on duty.
+/// - `actors`: Query to fetch the transforms and watches of the acting entities.
+/// - `stations`: Query to fetch and modify the stations, along with their transforms.
+/// - `query`: Query to fetch and modify the `ActionState` and `ActionSpan` components of
+///   entities with the `ManStation` component, as well as the `Actor` component to identify
+///   the entity.
+pub fn man_station_action(
+    schedule: Res<WatchSchedule>,
+    actors: Query<(&Transform, Option<&Watch>), Without<Station>>,
+    mut stations: Query<(&mut Station, &GlobalTransform)>,
+    mut query: Query<(&Actor, &mut ActionState, &ActionSpan), With<ManStation>>,
+) {
+    for (Actor(actor), mut state, span) in &mut query {
+        let _guard = span.span().enter();
+
+        let Ok((actor_transform, watch)) = actors.get(*actor) else {
+            continue;
+        };
+
+        match *state {
+            ActionState::Requested => {
+                debug!("Manning my station!");
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let Some((mut station, station_transform)) = stations
+                    .iter_mut()
+                    .find(|(station, _)| station.crew == Some(*actor))
+                else {
+                    debug!("Relieved of my station.");
+                    *state = ActionState::Success;
+                    continue;
+                };
+
+                if !schedule.is_on_duty(watch) {
+                    debug!("My watch is relieved.");
+                    station.manned = false;
+                    *state = ActionState::Success;
+                    continue;
+                }
+
+                let at_station = station_transform
+                    .translation()
+                    .distance(actor_transform.translation)
+                    <= STATION_DISTANCE;
+
+                if at_station {
+                    trace!("Working the station...");
+                    station.manned = true;
+                } else {
+                    debug!("Left my station.");
+                    station.manned = false;
+                    *state = ActionState::Failure;
+                }
+            }
+            // All actions should make sure to handle cancellations
+            ActionState::Cancelled => {
+                for (mut station, _) in stations.iter_mut() {
+                    if station.crew == Some(*actor) {
+                        station.manned = false;
+                    }
+                }
+                *state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use {calculate_station_score::*, man_station_action::*};
+
+mod calculate_station_score;
+mod man_station_action;
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::buoyancy_physics::constants::VOXEL_SIZE;
 use crate::buoyancy_physics::Voxel;
 
 /// The `Buoyancy` component represents the buoyant properties of an entity in the game.
@@ -9,24 +10,34 @@ use crate::buoyancy_physics::Voxel;
 ///
 /// # Fields
 /// - `voxels`: A vector of `Voxel` instances that represent the voxelized volume of the entity.
+/// - `voxel_size`: The size of each voxel in `voxels`.
 /// - `needs_update`: A boolean flag indicating whether the voxel data requires updating.
 ///
 /// # Methods
-/// - `from_voxels(voxels: Vec<Voxel>, needs_update: bool) -> Self`:
-///   Creates a new `Buoyancy` component from a list of voxels and an update flag.
-#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+/// - `from_voxels(voxels: Vec<Voxel>, voxel_size: f32, needs_update: bool) -> Self`:
+///   Creates a new `Buoyancy` component from a list of voxels, their size and an update flag.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize)]
 #[reflect(Component, Serialize, Deserialize)]
 pub struct Buoyancy {
     pub voxels: Vec<Voxel>, // List of voxel data, possibly pulled from generate_voxel_grid
+    pub voxel_size: f32,
     pub needs_update: bool,
 }
 
 impl Buoyancy {
-    /// Creates a new `Buoyancy` component from a list of voxels and an update flag.
-    pub fn from_voxels(voxels: Vec<Voxel>, needs_update: bool) -> Self {
+    /// Creates a new `Buoyancy` component from a list of voxels, their size and an update flag.
+    pub fn from_voxels(voxels: Vec<Voxel>, voxel_size: f32, needs_update: bool) -> Self {
         Self {
             voxels,
+            voxel_size,
             needs_update,
         }
     }
 }
+
+impl Default for Buoyancy {
+    /// Creates an empty `Buoyancy` component using the default voxel size.
+    fn default() -> Self {
+        Self::from_voxels(Vec::new(), VOXEL_SIZE, false)
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::buoyancy_physics::constants::{DEFAULT_HULL_DENSITY, VOXEL_SIZE};
+
+/// Describes the physical properties of a buoyant hull.
+///
+/// The `HullPhysics` component controls how the mass, centre of mass and inertia of a
+/// buoyant body are derived. By default they are computed from the solid voxels of the
+/// hull using `density`, so any hull mesh floats at a sensible draft without code changes.
+/// Each property can also be set explicitly, which lets a glTF file provide tuned values
+/// for a particular model through its extras.
+///
+/// The component is read from the entity carrying the `BuoyancyMarker` and copied onto
+/// the body it makes buoyant.
+///
+/// # Methods
+/// - `with_density(density: f32, voxel_size: f32) -> Self`: Creates a `HullPhysics` that computes
+///   every property from the given density and voxel size.
+///
+/// # Fields
+/// - `density`: The average density of the hull relative to `WATER_DENSITY`. A hull with a
+///   density of `0.5` floats with about half of its volume submerged.
+/// - `voxel_size`: The size of the voxels the hull is divided into. Small objects need a
+///   voxel size well below their own size to be voxelized accurately.
+/// - `mass`: An explicit mass in kilograms, overriding the computed mass.
+/// - `center_of_mass`: An explicit centre of mass in body-local space, overriding the computed one.
+/// - `inertia`: An explicit inertia tensor, overriding the computed one.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct HullPhysics {
+    pub density: f32,
+    pub voxel_size: f32,
+    pub mass: Option<f32>,
+    pub center_of_mass: Option<Vec3>,
+    pub inertia: Option<Mat3>,
+}
+
+impl HullPhysics {
+    /// Creates a `HullPhysics` that computes every property from the given density and voxel size.
+    pub fn with_density(density: f32, voxel_size: f32) -> Self {
+        Self {
+            density,
+            voxel_size,
+            ..default()
+        }
+    }
+}
+
+impl Default for HullPhysics {
+    /// Creates a `HullPhysics` that computes every property from the hull at the default
+    /// density and voxel size.
+    fn default() -> Self {
+        Self {
+            density: DEFAULT_HULL_DENSITY,
+            voxel_size: VOXEL_SIZE,
+            mass: None,
+            center_of_mass: None,
+            inertia: None,
+        }
+    }
+}
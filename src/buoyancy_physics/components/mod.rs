@@ -1,6 +1,7 @@
-pub use {buoyancy::*, buoyancy_marker::*, voxel::*, voxel_visual::*};
+pub use {buoyancy::*, buoyancy_marker::*, hull_physics::*, voxel::*, voxel_visual::*};
 
 mod buoyancy;
 mod buoyancy_marker;
+mod hull_physics;
 mod voxel;
 mod voxel_visual;
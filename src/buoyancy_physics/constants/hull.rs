@@ -0,0 +1,5 @@
+/// The density of a hull relative to the water, used when no density is provided.
+///
+/// Hulls at this density float with a little under half of their volume submerged,
+/// leaving room for cargo, crew and flooding before they sink.
+pub const DEFAULT_HULL_DENSITY: f32 = 0.45;
@@ -1,3 +1,5 @@
-pub use voxel::*;
+pub use {hull::*, voxel::*, water::*};
 
+mod hull;
 mod voxel;
+mod water;
@@ -1,3 +1,3 @@
-/// The size of each voxel in the grid. This constant is used throughout the voxel-related
-/// systems to determine the dimensions of the grid and the placement of individual voxels.
+/// The default size of each voxel in the grid. Buoyant bodies use this size unless their
+/// `HullPhysics` asks for a finer grid, which small objects need to be voxelized at all.
 pub const VOXEL_SIZE: f32 = 2.0;
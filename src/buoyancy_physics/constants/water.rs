@@ -0,0 +1,9 @@
+/// The density of sea water in kilograms per cubic metre, used for buoyancy calculations.
+///
+/// Buoyancy, flood water weight and hull drag are all computed in SI units with this value,
+/// and hull masses are derived from it through the relative density in `HullPhysics`, so a
+/// body floats when its average density is below it.
+pub const WATER_DENSITY: f32 = 1025.0;
+
+/// Acceleration due to gravity in m/s^2, used to turn displaced volume into buoyancy force.
+pub const GRAVITY: f32 = 9.81;
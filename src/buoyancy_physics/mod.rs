@@ -1,14 +1,18 @@
 use bevy::prelude::*;
 
 pub use components::*;
+pub use constants::*;
 pub use events::*;
+pub use resources::*;
 use systems::*;
+pub use utils::*;
 
 use crate::asset_management::states::app_states::AppStates;
 
 mod components;
 mod constants;
 mod events;
+mod resources;
 mod systems;
 mod utils;
 
@@ -22,14 +26,19 @@ mod utils;
 /// # Components
 /// - `Buoyancy`: Represents the buoyancy properties of an object.
 /// - `BuoyancyMarker`: Marks an object to be processed for buoyancy calculations.
+/// - `HullPhysics`: Describes how the mass, centre of mass and inertia of a hull are derived.
 /// - `Vec3I`: Represents a 3D vector with integer components.
 /// - `Voxel`: Represents a single voxel within the voxel grid.
 /// - `VoxelVisual`: Represents the visual properties of a voxel.
 ///
+/// # Resources
+/// - `SubmersionQuality`: Controls how many water samples are taken per voxel.
+/// - `VoxelCache`: Caches the voxelization of buoyant meshes in memory and optionally on disk.
+///
 /// # Systems
 /// - `calculate_and_apply_buoyancy`: Calculates and applies buoyancy forces to marked objects.
 /// - `read_buoyancy_objects`: Reads and processes objects marked for buoyancy calculations.
-/// - `update_voxel_solidity`: Updates the solidity state of voxels based on game state.
+/// - `update_mass_properties`: Derives mass, centre of mass and inertia from the solid voxels of a hull.
 /// - `visualize_mesh_bounds`: Visualizes the bounds of the mesh for debugging and tuning.
 /// - `visualize_voxel_grid`: Visualizes the voxel grid for debugging and tuning.
 ///
@@ -42,8 +51,12 @@ impl Plugin for BuoyancyPhysicsPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Buoyancy>()
             .register_type::<BuoyancyMarker>()
+            .register_type::<HullPhysics>()
             .register_type::<Voxel>()
             .register_type::<VoxelVisual>()
+            .register_type::<SubmersionQuality>()
+            .insert_resource(SubmersionQuality::default())
+            .insert_resource(VoxelCache::default())
             .add_event::<VisualizeMeshBoundsDebugToggle>()
             .add_event::<VisualizeVoxelsDebugToggle>()
             .add_systems(
@@ -56,7 +69,7 @@ impl Plugin for BuoyancyPhysicsPlugin {
             )
             .add_systems(
                 Update,
-                update_voxel_solidity.run_if(in_state(AppStates::Running)),
+                update_mass_properties.run_if(in_state(AppStates::Running)),
             )
             .add_systems(
                 Update,
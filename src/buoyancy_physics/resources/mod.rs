@@ -0,0 +1,4 @@
+pub use {submersion_quality::*, voxel_cache::*};
+
+mod submersion_quality;
+mod voxel_cache;
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+/// Controls how accurately the submersion of each voxel is measured.
+///
+/// Each voxel is split into `samples_per_axis()³` cells, and the water height is sampled
+/// once per cell. Higher quality follows wave slopes and heeled hulls more closely at the
+/// cost of more wave samples per frame.
+///
+/// # Variants
+/// - `Low`: One sample per voxel. The voxel's rotated height is still taken into account.
+/// - `Medium`: Eight samples per voxel.
+/// - `High`: Twenty-seven samples per voxel.
+///
+/// # Methods
+/// - `samples_per_axis(&self) -> u32`: Returns the number of cells along each axis of a voxel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub enum SubmersionQuality {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl SubmersionQuality {
+    /// Returns the number of cells each voxel is split into along each axis.
+    pub fn samples_per_axis(&self) -> u32 {
+        match self {
+            SubmersionQuality::Low => 1,
+            SubmersionQuality::Medium => 2,
+            SubmersionQuality::High => 3,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use bevy::log::warn;
+use bevy::prelude::{Mesh, Resource, Transform};
+
+use crate::buoyancy_physics::utils::{
+    calculate_voxel_cache_key, calculate_voxel_solidity, generate_voxel_grid, load_cached_voxels,
+    save_cached_voxels,
+};
+use crate::buoyancy_physics::Voxel;
+
+/// Caches the voxelization of buoyant meshes.
+///
+/// Voxelizing a hull is done once per mesh and kept in memory, so several ships sharing
+/// the same hull only pay for it once. When a `directory` is set, the voxels are also
+/// written to and read from disk, so the work is skipped across runs as well.
+///
+/// # Fields
+/// - `directory`: The directory used to cache voxels on disk, or `None` to only cache in memory.
+/// - `entries`: The voxels cached in memory, keyed by `calculate_voxel_cache_key`.
+///
+/// # Methods
+/// - `with_directory(directory)`: Creates a cache that also stores voxels on disk.
+/// - `voxelize(mesh, mesh_transform, voxel_size)`: Returns the voxels for a mesh, generating them if needed.
+#[derive(Debug, Default, Resource)]
+pub struct VoxelCache {
+    pub directory: Option<PathBuf>,
+    entries: HashMap<u64, Vec<Voxel>>,
+}
+
+impl VoxelCache {
+    /// Creates a `VoxelCache` that also stores voxels in the given directory.
+    pub fn with_directory(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: Some(directory.into()),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the voxels of a mesh, with their solidity computed in mesh-local space.
+    ///
+    /// The voxels are taken from memory or disk when available, and generated and cached
+    /// otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `mesh` - The mesh to voxelize.
+    /// * `mesh_transform` - The transform of the mesh relative to the buoyant body.
+    /// * `voxel_size` - The size of each voxel.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Voxel` structs covering the bounds of the mesh.
+    pub fn voxelize(
+        &mut self,
+        mesh: &Mesh,
+        mesh_transform: &Transform,
+        voxel_size: f32,
+    ) -> Vec<Voxel> {
+        let key = calculate_voxel_cache_key(mesh, mesh_transform, voxel_size);
+
+        if let Some(voxels) = self.entries.get(&key) {
+            return voxels.clone();
+        }
+
+        let voxels = self
+            .directory
+            .as_deref()
+            .and_then(|directory| load_cached_voxels(directory, key))
+            .unwrap_or_else(|| {
+                let mut voxels = generate_voxel_grid(mesh, mesh_transform, voxel_size);
+                calculate_voxel_solidity(mesh, mesh_transform, &mut voxels);
+
+                if let Some(directory) = &self.directory {
+                    if let Err(error) = save_cached_voxels(directory, key, &voxels) {
+                        warn!("Failed to write voxel cache to {:?}: {}", directory, error);
+                    }
+                }
+
+                voxels
+            });
+
+        self.entries.insert(key, voxels.clone());
+        voxels
+    }
+}
@@ -1,82 +1,69 @@
 use bevy::math::Vec3;
-use bevy::prelude::{Query, Transform};
+use bevy::prelude::{Query, Res, Transform};
 use bevy_water::WaterParam;
-use bevy_xpbd_3d::components::{CenterOfMass, ColliderDensity, ExternalForce};
+use bevy_xpbd_3d::components::{CenterOfMass, ExternalForce};
 
-use crate::buoyancy_physics::constants::VOXEL_SIZE;
-use crate::buoyancy_physics::utils::calculate_submerged_volume;
-use crate::buoyancy_physics::Buoyancy;
+use crate::buoyancy_physics::constants::{GRAVITY, WATER_DENSITY};
+use crate::buoyancy_physics::utils::calculate_submersion;
+use crate::buoyancy_physics::{Buoyancy, SubmersionQuality};
 use crate::utils::get_water_height_at_position;
 
 /// This system calculates and applies buoyancy forces to entities with the `Buoyancy` component.
 ///
-/// The system iterates over all entities with the `Buoyancy`, `Transform`, `ExternalForce`,
-/// `ColliderDensity`, and `CenterOfMass` components. For each voxel in the `Buoyancy` component,
-/// it calculates the buoyancy force based on the submerged volume and applies this force to
-/// the entity at the voxel's position, taking into account the entity's rotation and center of mass.
+/// The system iterates over all entities with the `Buoyancy`, `Transform`, `ExternalForce`
+/// and `CenterOfMass` components. For each solid voxel in the `Buoyancy` component, it measures
+/// the submerged part of the rotated voxel against the local wave surface and applies the
+/// resulting buoyancy force at the centre of the submerged volume, creating torque around
+/// the entity's center of mass.
 ///
 /// # Arguments
 ///
 /// * `water` - A parameter containing the global water settings and time resource, used to calculate wave heights.
+/// * `submersion_quality` - The number of water samples taken per voxel.
 /// * `query` - A query that retrieves entities with the required components for buoyancy calculation.
 ///
 /// # Details
 ///
 /// The buoyancy force is computed using the following formula:
 ///
-/// buoyancy force = gravity * submerged volume * hull density
-///
-/// The system also applies the calculated buoyancy force at the voxel's rotated position, creating torque around the center of mass.
-
+/// buoyancy force = gravity * submerged volume * water density
 pub fn calculate_and_apply_buoyancy(
     water: WaterParam,
-    mut query: Query<(
-        &Buoyancy,
-        &Transform,
-        &mut ExternalForce,
-        &ColliderDensity,
-        &CenterOfMass,
-    )>,
+    submersion_quality: Res<SubmersionQuality>,
+    mut query: Query<(&Buoyancy, &Transform, &mut ExternalForce, &CenterOfMass)>,
 ) {
-    let gravity = 9.81; // Acceleration due to gravity in m/s^2
+    let samples_per_axis = submersion_quality.samples_per_axis();
+
+    for (buoyancy, transform, mut external_force, center_of_mass) in query.iter_mut() {
+        let world_center_of_mass = transform.translation + transform.rotation * center_of_mass.0;
 
-    for (buoyancy, transform, mut external_force, _collider_density, center_of_mass) in
-        query.iter_mut()
-    {
         for voxel in &buoyancy.voxels {
             if voxel.is_solid {
-                // Apply the ship's rotation to the voxel's position relative to the ship's center of mass
+                // Apply the ship's rotation to the voxel's position relative to the ship's origin
                 let rotated_position = transform.rotation.mul_vec3(voxel.position);
                 let world_position = transform.translation + rotated_position;
 
-                let water_height = get_water_height_at_position(world_position, &water);
-                let submerged_volume =
-                    calculate_submerged_volume(world_position, water_height, VOXEL_SIZE);
-                let hull_density = 1.0;
-                let buoyancy_force = Vec3::new(0.0, gravity * submerged_volume * hull_density, 0.0);
-
-                // Apply the force at the voxel's rotated position, creating torque around the center of mass
-                external_force.apply_force_at_point(
-                    buoyancy_force,
+                let submersion = calculate_submersion(
                     world_position,
-                    center_of_mass.0,
+                    transform.rotation,
+                    buoyancy.voxel_size,
+                    samples_per_axis,
+                    |position| get_water_height_at_position(position, &water),
                 );
 
-                // TODO: Make this toggleable
-                // gizmos.sphere(center_of_mass.0, Quat::IDENTITY, 2.3, Color::RED);
+                if submersion.volume <= 0.0 {
+                    continue;
+                }
+
+                let buoyancy_force =
+                    Vec3::new(0.0, GRAVITY * submersion.volume * WATER_DENSITY, 0.0);
 
-                // Visualize the buoyancy force as an arrow
-                // gizmos.arrow(
-                //     world_position, // Start point of the arrow
-                //     world_position + buoyancy_force * 0.1, // End point scaled for visibility
-                //     Color::BLUE, // Color of the arrow
-                // );
-                // Optionally visualize the lever arm
-                // gizmos.line(
-                //     center_of_mass.0,
-                //     world_position,
-                //     Color::YELLOW,
-                // );
+                // Apply the force at the centre of buoyancy, creating torque around the center of mass
+                external_force.apply_force_at_point(
+                    buoyancy_force,
+                    submersion.center_of_buoyancy,
+                    world_center_of_mass,
+                );
             }
         }
     }
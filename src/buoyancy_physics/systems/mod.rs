@@ -1,10 +1,10 @@
 pub use {
-    calculate_and_apply_buoyancy::*, read_buoyancy_objects::*, update_voxel_solidity::*,
+    calculate_and_apply_buoyancy::*, read_buoyancy_objects::*, update_mass_properties::*,
     visualize_mesh_bounds::*, visualize_voxel_grid::*,
 };
 
 mod calculate_and_apply_buoyancy;
 mod read_buoyancy_objects;
-mod update_voxel_solidity;
+mod update_mass_properties;
 mod visualize_mesh_bounds;
 mod visualize_voxel_grid;
@@ -2,107 +2,148 @@ use bevy::asset::{Assets, Handle};
 use bevy::hierarchy::{Children, DespawnRecursiveExt, Parent};
 use bevy::math::Vec3;
 use bevy::prelude::*;
-use bevy_xpbd_3d::components::{
-    AngularDamping, CenterOfMass, ColliderDensity, ExternalForce, Inertia, LinearDamping, Mass,
-    RigidBody,
-};
-use bevy_xpbd_3d::math::Matrix3;
+use bevy::utils::HashSet;
+use bevy_xpbd_3d::components::{ColliderDensity, ExternalForce, ExternalImpulse, RigidBody};
 use bevy_xpbd_3d::prelude::Collider;
 use oxidized_navigation::NavMeshAffector;
 
-use crate::buoyancy_physics::utils::generate_voxel_grid;
-use crate::buoyancy_physics::{Buoyancy, BuoyancyMarker};
+use crate::buoyancy_physics::{Buoyancy, BuoyancyMarker, HullPhysics, VoxelCache};
 use crate::ship::Ship;
-use crate::utils::find_mesh;
+use crate::utils::{find_mesh, find_top_level_entity};
 
 /// System to process and configure buoyancy objects within the game.
 ///
 /// This system handles entities marked with the `BuoyancyMarker` component, generating
 /// voxel grids for buoyancy calculations and attaching necessary components to the
-/// top-level ship entity. The system ensures that buoyancy objects are correctly integrated
-/// into the ship's dynamics, allowing for realistic buoyancy and physics interactions.
+/// top-level entity of the marker's hierarchy. Any object can be made buoyant this way,
+/// from ships loaded from glTF scenes to barrels, longboats, debris or food spawned in code.
 ///
 /// # Parameters
-/// - `buoyancy_marker_query`: Query to retrieve entities with `BuoyancyMarker` components and their transforms.
+/// - `buoyancy_marker_query`: Query to retrieve entities with `BuoyancyMarker` components, their transforms
+///   and any `HullPhysics` provided alongside the marker.
 /// - `commands`: Commands for modifying entities and their components.
 /// - `children_query`: Query to retrieve the children of entities.
-/// - `parent_query`: Query to navigate up the hierarchy to find parent entities.
-/// - `ship_query`: Query to identify the top-level ship entity.
+/// - `parent_query`: Query to navigate up the hierarchy to find the top-level entity.
+/// - `collider_query`: Query to check whether the buoyant body already has a collider.
+/// - `ship_query`: Query to identify ships, which also affect the navigation mesh.
 /// - `meshes`: Resource containing the assets of meshes.
 /// - `mesh_handles`: Query to retrieve mesh handles from entities.
+/// - `voxel_cache`: Resource caching the voxelization of each mesh.
+/// - `buoyant_bodies`: Query to check whether a body has already been made buoyant.
 ///
 /// # Details
 /// For each `BuoyancyMarker` entity, the system:
-/// - Finds the associated mesh and generates a voxel grid for buoyancy calculations.
-/// - Navigates up the entity hierarchy to find the top-level ship entity.
-/// - Attaches the `Buoyancy` component and other physics-related components to the ship entity.
-/// - De-spawns the original marker entity after processing.
+/// - Navigates up the entity hierarchy to find the top-level entity, which becomes the
+///   buoyant body. A marker without a parent makes its own entity buoyant.
+/// - Ignores the marker with a warning if another marker has already made the same body
+///   buoyant, as a body has a single voxel grid and a second one would silently replace it.
+/// - Finds the associated mesh, on the marker itself or one of its children, and voxelizes it
+///   in body-local space, marking the voxels inside the mesh as solid. The result is shared
+///   through the `VoxelCache`.
+/// - Attaches the `Buoyancy` component and other physics-related components to the body, adding
+///   a collider built from the mesh if the body does not already have one.
+/// - Copies the marker's `HullPhysics` to the body, falling back to the default hull properties.
+///   The mass, centre of mass and inertia are then derived by `update_mass_properties`.
+/// - De-spawns the original marker entity after processing, unless it is the body itself.
+#[allow(clippy::too_many_arguments)]
 pub fn read_buoyancy_objects(
-    buoyancy_marker_query: Query<(Entity, &BuoyancyMarker, &Transform), Added<BuoyancyMarker>>,
+    buoyancy_marker_query: Query<
+        (Entity, &BuoyancyMarker, &Transform, Option<&HullPhysics>),
+        Added<BuoyancyMarker>,
+    >,
     mut commands: Commands,
     children_query: Query<&Children>,
     parent_query: Query<&Parent>,
-    ship_query: Query<Entity, With<Ship>>,
+    collider_query: Query<(), With<Collider>>,
+    ship_query: Query<(), With<Ship>>,
     meshes: Res<Assets<Mesh>>,
     mesh_handles: Query<&Handle<Mesh>>,
+    mut voxel_cache: ResMut<VoxelCache>,
+    buoyant_bodies: Query<(), With<Buoyancy>>,
 ) {
-    for (entity, _, mesh_transform) in buoyancy_marker_query.iter() {
-        println!(
-            "Processing Entity: {:?}, Transform: {:?}",
-            entity, mesh_transform
+    let mut claimed_bodies = HashSet::new();
+
+    for (entity, _, marker_transform, hull_physics) in buoyancy_marker_query.iter() {
+        debug!(
+            "Processing entity {:?} with transform {:?}",
+            entity, marker_transform
         );
 
-        if let Some(mesh_handle) = find_mesh(entity, &children_query, &mesh_handles) {
-            println!("Mesh handle found: {:?}", mesh_handle);
-            if let Some(mesh) = meshes.get(mesh_handle) {
-                println!("Generating voxel grid for mesh.");
-                let voxels = generate_voxel_grid(mesh, mesh_transform);
+        // Find the top-level entity, which becomes the buoyant body
+        let body = find_top_level_entity(entity, &parent_query);
 
-                // Find the top-level Ship entity
-                let mut current_parent = entity;
-                let mut ship_entity = None;
-                while let Ok(parent) = parent_query.get(current_parent) {
-                    if ship_query.get(parent.get()).is_ok() {
-                        ship_entity = Some(parent.get());
-                        break;
-                    }
-                    current_parent = parent.get();
-                }
+        // A body has a single voxel grid, so only the first marker found for it is used
+        if buoyant_bodies.contains(body) || !claimed_bodies.insert(body) {
+            warn!(
+                "Ignoring BuoyancyMarker {:?}, as its body {:?} is already made buoyant by another marker",
+                entity, body
+            );
+            if body != entity {
+                commands.entity(entity).despawn_recursive();
+            }
+            continue;
+        }
+
+        // A marker on the body itself is already placed by the body's transform
+        let is_own_body = body == entity;
+        let mesh_transform = if is_own_body {
+            Transform::IDENTITY
+        } else {
+            *marker_transform
+        };
+
+        let Some(mesh_handle) = mesh_handles
+            .get(entity)
+            .ok()
+            .cloned()
+            .or_else(|| find_mesh(entity, &children_query, &mesh_handles))
+        else {
+            error!(
+                "Mesh not found for entity {:?} marked with BuoyancyMarker",
+                entity
+            );
+            continue;
+        };
+
+        let Some(mesh) = meshes.get(&mesh_handle) else {
+            error!(
+                "Failed to retrieve mesh from handle for entity {:?} marked with BuoyancyMarker",
+                entity
+            );
+            continue;
+        };
 
-                if let Some(ship) = ship_entity {
-                    // Attach the Buoyancy component and collider to the Ship entity
-                    println!("Inserting collider and dynamics components to the Ship entity.");
-                    if let Some(collider) = Collider::trimesh_from_mesh(mesh) {
-                        commands.entity(ship).insert((
-                            Buoyancy::from_voxels(voxels, true),
-                            collider,
-                            ColliderDensity(0.0),
-                            RigidBody::Dynamic,
-                            LinearDamping(0.8),
-                            AngularDamping(0.8),
-                            ExternalForce::new(Vec3::ZERO).with_persistence(false),
-                            Visibility::Visible,
-                            NavMeshAffector,
-                            CenterOfMass(Vec3::new(-2.0, 0.0, 0.2)),
-                            Mass(2000.0),
-                            Inertia(Matrix3::from_cols(
-                                Vec3::new(126395.3, -28743.2, 16967.54),
-                                Vec3::new(-28743.2, 259213.7, -6361.74),
-                                Vec3::new(16967.54, -6361.74, 246570.2),
-                            )),
-                        ));
-                        commands.entity(entity).despawn_recursive();
-                    }
-                } else {
-                    println!("No Ship entity found for the buoyancy component.");
+        debug!("Generating voxel grid for mesh.");
+        let hull_physics = hull_physics.cloned().unwrap_or_default();
+        let voxels = voxel_cache.voxelize(mesh, &mesh_transform, hull_physics.voxel_size);
+
+        // Attach the Buoyancy component and dynamics to the body
+        debug!("Inserting buoyancy and dynamics components to {:?}.", body);
+        let mut body_commands = commands.entity(body);
+        body_commands.insert((
+            Buoyancy::from_voxels(voxels, hull_physics.voxel_size, false),
+            RigidBody::Dynamic,
+            ExternalForce::new(Vec3::ZERO).with_persistence(false),
+            ExternalImpulse::new(Vec3::ZERO).with_persistence(false),
+            Visibility::Visible,
+            hull_physics,
+        ));
+
+        if !collider_query.contains(body) {
+            match Collider::trimesh_from_mesh(mesh) {
+                Some(collider) => {
+                    body_commands.insert((collider, ColliderDensity(0.0)));
                 }
-            } else {
-                eprintln!(
-                    "Failed to retrieve mesh from handle for entity marked with BuoyancyMarker"
-                );
+                None => error!("Failed to build a collider for the buoyant body {:?}", body),
             }
-        } else {
-            eprintln!("Mesh not found for entity marked with BuoyancyMarker");
+        }
+
+        if ship_query.contains(body) {
+            body_commands.insert(NavMeshAffector);
+        }
+
+        if !is_own_body {
+            commands.entity(entity).despawn_recursive();
         }
     }
 }
@@ -0,0 +1,50 @@
+use bevy::log::warn;
+use bevy::prelude::{Changed, Commands, Entity, Query};
+use bevy_xpbd_3d::components::{CenterOfMass, Inertia, Mass};
+
+use crate::buoyancy_physics::utils::calculate_mass_properties;
+use crate::buoyancy_physics::{Buoyancy, HullPhysics, WATER_DENSITY};
+
+/// Updates the mass, centre of mass and inertia of buoyant bodies from their hulls.
+///
+/// This system runs whenever the voxels of a `Buoyancy` component change and their solidity
+/// is up to date. It computes the mass properties of the solid voxels using the density from
+/// the body's `HullPhysics`, scaled by `WATER_DENSITY` to kilograms per cubic metre, applies
+/// any explicit overrides from the same component, and inserts the resulting `Mass`,
+/// `CenterOfMass` and `Inertia` on the body.
+///
+/// # Parameters
+/// - `commands`: Commands for inserting the mass properties on the body.
+/// - `query`: Query to retrieve bodies whose `Buoyancy` has changed, along with their `HullPhysics`.
+pub fn update_mass_properties(
+    mut commands: Commands,
+    query: Query<(Entity, &Buoyancy, &HullPhysics), Changed<Buoyancy>>,
+) {
+    for (entity, buoyancy, hull_physics) in query.iter() {
+        if buoyancy.needs_update {
+            continue;
+        }
+
+        let Some(computed) = calculate_mass_properties(
+            &buoyancy.voxels,
+            buoyancy.voxel_size,
+            hull_physics.density * WATER_DENSITY,
+        ) else {
+            warn!(
+                "No solid voxels found for {:?}, unable to compute its mass.",
+                entity
+            );
+            continue;
+        };
+
+        commands.entity(entity).insert((
+            Mass(hull_physics.mass.unwrap_or(computed.mass)),
+            CenterOfMass(
+                hull_physics
+                    .center_of_mass
+                    .unwrap_or(computed.center_of_mass),
+            ),
+            Inertia(hull_physics.inertia.unwrap_or(computed.inertia)),
+        ));
+    }
+}
@@ -1,6 +1,5 @@
 use bevy::prelude::*;
 
-use crate::buoyancy_physics::constants::VOXEL_SIZE;
 use crate::buoyancy_physics::VoxelVisual;
 use crate::buoyancy_physics::{Buoyancy, VisualizeVoxelsDebugToggle};
 
@@ -36,10 +35,10 @@ pub fn visualize_voxel_grid(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    let voxel_visual_size = VOXEL_SIZE * 0.95; // Adjust size for visual gaps
-
     for _event in visualize_voxel_grid_debug_event_reader.read() {
         for (_entity, transform, buoyancy) in query.iter() {
+            let voxel_visual_size = buoyancy.voxel_size * 0.95; // Adjust size for visual gaps
+
             for voxel in &buoyancy.voxels {
                 if voxel.is_solid {
                     // Transform for each voxel based on its position relative to the parent entity
@@ -0,0 +1,75 @@
+use bevy::math::{Mat3, Vec3};
+
+use crate::buoyancy_physics::Voxel;
+
+/// The mass properties of a rigid body.
+///
+/// # Fields
+/// - `mass`: The total mass in kilograms.
+/// - `center_of_mass`: The centre of mass in body-local space.
+/// - `inertia`: The inertia tensor about the centre of mass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MassProperties {
+    pub mass: f32,
+    pub center_of_mass: Vec3,
+    pub inertia: Mat3,
+}
+
+/// Calculates the mass properties of a body from its solid voxels.
+///
+/// Each solid voxel is treated as a uniform cube of the given density. The mass is the sum
+/// of the voxel masses, the centre of mass is their weighted average position, and the
+/// inertia tensor combines each cube's own inertia with its offset from the centre of mass
+/// using the parallel axis theorem.
+///
+/// # Arguments
+///
+/// * `voxels` - The voxels making up the body, in body-local space.
+/// * `voxel_size` - The size of each voxel.
+/// * `density` - The density of the solid voxels in kilograms per cubic metre.
+///
+/// # Returns
+///
+/// `Some(MassProperties)` for the solid voxels, or `None` if there are no solid voxels.
+pub fn calculate_mass_properties(
+    voxels: &[Voxel],
+    voxel_size: f32,
+    density: f32,
+) -> Option<MassProperties> {
+    let solid_positions: Vec<Vec3> = voxels
+        .iter()
+        .filter(|voxel| voxel.is_solid)
+        .map(|voxel| voxel.position)
+        .collect();
+
+    if solid_positions.is_empty() {
+        return None;
+    }
+
+    let voxel_mass = density * voxel_size.powi(3);
+    let mass = voxel_mass * solid_positions.len() as f32;
+    let center_of_mass =
+        solid_positions.iter().copied().sum::<Vec3>() / solid_positions.len() as f32;
+
+    // A solid cube of side s has an inertia of m * s^2 / 6 about every axis through its centre.
+    let cube_inertia = Mat3::from_diagonal(Vec3::splat(voxel_mass * voxel_size * voxel_size / 6.0));
+
+    let inertia = solid_positions
+        .iter()
+        .fold(Mat3::ZERO, |inertia, position| {
+            let offset = *position - center_of_mass;
+            let outer_product =
+                Mat3::from_cols(offset * offset.x, offset * offset.y, offset * offset.z);
+            let parallel_axis = (Mat3::from_diagonal(Vec3::splat(offset.length_squared()))
+                - outer_product)
+                * voxel_mass;
+
+            inertia + cube_inertia + parallel_axis
+        });
+
+    Some(MassProperties {
+        mass,
+        center_of_mass,
+        inertia,
+    })
+}
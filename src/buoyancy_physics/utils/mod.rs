@@ -1,3 +1,6 @@
-pub use voxel_utils::*;
+pub use {mass_utils::*, solidity_utils::*, voxel_cache_utils::*, voxel_utils::*};
 
+mod mass_utils;
+mod solidity_utils;
+mod voxel_cache_utils;
 mod voxel_utils;
@@ -0,0 +1,126 @@
+use std::f32::consts::PI;
+
+use bevy::math::Vec3;
+use bevy::prelude::{Mesh, Transform};
+use bevy::render::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
+
+use crate::buoyancy_physics::Voxel;
+
+/// Marks each voxel as solid when its centre lies inside the given mesh.
+///
+/// The test is done in mesh-local space, so the result only depends on the mesh itself and
+/// not on where the hull is or how it is oriented in the world. Voxel positions are expected
+/// in the space of the buoyant body, as produced by `generate_voxel_grid`, and are brought
+/// into mesh-local space through the inverse of the full mesh transform, so rotated and
+/// scaled meshes are handled as well as offset ones.
+///
+/// # Arguments
+///
+/// * `mesh` - The mesh whose interior defines the solid voxels.
+/// * `mesh_transform` - The transform of the mesh relative to the buoyant body.
+/// * `voxels` - The voxels to update.
+pub fn calculate_voxel_solidity(mesh: &Mesh, mesh_transform: &Transform, voxels: &mut [Voxel]) {
+    let triangles = extract_triangles(mesh);
+    let body_to_mesh = mesh_transform.compute_affine().inverse();
+
+    for voxel in voxels.iter_mut() {
+        let local_position = body_to_mesh.transform_point3(voxel.position);
+        voxel.is_solid = is_point_inside_mesh(local_position, &triangles);
+    }
+}
+
+/// Extracts the triangles of a mesh as lists of vertex positions.
+///
+/// Both indexed (`u16` and `u32`) and non-indexed meshes are supported. Meshes that are not
+/// triangle lists or that have no `Float32x3` positions produce no triangles.
+///
+/// # Arguments
+///
+/// * `mesh` - The mesh to read the triangles from.
+///
+/// # Returns
+///
+/// A vector of triangles, each given by its three vertex positions.
+pub fn extract_triangles(mesh: &Mesh) -> Vec<[Vec3; 3]> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return Vec::new();
+    }
+
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        return Vec::new();
+    };
+
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(Indices::U16(indices)) => indices.iter().map(|&index| index as usize).collect(),
+        Some(Indices::U32(indices)) => indices.iter().map(|&index| index as usize).collect(),
+        None => (0..positions.len()).collect(),
+    };
+
+    indices
+        .chunks_exact(3)
+        .map(|triangle| {
+            [
+                Vec3::from(positions[triangle[0]]),
+                Vec3::from(positions[triangle[1]]),
+                Vec3::from(positions[triangle[2]]),
+            ]
+        })
+        .collect()
+}
+
+/// Determines whether a point lies inside a closed surface made of triangles.
+///
+/// The generalized winding number is used rather than ray parity, as it is not thrown off by
+/// rays grazing edges or vertices and degrades gracefully on meshes with small holes or seams,
+/// which are common in modelled hulls. Triangle winding order does not matter.
+///
+/// # Arguments
+///
+/// * `point` - The point to test, in the same space as the triangles.
+/// * `triangles` - The triangles of the surface.
+///
+/// # Returns
+///
+/// `true` if the point is inside the surface, `false` otherwise.
+pub fn is_point_inside_mesh(point: Vec3, triangles: &[[Vec3; 3]]) -> bool {
+    calculate_winding_number(point, triangles).abs() > 0.5
+}
+
+/// Calculates the generalized winding number of a surface around a point.
+///
+/// The winding number is the sum of the signed solid angles subtended by each triangle,
+/// divided by `4π`. It is close to `±1` inside a closed surface and close to `0` outside.
+///
+/// # Arguments
+///
+/// * `point` - The point to measure the winding number around.
+/// * `triangles` - The triangles of the surface.
+///
+/// # Returns
+///
+/// The winding number of the surface around the point.
+pub fn calculate_winding_number(point: Vec3, triangles: &[[Vec3; 3]]) -> f32 {
+    let total_solid_angle: f32 = triangles
+        .iter()
+        .map(|[a, b, c]| calculate_solid_angle(*a - point, *b - point, *c - point))
+        .sum();
+
+    total_solid_angle / (4.0 * PI)
+}
+
+/// Calculates the signed solid angle of a triangle as seen from the origin.
+///
+/// Uses the formula by Van Oosterom and Strackee.
+fn calculate_solid_angle(a: Vec3, b: Vec3, c: Vec3) -> f32 {
+    let (length_a, length_b, length_c) = (a.length(), b.length(), c.length());
+
+    let numerator = a.dot(b.cross(c));
+    let denominator = length_a * length_b * length_c
+        + a.dot(b) * length_c
+        + a.dot(c) * length_b
+        + b.dot(c) * length_a;
+
+    2.0 * numerator.atan2(denominator)
+}
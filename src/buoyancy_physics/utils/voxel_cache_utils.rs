@@ -0,0 +1,119 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use bevy::asset::ron;
+use bevy::prelude::{Mesh, Transform};
+use bevy::render::mesh::{Indices, VertexAttributeValues};
+
+use crate::buoyancy_physics::Voxel;
+
+/// Calculates the key under which the voxels of a mesh are cached.
+///
+/// The key covers everything the voxelization depends on: the vertex positions and indices
+/// of the mesh, the full transform of the mesh within its body and the voxel size. Any change
+/// to these produces a different key, so stale cache entries are never used.
+///
+/// The key names files on disk, so it is hashed with 64-bit FNV-1a over the little-endian
+/// bytes of each value rather than with the standard library's hasher, whose output may
+/// change between Rust versions and would silently invalidate the cache.
+///
+/// # Arguments
+///
+/// * `mesh` - The mesh being voxelized.
+/// * `mesh_transform` - The transform of the mesh relative to the buoyant body.
+/// * `voxel_size` - The size of each voxel.
+///
+/// # Returns
+///
+/// A `u64` hash identifying the voxelization.
+pub fn calculate_voxel_cache_key(mesh: &Mesh, mesh_transform: &Transform, voxel_size: f32) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+
+    if let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    {
+        for position in positions {
+            for value in position {
+                hash = fnv1a(hash, &value.to_le_bytes());
+            }
+        }
+    }
+
+    match mesh.indices() {
+        Some(Indices::U16(indices)) => {
+            for index in indices {
+                hash = fnv1a(hash, &index.to_le_bytes());
+            }
+        }
+        Some(Indices::U32(indices)) => {
+            for index in indices {
+                hash = fnv1a(hash, &index.to_le_bytes());
+            }
+        }
+        None => {}
+    }
+
+    let transform_values = mesh_transform
+        .translation
+        .to_array()
+        .into_iter()
+        .chain(mesh_transform.rotation.to_array())
+        .chain(mesh_transform.scale.to_array());
+    for value in transform_values.chain([voxel_size]) {
+        hash = fnv1a(hash, &value.to_le_bytes());
+    }
+
+    hash
+}
+
+/// The offset basis of the 64-bit FNV-1a hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime of the 64-bit FNV-1a hash.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Folds the given bytes into a 64-bit FNV-1a hash.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Loads previously cached voxels from disk.
+///
+/// # Arguments
+///
+/// * `directory` - The directory holding the voxel cache.
+/// * `key` - The cache key from `calculate_voxel_cache_key`.
+///
+/// # Returns
+///
+/// `Some(Vec<Voxel>)` if a readable cache entry exists, or `None` otherwise.
+pub fn load_cached_voxels(directory: &Path, key: u64) -> Option<Vec<Voxel>> {
+    let contents = fs::read_to_string(voxel_cache_path(directory, key)).ok()?;
+    ron::de::from_str(&contents).ok()
+}
+
+/// Saves voxels to the cache on disk, creating the cache directory if needed.
+///
+/// # Arguments
+///
+/// * `directory` - The directory holding the voxel cache.
+/// * `key` - The cache key from `calculate_voxel_cache_key`.
+/// * `voxels` - The voxels to cache.
+///
+/// # Returns
+///
+/// An `io::Result` indicating whether the voxels were written.
+pub fn save_cached_voxels(directory: &Path, key: u64, voxels: &[Voxel]) -> io::Result<()> {
+    let contents = ron::ser::to_string(voxels).map_err(io::Error::other)?;
+
+    fs::create_dir_all(directory)?;
+    fs::write(voxel_cache_path(directory, key), contents)
+}
+
+/// Returns the path of the cache file for the given key.
+fn voxel_cache_path(directory: &Path, key: u64) -> PathBuf {
+    directory.join(format!("{:016x}.ron", key))
+}
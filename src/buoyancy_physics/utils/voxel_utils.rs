@@ -1,37 +1,45 @@
-use bevy::math::Vec3;
+use bevy::math::{Quat, Vec3};
 use bevy::prelude::{Mesh, Transform};
 
-use crate::buoyancy_physics::constants::VOXEL_SIZE;
 use crate::buoyancy_physics::Voxel;
-use crate::utils::{calculate_mesh_bounds, Vec3I};
+use crate::utils::{calculate_transformed_mesh_bounds, Vec3I};
 
 /// Generates a voxel grid within the bounds of the given mesh.
 ///
-/// This function calculates the bounding box of the mesh and fills it with a voxel grid,
-/// determining the center position of each voxel and initializing it as non-solid.
-/// The solidity of each voxel will be updated based on spatial queries in subsequent steps.
+/// This function calculates the bounding box of the mesh once it has been placed by its
+/// transform, including any rotation and scale, and fills it with a voxel grid centred on
+/// the bounds, determining the center position of each voxel and initializing it as non-solid.
+/// The grid is aligned with the axes of the buoyant body rather than those of the mesh.
+/// The solidity of each voxel is filled in afterwards by `calculate_voxel_solidity`.
 ///
 /// # Arguments
 ///
 /// * `mesh` - A reference to the mesh to be voxelized.
-/// * `mesh_transform` - The transform of the mesh in the game world.
+/// * `mesh_transform` - The transform of the mesh relative to the buoyant body.
+/// * `voxel_size` - The size of each voxel.
 ///
 /// # Returns
 ///
 /// A vector of `Voxel` structs representing the voxel grid.
-pub fn generate_voxel_grid(mesh: &Mesh, mesh_transform: &Transform) -> Vec<Voxel> {
-    let bounds = calculate_mesh_bounds(mesh);
-    let grid_size = calculate_grid_size(&bounds);
+pub fn generate_voxel_grid(mesh: &Mesh, mesh_transform: &Transform, voxel_size: f32) -> Vec<Voxel> {
+    let bounds = calculate_transformed_mesh_bounds(mesh, mesh_transform);
+    let grid_size = calculate_grid_size(&bounds, voxel_size);
     let mut voxels = Vec::new();
 
+    // The grid overhangs the bounds when they are not a multiple of the voxel size, so
+    // spread the overhang evenly on both sides.
+    let grid_extent =
+        Vec3::new(grid_size.x as f32, grid_size.y as f32, grid_size.z as f32) * voxel_size;
+    let grid_min = (bounds.0 + bounds.1 - grid_extent) / 2.0;
+
     for x in 0..grid_size.x {
         for y in 0..grid_size.y {
             for z in 0..grid_size.z {
                 let position = Vec3::new(
-                    bounds.0.x + x as f32 * VOXEL_SIZE + VOXEL_SIZE / 2.0,
-                    bounds.0.y + y as f32 * VOXEL_SIZE + VOXEL_SIZE / 2.0,
-                    bounds.0.z + z as f32 * VOXEL_SIZE + VOXEL_SIZE / 2.0,
-                ) + mesh_transform.translation;
+                    grid_min.x + x as f32 * voxel_size + voxel_size / 2.0,
+                    grid_min.y + y as f32 * voxel_size + voxel_size / 2.0,
+                    grid_min.z + z as f32 * voxel_size + voxel_size / 2.0,
+                );
 
                 voxels.push(Voxel {
                     position,
@@ -53,45 +61,104 @@ pub fn generate_voxel_grid(mesh: &Mesh, mesh_transform: &Transform) -> Vec<Voxel
 /// # Arguments
 ///
 /// * `bounds` - A tuple containing the minimum and maximum coordinates of the bounding box.
+/// * `voxel_size` - The size of each voxel.
 ///
 /// # Returns
 ///
 /// A `Vec3I` struct representing the number of voxels along each axis.
-fn calculate_grid_size(bounds: &(Vec3, Vec3)) -> Vec3I {
+fn calculate_grid_size(bounds: &(Vec3, Vec3), voxel_size: f32) -> Vec3I {
     let (min, max) = bounds;
     let size = *max - *min;
 
     Vec3I::new(
-        (size.x / VOXEL_SIZE).ceil() as i32,
-        (size.y / VOXEL_SIZE).ceil() as i32,
-        (size.z / VOXEL_SIZE).ceil() as i32,
+        (size.x / voxel_size).ceil().max(1.0) as i32,
+        (size.y / voxel_size).ceil().max(1.0) as i32,
+        (size.z / voxel_size).ceil().max(1.0) as i32,
     )
 }
 
-/// Calculates the submerged volume of a voxel based on its position and water height.
+/// The submerged part of a voxel.
+///
+/// # Fields
+/// - `volume`: The submerged volume of the voxel.
+/// - `center_of_buoyancy`: The world-space centroid of the submerged volume, where the
+///   buoyancy force acts. Only meaningful when `volume` is greater than zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Submersion {
+    pub volume: f32,
+    pub center_of_buoyancy: Vec3,
+}
+
+/// Calculates how much of a rotated voxel is below the water surface.
 ///
-/// This function determines the volume of a voxel that is submerged under water,
-/// taking into account the voxel's size and its position relative to the water height.
+/// The voxel is split into `samples_per_axis³` cubic cells. For each cell the water height
+/// is sampled at the cell's world position, and the submerged fraction of the cell is found
+/// by comparing the water height against the vertical extent of the rotated cell. This
+/// follows the slope of waves across the voxel and accounts for the voxel being heeled or
+/// pitched along with its body.
 ///
 /// # Arguments
 ///
-/// * `world_position` - The position of the voxel in the game world.
-/// * `water_height` - The height of the water surface.
+/// * `world_position` - The centre of the voxel in the game world.
+/// * `rotation` - The rotation of the body the voxel belongs to.
 /// * `voxel_size` - The size of the voxel.
+/// * `samples_per_axis` - The number of cells along each axis of the voxel.
+/// * `water_height_at` - Returns the height of the water surface at a world position.
 ///
 /// # Returns
 ///
-/// A `f32` value representing the submerged volume of the voxel.
-pub fn calculate_submerged_volume(world_position: Vec3, water_height: f32, voxel_size: f32) -> f32 {
-    let bottom_of_voxel = world_position.y - voxel_size / 2.0;
-    let top_of_voxel = world_position.y + voxel_size / 2.0;
-
-    if top_of_voxel <= water_height {
-        voxel_size.powi(3) // Fully submerged
-    } else if bottom_of_voxel >= water_height {
-        0.0 // Not submerged
-    } else {
-        let submerged_height = water_height - bottom_of_voxel;
-        submerged_height * voxel_size * voxel_size // Partially submerged volume
+/// A `Submersion` with the submerged volume and its centre of buoyancy.
+pub fn calculate_submersion(
+    world_position: Vec3,
+    rotation: Quat,
+    voxel_size: f32,
+    samples_per_axis: u32,
+    water_height_at: impl Fn(Vec3) -> f32,
+) -> Submersion {
+    let samples_per_axis = samples_per_axis.max(1);
+    let cell_size = voxel_size / samples_per_axis as f32;
+    let cell_volume = cell_size.powi(3);
+
+    // The vertical extent of a rotated cube is the sum of the vertical components of its edges.
+    let cell_height = cell_size
+        * (rotation.mul_vec3(Vec3::X).y.abs()
+            + rotation.mul_vec3(Vec3::Y).y.abs()
+            + rotation.mul_vec3(Vec3::Z).y.abs());
+
+    let mut volume = 0.0;
+    let mut weighted_position = Vec3::ZERO;
+
+    for x in 0..samples_per_axis {
+        for y in 0..samples_per_axis {
+            for z in 0..samples_per_axis {
+                let local_offset = (Vec3::new(x as f32, y as f32, z as f32) + 0.5) * cell_size
+                    - Vec3::splat(voxel_size / 2.0);
+                let cell_position = world_position + rotation.mul_vec3(local_offset);
+
+                let bottom_of_cell = cell_position.y - cell_height / 2.0;
+                let submerged_fraction = ((water_height_at(cell_position) - bottom_of_cell)
+                    / cell_height)
+                    .clamp(0.0, 1.0);
+
+                if submerged_fraction > 0.0 {
+                    let submerged_volume = submerged_fraction * cell_volume;
+                    // Shift the centroid down towards the submerged part of a partially wet cell.
+                    let submerged_position =
+                        cell_position - Vec3::Y * (1.0 - submerged_fraction) * cell_height / 2.0;
+
+                    volume += submerged_volume;
+                    weighted_position += submerged_position * submerged_volume;
+                }
+            }
+        }
+    }
+
+    Submersion {
+        volume,
+        center_of_buoyancy: if volume > 0.0 {
+            weighted_position / volume
+        } else {
+            world_position
+        },
     }
 }
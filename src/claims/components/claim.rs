@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A reservation an actor holds on a `Claimable` entity.
+///
+/// # Fields
+/// - `claimant`: The actor holding the claim.
+/// - `remaining`: The seconds left before the claim lapses, unless it is renewed.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct Claim {
+    pub claimant: Entity,
+    pub remaining: f32,
+}
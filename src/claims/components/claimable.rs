@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::claims::Claim;
+
+/// Component for entities that actors can reserve before heading for them, such as bunks,
+/// bilge pumps, cannons and stockpiles of food.
+///
+/// Each claim lapses once its timeout runs out, so a claim that is never released, because its
+/// claimant was lost or stopped acting without letting go, frees up again by itself. Actors
+/// keep their claims alive by renewing them for as long as they act on the entity.
+///
+/// # Fields
+/// - `capacity`: The number of actors that can hold a claim on the entity at once.
+/// - `claims`: The claims currently held on the entity.
+///
+/// # Methods
+/// - `new`: Creates an unclaimed entity with room for the given number of claims.
+/// - `is_claimed_by`: Checks whether an actor holds a claim on the entity.
+/// - `has_room`: Checks whether an actor could claim the entity.
+/// - `claim`: Claims the entity for an actor, or renews the actor's claim.
+/// - `renew`: Renews the claim an actor holds, if any.
+/// - `release`: Releases the claim an actor holds, if any.
+/// - `expire`: Counts down the timeouts of the claims, dropping those that have lapsed.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Claimable {
+    pub capacity: usize,
+    pub claims: Vec<Claim>,
+}
+
+impl Claimable {
+    /// Creates an unclaimed entity with room for `capacity` claims.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            claims: Vec::new(),
+        }
+    }
+
+    /// Checks whether `claimant` holds a claim on the entity.
+    pub fn is_claimed_by(&self, claimant: Entity) -> bool {
+        self.claims.iter().any(|claim| claim.claimant == claimant)
+    }
+
+    /// Checks whether `claimant` could claim the entity, either because there is room for
+    /// another claim or because they already hold one.
+    pub fn has_room(&self, claimant: Entity) -> bool {
+        self.is_claimed_by(claimant) || self.claims.len() < self.capacity
+    }
+
+    /// Claims the entity for `claimant` for `timeout` seconds, renewing their claim if they
+    /// already hold one.
+    ///
+    /// # Returns
+    /// `true` if `claimant` holds a claim on the entity, `false` if there is no room left.
+    pub fn claim(&mut self, claimant: Entity, timeout: f32) -> bool {
+        if !self.has_room(claimant) {
+            return false;
+        }
+        if !self.is_claimed_by(claimant) {
+            self.claims.push(Claim {
+                claimant,
+                remaining: timeout,
+            });
+        }
+        self.renew(claimant, timeout);
+        true
+    }
+
+    /// Renews the claim `claimant` holds for another `timeout` seconds, if they hold one.
+    pub fn renew(&mut self, claimant: Entity, timeout: f32) {
+        for claim in self.claims.iter_mut() {
+            if claim.claimant == claimant {
+                claim.remaining = timeout;
+            }
+        }
+    }
+
+    /// Releases the claim `claimant` holds, if any.
+    pub fn release(&mut self, claimant: Entity) {
+        self.claims.retain(|claim| claim.claimant != claimant);
+    }
+
+    /// Counts down the timeouts of the claims by `seconds`, dropping those that have lapsed.
+    pub fn expire(&mut self, seconds: f32) {
+        for claim in self.claims.iter_mut() {
+            claim.remaining -= seconds;
+        }
+        self.claims.retain(|claim| claim.remaining > 0.0);
+    }
+}
+
+impl Default for Claimable {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
@@ -0,0 +1,4 @@
+pub use {claim::*, claimable::*};
+
+mod claim;
+mod claimable;
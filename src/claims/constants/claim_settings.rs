@@ -0,0 +1,2 @@
+/// The seconds a claim is held for before it lapses, unless its claimant renews it.
+pub const CLAIM_TIMEOUT: f32 = 10.0;
@@ -0,0 +1,3 @@
+pub use claim_settings::*;
+
+mod claim_settings;
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+pub use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod systems;
+mod utils;
+
+/// Plugin for letting actors reserve the entities they head for.
+///
+/// The `ClaimsPlugin` lets an actor claim a `Claimable` entity, such as a bunk, a bilge pump, a
+/// cannon or a stockpile of food, so that other actors looking for one pass it over once it is
+/// full. Claims are held for a timeout and lapse unless they are renewed. The plugins of the
+/// actions that make use of claimed entities add a `hold_claims` system for their action,
+/// which keeps the claims alive while the action runs and releases them once it ends.
+///
+/// # Components
+/// - `Claimable`: An entity that actors can claim, with the number of claims it has room for
+///   and the claims held on it.
+///
+/// # Systems
+/// - `expire_claims`: Counts down the timeouts of the claims, dropping those that have lapsed.
+///
+/// All systems are run during the `Update` stage and only when the application is in the `AppStates::Running` state.
+pub struct ClaimsPlugin;
+
+impl Plugin for ClaimsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Claim>()
+            .register_type::<Claimable>()
+            .add_systems(Update, expire_claims.run_if(in_state(AppStates::Running)));
+    }
+}
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+use crate::claims::Claimable;
+
+/// Counts down the timeouts of every claim, dropping the claims that have lapsed.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `claimables`: Query to retrieve and modify the claimable entities.
+pub fn expire_claims(time: Res<Time>, mut claimables: Query<&mut Claimable>) {
+    for mut claimable in claimables.iter_mut() {
+        if !claimable.claims.is_empty() {
+            claimable.expire(time.delta_seconds());
+        }
+    }
+}
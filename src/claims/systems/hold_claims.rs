@@ -0,0 +1,39 @@
+use bevy::log::trace;
+use bevy::prelude::{Component, Query, With};
+use big_brain::actions::ActionState;
+use big_brain::prelude::{ActionSpan, Actor};
+
+use crate::claims::utils::{release_claims, renew_claims};
+use crate::claims::{Claimable, CLAIM_TIMEOUT};
+
+/// System to hold on to the claims of actors while they carry out an action of type `A`.
+///
+/// While the action is executing, the claims its actor holds are renewed so that they do not
+/// lapse. Once the action ends, whether it succeeded, failed or was cancelled, the claims are
+/// released for other actors. It should run after the system managing the action, so that it
+/// sees the action end before big-brain cleans it up.
+///
+/// # Parameters
+/// - `claimables`: Query to retrieve and modify the claimable entities.
+/// - `query`: Query to retrieve the actors, action states and action spans of entities with the
+///   action component of type `A`.
+///
+/// # Type Parameters
+/// - `A`: Component type of the action that makes use of the claimed entities.
+pub fn hold_claims<A: Component>(
+    mut claimables: Query<&mut Claimable>,
+    query: Query<(&Actor, &ActionState, &ActionSpan), With<A>>,
+) {
+    for (Actor(actor), state, span) in &query {
+        let _guard = span.span().enter();
+
+        match *state {
+            ActionState::Executing => renew_claims(*actor, CLAIM_TIMEOUT, claimables.iter_mut()),
+            ActionState::Success | ActionState::Failure => {
+                trace!("Releasing my claims.");
+                release_claims(*actor, claimables.iter_mut());
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use {expire_claims::*, hold_claims::*};
+
+mod expire_claims;
+mod hold_claims;
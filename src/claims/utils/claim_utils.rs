@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::claims::Claimable;
+
+/// Releases every claim an actor holds.
+///
+/// # Arguments
+/// - `claimant`: The actor whose claims are released.
+/// - `claimables`: The entities the claims may be held on.
+pub fn release_claims<'a>(claimant: Entity, claimables: impl Iterator<Item = Mut<'a, Claimable>>) {
+    for mut claimable in claimables {
+        if claimable.is_claimed_by(claimant) {
+            claimable.release(claimant);
+        }
+    }
+}
+
+/// Renews every claim an actor holds.
+///
+/// # Arguments
+/// - `claimant`: The actor whose claims are renewed.
+/// - `timeout`: The seconds the claims are renewed for.
+/// - `claimables`: The entities the claims may be held on.
+pub fn renew_claims<'a>(
+    claimant: Entity,
+    timeout: f32,
+    claimables: impl Iterator<Item = Mut<'a, Claimable>>,
+) {
+    for mut claimable in claimables {
+        if claimable.is_claimed_by(claimant) {
+            claimable.renew(claimant, timeout);
+        }
+    }
+}
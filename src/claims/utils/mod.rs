@@ -0,0 +1,3 @@
+pub use claim_utils::*;
+
+mod claim_utils;
@@ -1,5 +1,5 @@
 use bevy::asset::{Assets, Handle};
-use bevy::hierarchy::Children;
+use bevy::hierarchy::{Children, Parent};
 use bevy::log::error;
 use bevy::prelude::{
     Added, Commands, Entity, GlobalTransform, Mesh, Query, Res, Transform, Visibility, With,
@@ -11,7 +11,7 @@ use oxidized_navigation::NavMeshAffector;
 use crate::collider_management::ColliderMarker;
 use crate::navmesh::NavMeshMarker;
 use crate::ship::Ship;
-use crate::utils::find_mesh;
+use crate::utils::{find_mesh, find_top_level_entity};
 
 /// System to process and configure collider objects within the game.
 ///
@@ -27,7 +27,8 @@ use crate::utils::find_mesh;
 /// - `children`: Query to retrieve the children of entities.
 /// - `meshes`: Resource containing the assets of meshes.
 /// - `mesh_handles`: Query to retrieve mesh handles from entities.
-/// - `parent_query`: Query to retrieve the transform of the ship entity.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying the collider.
+/// - `ship_query`: Query to retrieve the transforms of ship entities.
 ///
 /// # Details
 /// For each `ColliderMarker` entity, the system:
@@ -45,7 +46,8 @@ pub fn read_colliders(
     children: Query<&Children>,
     meshes: Res<Assets<Mesh>>,
     mesh_handles: Query<&Handle<Mesh>>,
-    parent_query: Query<&Transform, With<Ship>>,
+    parent_query: Query<&Parent>,
+    ship_query: Query<&Transform, With<Ship>>,
 ) {
     for (entity, nav_mesh_marker_opt, transform) in collider_marker_query.iter() {
         println!("QUERY WORKS");
@@ -56,7 +58,9 @@ pub fn read_colliders(
                 if let Some(collider) = Collider::trimesh_from_mesh(mesh) {
                     println!("MAKING COLLIDER");
                     // Update transform to follow the ship if needed
-                    if let Ok(ship_transform) = parent_query.get_single() {
+                    let ship = find_top_level_entity(entity, &parent_query);
+
+                    if let Ok(ship_transform) = ship_query.get(ship) {
                         commands.entity(entity).insert((
                             Transform {
                                 translation: ship_transform.translation + transform.translation,
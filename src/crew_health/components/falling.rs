@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Tracks how fast a crew member is falling, so the landing can be judged.
+///
+/// # Fields
+/// - `speed`: The downward speed of the crew member on the last update, in metres per second.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Falling {
+    pub speed: f32,
+}
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::crew_health::utils::calculate_work_rate;
+use crate::crew_health::{Injury, InjuryKind};
+
+/// Tracks the health of a crew member and the injuries they are carrying.
+///
+/// The `Health` component is added to crew members. Injuries take health away, and an injured
+/// crew member works slower until they have been treated in the sickbay. A crew member whose
+/// health runs out dies.
+///
+/// # Fields
+/// - `level`: The crew member's health, from `0.0` (dead) to `1.0` (unhurt).
+/// - `injuries`: The injuries the crew member has not yet recovered from.
+/// - `under_treatment`: Whether the crew member is being treated in the sickbay.
+///
+/// # Methods
+/// - `injure(&mut self, kind: InjuryKind, severity: f32)`: Adds an injury, taking health away.
+/// - `starve(&mut self, amount: f32)`: Takes health away to starvation, adding to any starvation
+///   the crew member is already suffering from.
+/// - `treat(&mut self, amount: f32)`: Restores health, clearing the injuries once fully recovered.
+/// - `is_injured(&self) -> bool`: Returns whether the crew member is carrying any injuries.
+/// - `is_dead(&self) -> bool`: Returns whether the crew member's health has run out.
+/// - `work_rate(&self) -> f32`: Returns how fast the crew member works given their health.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Health {
+    pub level: f32,
+    pub injuries: Vec<Injury>,
+    pub under_treatment: bool,
+}
+
+impl Health {
+    /// Adds an injury, taking its severity from the crew member's health.
+    pub fn injure(&mut self, kind: InjuryKind, severity: f32) {
+        let severity = severity.clamp(0.0, 1.0);
+        self.level = (self.level - severity).max(0.0);
+        self.injuries.push(Injury { kind, severity });
+    }
+
+    /// Takes health away to starvation.
+    ///
+    /// Starvation wears a crew member down a little at a time, so it is kept as a single
+    /// injury that grows for as long as they go hungry, rather than a new injury each time.
+    pub fn starve(&mut self, amount: f32) {
+        let amount = amount.clamp(0.0, self.level);
+        self.level -= amount;
+
+        match self
+            .injuries
+            .iter_mut()
+            .find(|injury| injury.kind == InjuryKind::Starvation)
+        {
+            Some(injury) => injury.severity = (injury.severity + amount).min(1.0),
+            None => self.injuries.push(Injury {
+                kind: InjuryKind::Starvation,
+                severity: amount,
+            }),
+        }
+    }
+
+    /// Restores health, up to full health. The injuries are cleared once the crew member has
+    /// fully recovered.
+    pub fn treat(&mut self, amount: f32) {
+        self.level = (self.level + amount.max(0.0)).min(1.0);
+        if self.level >= 1.0 {
+            self.injuries.clear();
+        }
+    }
+
+    /// Returns whether the crew member is carrying any injuries.
+    pub fn is_injured(&self) -> bool {
+        !self.injuries.is_empty()
+    }
+
+    /// Returns whether the crew member's health has run out.
+    pub fn is_dead(&self) -> bool {
+        self.level <= 0.0
+    }
+
+    /// Returns how fast the crew member works given their health, from `MIN_WORK_RATE` to `1.0`.
+    pub fn work_rate(&self) -> f32 {
+        calculate_work_rate(self.level)
+    }
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self {
+            level: 1.0,
+            injuries: Vec::new(),
+            under_treatment: false,
+        }
+    }
+}
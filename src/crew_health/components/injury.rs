@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// An injury a crew member has suffered and not yet recovered from.
+///
+/// # Fields
+/// - `kind`: What caused the injury.
+/// - `severity`: The health the injury took, from `0.0` to `1.0`.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct Injury {
+    pub kind: InjuryKind,
+    pub severity: f32,
+}
+
+/// The ways a crew member can be hurt.
+///
+/// - `Splinter`: Struck by wooden splinters thrown off by shot hitting the ship nearby.
+/// - `Fall`: Hurt by a hard landing after a fall.
+/// - `Shot`: Struck by shot directly.
+/// - `Starvation`: Wasted away by going without food.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum InjuryKind {
+    Splinter,
+    Fall,
+    Shot,
+    Starvation,
+}
@@ -0,0 +1,5 @@
+pub use {falling::*, health::*, injury::*};
+
+mod falling;
+mod health;
+mod injury;
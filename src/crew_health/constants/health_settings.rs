@@ -0,0 +1,32 @@
+/// How fast a crew member on the brink of death still works, as a fraction of full speed.
+pub const MIN_WORK_RATE: f32 = 0.25;
+
+/// The distance in metres from a hit within which crew are struck by the shot itself.
+pub const SHOT_INJURY_RADIUS: f32 = 1.0;
+
+/// The health a crew member loses to a direct hit from round or chain shot.
+pub const SHOT_INJURY_SEVERITY: f32 = 0.8;
+
+/// The distance in metres from a hit within which crew are struck by grape shot. Grape shot
+/// scatters, so it hurts crew across a wider area but less severely.
+pub const GRAPE_SHOT_INJURY_RADIUS: f32 = 3.0;
+
+/// The health a crew member loses to a direct hit from grape shot.
+pub const GRAPE_SHOT_INJURY_SEVERITY: f32 = 0.4;
+
+/// The distance in metres from a round shot hit within which crew are struck by splinters.
+pub const SPLINTER_RADIUS: f32 = 5.0;
+
+/// The health a crew member right beside a round shot hit loses to splinters. Crew further
+/// away are hurt less.
+pub const SPLINTER_SEVERITY: f32 = 0.3;
+
+/// The downward speed in metres per second a crew member can land at without being hurt.
+pub const FALL_INJURY_SPEED: f32 = 8.0;
+
+/// The health a crew member loses for every metre per second they land faster than
+/// `FALL_INJURY_SPEED`.
+pub const FALL_SEVERITY_PER_SPEED: f32 = 0.05;
+
+/// The health per second a crew member loses while starving.
+pub const STARVATION_HEALTH_LOSS: f32 = 0.002;
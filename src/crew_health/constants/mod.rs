@@ -0,0 +1,3 @@
+pub use health_settings::*;
+
+mod health_settings;
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::crew_health::InjuryKind;
+
+/// Event sent when a crew member dies.
+///
+/// This event is sent by the `remove_dead_crew` system once the crew member has been struck
+/// from their ship's crew, just before they are de-spawned. Anything that reacts to losing
+/// crew, such as the interface or the morale of their shipmates, reads these events.
+///
+/// # Fields
+/// - `crew_member`: The crew member who died. The entity no longer exists once the event is read.
+/// - `ship`: The ship the crew member served aboard, if any.
+/// - `cause`: The injury that killed the crew member, if known.
+#[derive(Event, Debug, Clone)]
+pub struct CrewDied {
+    pub crew_member: Entity,
+    pub ship: Option<Entity>,
+    pub cause: Option<InjuryKind>,
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::crew_health::InjuryKind;
+
+/// Event sent when a crew member is injured.
+///
+/// This event is sent by the systems that hurt crew members, such as `injure_crew_from_hits`
+/// and `injure_crew_from_falls`.
+///
+/// # Fields
+/// - `crew_member`: The crew member who was injured.
+/// - `kind`: What caused the injury.
+/// - `severity`: The health the injury took, from `0.0` to `1.0`.
+#[derive(Event, Debug, Clone)]
+pub struct CrewInjured {
+    pub crew_member: Entity,
+    pub kind: InjuryKind,
+    pub severity: f32,
+}
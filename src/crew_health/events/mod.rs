@@ -0,0 +1,4 @@
+pub use {crew_died::*, crew_injured::*};
+
+mod crew_died;
+mod crew_injured;
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+pub use events::*;
+pub use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod events;
+mod systems;
+mod utils;
+
+/// Plugin for the health, injury and death of crew members.
+///
+/// The `CrewHealthPlugin` lets crew members be hurt by shot, by the splinters it throws off,
+/// by hard falls and by starvation. Injured crew work slower until they are treated in the
+/// sickbay, and crew whose health runs out die and are struck from their ship's crew.
+///
+/// # Components
+/// - `Falling`: Tracks how fast a crew member is falling, so the landing can be judged.
+/// - `Health`: Tracks the health of a crew member and the injuries they are carrying.
+///
+/// # Events
+/// - `CrewDied`: Sent when a crew member dies.
+/// - `CrewInjured`: Sent when a crew member is injured.
+///
+/// # Systems
+/// - `injure_crew_from_falls`: Injures crew members who land hard after a fall.
+/// - `injure_crew_from_hits`: Injures crew members caught by shot.
+/// - `remove_dead_crew`: Removes crew members whose health has run out.
+/// - `starve_crew`: Wears down the health and morale of crew members who are starving.
+///
+/// All systems are run during the `Update` stage and only when the application is in the `AppStates::Running` state.
+pub struct CrewHealthPlugin;
+
+impl Plugin for CrewHealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Falling>()
+            .register_type::<Health>()
+            .add_event::<CrewDied>()
+            .add_event::<CrewInjured>()
+            .add_systems(
+                Update,
+                injure_crew_from_falls.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                injure_crew_from_hits.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                remove_dead_crew.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, starve_crew.run_if(in_state(AppStates::Running)));
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::{Entity, EventWriter, Query};
+use bevy_xpbd_3d::components::LinearVelocity;
+
+use crate::crew_health::utils::calculate_fall_injury;
+use crate::crew_health::{CrewInjured, Falling, Health, InjuryKind};
+
+/// Injures crew members who land hard after a fall.
+///
+/// A crew member has landed when their downward speed drops to less than half of what it was
+/// on the last update. The faster they were falling, the worse they are hurt, and a
+/// `CrewInjured` event is sent for them.
+///
+/// # Parameters
+/// - `crew_injured_events`: Event writer for `CrewInjured` events.
+/// - `crew`: Query to retrieve the velocities of crew members and modify how fast they are
+///   falling and their health.
+pub fn injure_crew_from_falls(
+    mut crew_injured_events: EventWriter<CrewInjured>,
+    mut crew: Query<(Entity, &LinearVelocity, &mut Falling, &mut Health)>,
+) {
+    for (crew_member, velocity, mut falling, mut health) in crew.iter_mut() {
+        let speed = (-velocity.y).max(0.0);
+
+        if speed < falling.speed / 2.0 {
+            if let Some(severity) = calculate_fall_injury(falling.speed) {
+                health.injure(InjuryKind::Fall, severity);
+                crew_injured_events.send(CrewInjured {
+                    crew_member,
+                    kind: InjuryKind::Fall,
+                    severity,
+                });
+            }
+        }
+
+        falling.speed = speed;
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::{Entity, EventReader, EventWriter, GlobalTransform, Query, With};
+
+use crate::crew_health::utils::calculate_hit_injury;
+use crate::crew_health::{CrewInjured, Health};
+use crate::crew_management::CrewMember;
+use crate::gunnery::ProjectileHit;
+
+/// Injures crew members caught by shot in response to `ProjectileHit` events.
+///
+/// Each crew member near a hit is hurt by the shot itself or by the splinters it throws off,
+/// and a `CrewInjured` event is sent for them.
+///
+/// # Parameters
+/// - `projectile_hit_events`: Event reader for `ProjectileHit` events.
+/// - `crew_injured_events`: Event writer for `CrewInjured` events.
+/// - `crew`: Query to retrieve and modify the health of crew members, along with their transforms.
+pub fn injure_crew_from_hits(
+    mut projectile_hit_events: EventReader<ProjectileHit>,
+    mut crew_injured_events: EventWriter<CrewInjured>,
+    mut crew: Query<(Entity, &GlobalTransform, &mut Health), With<CrewMember>>,
+) {
+    for hit in projectile_hit_events.read() {
+        for (crew_member, transform, mut health) in crew.iter_mut() {
+            let distance = transform.translation().distance(hit.position);
+            let Some((kind, severity)) = calculate_hit_injury(hit.shot, distance) else {
+                continue;
+            };
+
+            health.injure(kind, severity);
+            crew_injured_events.send(CrewInjured {
+                crew_member,
+                kind,
+                severity,
+            });
+        }
+    }
+}
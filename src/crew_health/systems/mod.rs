@@ -0,0 +1,8 @@
+pub use {
+    injure_crew_from_falls::*, injure_crew_from_hits::*, remove_dead_crew::*, starve_crew::*,
+};
+
+mod injure_crew_from_falls;
+mod injure_crew_from_hits;
+mod remove_dead_crew;
+mod starve_crew;
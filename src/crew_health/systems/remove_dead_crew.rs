@@ -0,0 +1,48 @@
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Commands, Entity, EventWriter, Query};
+
+use crate::crew_health::{CrewDied, Health};
+use crate::crew_management::{discharge_crew_member, ShipCrew, Station};
+use crate::gunnery::Cannon;
+use crate::ship_items::BilgePump;
+
+/// Removes crew members whose health has run out.
+///
+/// A dead crew member is struck from their ship's crew and from the duty roster, leaves any
+/// bilge pump or cannon they were operating, and is de-spawned. A `CrewDied` event is sent
+/// for them.
+///
+/// # Parameters
+/// - `commands`: Commands for de-spawning dead crew members.
+/// - `crew_died_events`: Event writer for `CrewDied` events.
+/// - `crew`: Query to retrieve the health of crew members.
+/// - `ships`: Query to retrieve and modify the crew of ships.
+/// - `stations`: Query to retrieve and modify the stations.
+/// - `pumps`: Query to retrieve and modify the bilge pumps.
+/// - `cannons`: Query to retrieve and modify the cannons.
+pub fn remove_dead_crew(
+    mut commands: Commands,
+    mut crew_died_events: EventWriter<CrewDied>,
+    crew: Query<(Entity, &Health)>,
+    mut ships: Query<(Entity, &mut ShipCrew)>,
+    mut stations: Query<&mut Station>,
+    mut pumps: Query<&mut BilgePump>,
+    mut cannons: Query<&mut Cannon>,
+) {
+    for (crew_member, health) in crew.iter().filter(|(_, health)| health.is_dead()) {
+        let ship = discharge_crew_member(
+            crew_member,
+            &mut ships,
+            &mut stations,
+            &mut pumps,
+            &mut cannons,
+        );
+
+        crew_died_events.send(CrewDied {
+            crew_member,
+            ship,
+            cause: health.injuries.last().map(|injury| injury.kind),
+        });
+        commands.entity(crew_member).despawn_recursive();
+    }
+}
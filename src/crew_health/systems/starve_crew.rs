@@ -0,0 +1,30 @@
+use bevy::prelude::{Query, Res, Time};
+
+use crate::ai_need_behavior::{Hunger, Need, MAX_NEED_LEVEL};
+use crate::crew_health::{Health, STARVATION_HEALTH_LOSS};
+use crate::crew_morale::{Morale, STARVATION_MORALE_LOSS};
+
+/// Wears down crew members who are starving.
+///
+/// A crew member starves once their hunger has grown to `MAX_NEED_LEVEL` and they are not
+/// eating, such as when the ship's food has run out. Starving crew lose health, and die if
+/// they go without food for long enough, and lose heart as they waste away.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `crew`: Query to retrieve the hunger of crew members and modify their health and morale.
+pub fn starve_crew(
+    time: Res<Time>,
+    mut crew: Query<(&Need<Hunger>, &mut Health, Option<&mut Morale>)>,
+) {
+    for (hunger, mut health, morale) in crew.iter_mut() {
+        if hunger.level < MAX_NEED_LEVEL || hunger.is_satisfying {
+            continue;
+        }
+
+        health.starve(STARVATION_HEALTH_LOSS * time.delta_seconds());
+        if let Some(mut morale) = morale {
+            morale.lower(STARVATION_MORALE_LOSS * time.delta_seconds());
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::crew_health::{
+    InjuryKind, FALL_INJURY_SPEED, FALL_SEVERITY_PER_SPEED, GRAPE_SHOT_INJURY_RADIUS,
+    GRAPE_SHOT_INJURY_SEVERITY, MIN_WORK_RATE, SHOT_INJURY_RADIUS, SHOT_INJURY_SEVERITY,
+    SPLINTER_RADIUS, SPLINTER_SEVERITY,
+};
+use crate::gunnery::ShotType;
+
+/// Calculates the injury a crew member suffers from shot striking near them.
+///
+/// Crew right where the shot strikes are hit by the shot itself. Round shot also throws off
+/// splinters that hurt crew further away, less so the further they are from the hit. Grape
+/// shot scatters across a wider area, and chain shot only hurts those it strikes.
+///
+/// # Arguments
+/// - `shot`: The type of shot that struck.
+/// - `distance`: The distance in metres from the hit to the crew member.
+///
+/// # Returns
+/// The kind of injury and the health it takes, or `None` if the crew member is unhurt.
+pub fn calculate_hit_injury(shot: ShotType, distance: f32) -> Option<(InjuryKind, f32)> {
+    match shot {
+        ShotType::GrapeShot if distance <= GRAPE_SHOT_INJURY_RADIUS => {
+            Some((InjuryKind::Shot, GRAPE_SHOT_INJURY_SEVERITY))
+        }
+        ShotType::RoundShot | ShotType::ChainShot if distance <= SHOT_INJURY_RADIUS => {
+            Some((InjuryKind::Shot, SHOT_INJURY_SEVERITY))
+        }
+        ShotType::RoundShot if distance <= SPLINTER_RADIUS => Some((
+            InjuryKind::Splinter,
+            SPLINTER_SEVERITY * (1.0 - distance / SPLINTER_RADIUS),
+        )),
+        _ => None,
+    }
+}
+
+/// Calculates the injury a crew member suffers from landing after a fall.
+///
+/// # Arguments
+/// - `landing_speed`: The downward speed the crew member landed at, in metres per second.
+///
+/// # Returns
+/// The health the landing takes, or `None` if the crew member landed safely.
+pub fn calculate_fall_injury(landing_speed: f32) -> Option<f32> {
+    let excess_speed = landing_speed - FALL_INJURY_SPEED;
+    (excess_speed > 0.0).then_some(excess_speed * FALL_SEVERITY_PER_SPEED)
+}
+
+/// Calculates how fast a crew member works given their health.
+///
+/// # Arguments
+/// - `level`: The crew member's health, from `0.0` to `1.0`.
+///
+/// # Returns
+/// The fraction of full speed the crew member works at, from `MIN_WORK_RATE` to `1.0`.
+pub fn calculate_work_rate(level: f32) -> f32 {
+    MIN_WORK_RATE + (1.0 - MIN_WORK_RATE) * level.clamp(0.0, 1.0)
+}
@@ -0,0 +1,3 @@
+pub use injury_utils::*;
+
+mod injury_utils;
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::crew_management::Skill;
+
+/// The duty a crew member is rated for aboard ship.
+///
+/// Each crew member has a `CrewRole`, and the duty roster only assigns them to stations of
+/// the same role.
+///
+/// - `Helmsman`: Steers the ship at the helm.
+/// - `Gunner`: Serves one of the ship's cannons.
+/// - `SailHandler`: Works the sheets and braces that trim, set and furl the sails.
+/// - `Carpenter`: Keeps the hull and the ship's systems in repair.
+/// - `Cook`: Runs the galley.
+/// - `Surgeon`: Treats the injured in the sickbay.
+///
+/// # Constants
+/// - `ALL`: Every role, in the order they are listed here.
+///
+/// # Methods
+/// - `skill(&self) -> Skill`: Returns the skill the work of the role calls for.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect, Serialize, Deserialize, Default,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub enum CrewRole {
+    Helmsman,
+    Gunner,
+    #[default]
+    SailHandler,
+    Carpenter,
+    Cook,
+    Surgeon,
+}
+
+impl CrewRole {
+    /// Every role, in the order they are listed here.
+    pub const ALL: [CrewRole; 6] = [
+        CrewRole::Helmsman,
+        CrewRole::Gunner,
+        CrewRole::SailHandler,
+        CrewRole::Carpenter,
+        CrewRole::Cook,
+        CrewRole::Surgeon,
+    ];
+
+    /// Returns the skill the work of the role calls for.
+    pub fn skill(&self) -> Skill {
+        match self {
+            CrewRole::Helmsman | CrewRole::SailHandler => Skill::Seamanship,
+            CrewRole::Gunner => Skill::Gunnery,
+            CrewRole::Carpenter => Skill::Carpentry,
+            CrewRole::Cook => Skill::Cooking,
+            CrewRole::Surgeon => Skill::Surgery,
+        }
+    }
+}
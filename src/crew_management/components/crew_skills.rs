@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::crew_management::utils::calculate_skill_effectiveness;
+use crate::crew_management::RATED_EXPERIENCE;
+
+/// The skills a crew member learns by working aboard ship.
+///
+/// - `Gunnery`: Loading and serving the cannons.
+/// - `Seamanship`: Steering the ship and working the sails.
+/// - `Carpentry`: Repairing the hull and the ship's systems.
+/// - `Cooking`: Running the galley.
+/// - `Surgery`: Treating the injured.
+///
+/// # Constants
+/// - `ALL`: Every skill, in the order they are listed here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize, Default)]
+pub enum Skill {
+    Gunnery,
+    #[default]
+    Seamanship,
+    Carpentry,
+    Cooking,
+    Surgery,
+}
+
+impl Skill {
+    /// Every skill, in the order they are listed here.
+    pub const ALL: [Skill; 5] = [
+        Skill::Gunnery,
+        Skill::Seamanship,
+        Skill::Carpentry,
+        Skill::Cooking,
+        Skill::Surgery,
+    ];
+}
+
+/// Tracks the experience a crew member has gained in each skill.
+///
+/// The `CrewSkills` component is added to crew members. Crew members gain experience in a
+/// skill by working at it, and the more experienced they are the more effective their work,
+/// from a green hand at `NOVICE_EFFECTIVENESS` up to a seasoned one at `1.0`.
+///
+/// The skills of the crew on each ship's roster are saved to the `CrewRecords` as the game
+/// exits and given back to the crew of the same roles when the ship's crew is next spawned, so
+/// veterans keep their experience across voyages.
+///
+/// # Fields
+/// - `gunnery`: The seconds of experience in `Gunnery`.
+/// - `seamanship`: The seconds of experience in `Seamanship`.
+/// - `carpentry`: The seconds of experience in `Carpentry`.
+/// - `cooking`: The seconds of experience in `Cooking`.
+/// - `surgery`: The seconds of experience in `Surgery`.
+///
+/// # Methods
+/// - `rated(skill: Skill) -> Self`: Creates the skills of a crew member rated for a skill.
+/// - `experience(&self, skill: Skill) -> f32`: Returns the experience in a skill.
+/// - `gain_experience(&mut self, skill: Skill, experience: f32)`: Adds experience in a skill.
+/// - `effectiveness(&self, skill: Skill) -> f32`: Returns how effective the crew member's work
+///   in a skill is.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct CrewSkills {
+    pub gunnery: f32,
+    pub seamanship: f32,
+    pub carpentry: f32,
+    pub cooking: f32,
+    pub surgery: f32,
+}
+
+impl CrewSkills {
+    /// Creates the skills of a crew member who signed on rated for a skill.
+    ///
+    /// # Parameters
+    /// - `skill`: The skill the crew member is rated for.
+    ///
+    /// # Returns
+    /// New `CrewSkills` with `RATED_EXPERIENCE` in the rated skill and none in the others.
+    pub fn rated(skill: Skill) -> Self {
+        let mut skills = Self::default();
+        skills.gain_experience(skill, RATED_EXPERIENCE);
+        skills
+    }
+
+    /// Returns the seconds of experience in a skill.
+    pub fn experience(&self, skill: Skill) -> f32 {
+        match skill {
+            Skill::Gunnery => self.gunnery,
+            Skill::Seamanship => self.seamanship,
+            Skill::Carpentry => self.carpentry,
+            Skill::Cooking => self.cooking,
+            Skill::Surgery => self.surgery,
+        }
+    }
+
+    /// Adds seconds of experience in a skill. Experience is never lost.
+    pub fn gain_experience(&mut self, skill: Skill, experience: f32) {
+        let experience = experience.max(0.0);
+        match skill {
+            Skill::Gunnery => self.gunnery += experience,
+            Skill::Seamanship => self.seamanship += experience,
+            Skill::Carpentry => self.carpentry += experience,
+            Skill::Cooking => self.cooking += experience,
+            Skill::Surgery => self.surgery += experience,
+        }
+    }
+
+    /// Returns how effective the crew member's work in a skill is, from `NOVICE_EFFECTIVENESS`
+    /// to `1.0`.
+    pub fn effectiveness(&self, skill: Skill) -> f32 {
+        calculate_skill_effectiveness(self.experience(skill))
+    }
+}
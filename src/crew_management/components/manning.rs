@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Tracks how well the stations that work a ship's helm and sails are manned.
+///
+/// The `Manning` component is added to ships and updated from their stations. The helm and
+/// the sails are worked by the ship as a whole, in proportion to how well their stations are
+/// manned, so an unmanned helm cannot put the rudder over and short-handed sheets trim the
+/// sails slowly. Stations manned by green hands count for less than those manned by seasoned
+/// ones. A ship with no stations for the helm or sails is treated as fully manned.
+///
+/// The other roles work a single item each, such as a cannon, the galley or the sickbay,
+/// which reads the crew member at its own station rather than the manning of the ship.
+///
+/// # Fields
+/// - `helm`: How well the `Helmsman` stations are manned.
+/// - `sails`: How well the `SailHandler` stations are manned.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Manning {
+    pub helm: f32,
+    pub sails: f32,
+}
+
+impl Default for Manning {
+    fn default() -> Self {
+        Self {
+            helm: 1.0,
+            sails: 1.0,
+        }
+    }
+}
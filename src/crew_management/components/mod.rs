@@ -1,3 +1,8 @@
-pub use crew_member::*;
+pub use {crew_member::*, crew_role::*, crew_skills::*, manning::*, ship_crew::*, station::*};
 
 mod crew_member;
+mod crew_role;
+mod crew_skills;
+mod manning;
+mod ship_crew;
+mod station;
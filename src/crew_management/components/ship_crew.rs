@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The crew a ship carries.
+///
+/// The `ShipCrew` component is added to a ship to have a crew spawned aboard it. The crew members
+/// spawned for the ship are recorded, so the ship's strength can be judged by how many of them
+/// are still alive.
+///
+/// # Fields
+/// - `complement`: The number of crew members the ship sails with.
+/// - `members`: The crew members spawned aboard the ship.
+///
+/// # Methods
+/// - `new(complement: u32) -> Self`: Creates a `ShipCrew` waiting for its crew to be spawned.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct ShipCrew {
+    pub complement: u32,
+    pub members: Vec<Entity>,
+}
+
+impl ShipCrew {
+    /// Creates a `ShipCrew` waiting for its crew to be spawned.
+    ///
+    /// # Parameters
+    /// - `complement`: The number of crew members the ship sails with.
+    ///
+    /// # Returns
+    /// A new `ShipCrew` with no members yet.
+    pub fn new(complement: u32) -> Self {
+        Self {
+            complement,
+            members: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::crew_management::CrewRole;
+
+/// A place aboard a ship where a crew member of a particular role works.
+///
+/// The `Station` component is added to entities inside a ship's hierarchy, such as the helm,
+/// the sheets of the sails or the cannons themselves. The duty roster assigns a crew member
+/// of the station's role to it, and the station is manned while that crew member is at work
+/// there. The ship systems a station drives only work while it is manned.
+///
+/// # Fields
+/// - `role`: The role of the crew member who works the station.
+/// - `crew`: The crew member assigned to the station by the duty roster, if any.
+/// - `manned`: Whether the assigned crew member is at the station and working it.
+///
+/// # Methods
+/// - `new(role: CrewRole) -> Self`: Creates an unassigned `Station` for the given role.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Station {
+    pub role: CrewRole,
+    pub crew: Option<Entity>,
+    pub manned: bool,
+}
+
+impl Station {
+    /// Creates an unassigned `Station`.
+    ///
+    /// # Parameters
+    /// - `role`: The role of the crew member who works the station.
+    ///
+    /// # Returns
+    /// A new `Station` with no crew assigned.
+    pub fn new(role: CrewRole) -> Self {
+        Self {
+            role,
+            crew: None,
+            manned: false,
+        }
+    }
+}
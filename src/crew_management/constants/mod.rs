@@ -0,0 +1,4 @@
+pub use {skill_settings::*, station_settings::*};
+
+mod skill_settings;
+mod station_settings;
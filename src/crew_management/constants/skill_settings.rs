@@ -0,0 +1,10 @@
+/// How effective the work of a crew member with no experience in a skill is.
+pub const NOVICE_EFFECTIVENESS: f32 = 0.5;
+
+/// The seconds of experience over which a crew member closes most of the gap between a novice
+/// and a seasoned hand. After this much experience, a crew member has closed about two thirds
+/// of it.
+pub const SKILL_EXPERIENCE_SCALE: f32 = 600.0;
+
+/// The seconds of experience a crew member signs on with in the skill they are rated for.
+pub const RATED_EXPERIENCE: f32 = 900.0;
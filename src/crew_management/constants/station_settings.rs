@@ -0,0 +1,22 @@
+use crate::crew_management::CrewRole;
+
+/// The distance in metres within which a crew member is at their station and can work it.
+pub const STATION_DISTANCE: f32 = 1.5;
+
+/// The roles crew members are rated for, in the order they are signed on.
+///
+/// A ship's crew is rated down this list, starting again from the top once it runs out, so
+/// larger crews carry more hands of every role. The helmsmen, gunners and sail handlers come
+/// in pairs, one for each watch, so the helm, guns and sails are worked whichever watch is on
+/// duty.
+pub const WATCH_BILL: [CrewRole; 9] = [
+    CrewRole::Helmsman,
+    CrewRole::Gunner,
+    CrewRole::SailHandler,
+    CrewRole::Helmsman,
+    CrewRole::Gunner,
+    CrewRole::SailHandler,
+    CrewRole::Carpenter,
+    CrewRole::Cook,
+    CrewRole::Surgeon,
+];
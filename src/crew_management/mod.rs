@@ -1,12 +1,18 @@
 use bevy::prelude::*;
 
 pub use components::*;
+pub use constants::*;
+pub use resources::*;
 use systems::*;
+pub use utils::*;
 
 use crate::asset_management::AppStates;
 
 mod components;
+mod constants;
+mod resources;
 mod systems;
+mod utils;
 
 pub struct CrewManagementPlugin;
 
@@ -14,15 +20,49 @@ pub struct CrewManagementPlugin;
 ///
 /// The `CrewManagementPlugin` handles the creation and management of crew members,
 /// setting up the necessary components and systems to simulate their behavior and interactions.
+/// Each crew member is rated for a role, and a duty roster assigns them to the stations of
+/// that role aboard their ship. Crew members grow more skilled, and so more effective, the
+/// longer they work, and keep their skills from one voyage to the next.
 ///
 /// # Components
 /// - `CrewMember`: A marker component indicating that an entity is a crew member.
+/// - `CrewRole`: The duty a crew member is rated for aboard ship.
+/// - `CrewSkills`: Tracks the experience a crew member has gained in each skill.
+/// - `Manning`: Tracks how well the stations that work a ship's helm and sails are manned.
+/// - `ShipCrew`: The crew a ship carries.
+/// - `Station`: A place aboard a ship where a crew member of a particular role works.
+///
+/// # Resources
+/// - `CrewRecords`: Keeps the skills of each ship's crew on disk from one voyage to the next.
 ///
 /// # Systems
-/// - `spawn_crew_members`: Spawns crew members in the game world and configures their initial behavior and properties.
+/// - `assign_duty_roster`: Assigns crew members to the vacant stations of their role.
+/// - `save_crew_skills`: Saves the skills of the crew on each ship's roster as the game exits.
+/// - `spawn_crew_members`: Spawns the crew of each ship in the game world and configures their initial behavior and properties.
+/// - `train_crew_skills`: Gives the crew experience for the time they spend working their stations.
+/// - `update_manning`: Updates how well the helm and sails of each ship are manned.
 impl Plugin for CrewManagementPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<CrewMember>()
-            .add_systems(OnEnter(AppStates::Running), spawn_crew_members);
+            .register_type::<CrewRole>()
+            .register_type::<CrewSkills>()
+            .register_type::<Manning>()
+            .register_type::<ShipCrew>()
+            .register_type::<Station>()
+            .insert_resource(CrewRecords::default())
+            .add_systems(
+                Update,
+                assign_duty_roster.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Last, save_crew_skills.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                spawn_crew_members.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                train_crew_skills.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, update_manning.run_if(in_state(AppStates::Running)));
     }
 }
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use bevy::log::warn;
+use bevy::prelude::Resource;
+
+use crate::crew_management::utils::{load_crew_records, save_crew_records};
+use crate::crew_management::{CrewRole, CrewSkills};
+
+/// Keeps the skills of each ship's crew from one voyage to the next.
+///
+/// When a `directory` is set, the skills of the crew on each ship's roster are written to disk
+/// as the game exits, and read back when the ship's crew is next spawned, so veterans keep
+/// the experience they earned. Without a directory, every game starts with a freshly rated
+/// crew.
+///
+/// # Fields
+/// - `directory`: The directory the crew records are kept in, or `None` to keep none.
+///
+/// # Methods
+/// - `with_directory(directory)`: Creates records that are kept in the given directory.
+/// - `load(ship)`: Returns the role and skills of the crew saved for a ship.
+/// - `save(ship, crew)`: Saves the role and skills of a ship's crew.
+#[derive(Debug, Default, Resource)]
+pub struct CrewRecords {
+    pub directory: Option<PathBuf>,
+}
+
+impl CrewRecords {
+    /// Creates `CrewRecords` that are kept in the given directory.
+    pub fn with_directory(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: Some(directory.into()),
+        }
+    }
+
+    /// Returns the role and skills of each crew member saved for a ship, or none if the ship
+    /// has no readable record.
+    pub fn load(&self, ship: &str) -> Vec<(CrewRole, CrewSkills)> {
+        self.directory
+            .as_deref()
+            .and_then(|directory| load_crew_records(directory, ship))
+            .unwrap_or_default()
+    }
+
+    /// Saves the role and skills of each crew member on a ship's roster.
+    pub fn save(&self, ship: &str, crew: &[(CrewRole, CrewSkills)]) {
+        if let Some(directory) = &self.directory {
+            if let Err(error) = save_crew_records(directory, ship, crew) {
+                warn!("Failed to write crew records to {:?}: {}", directory, error);
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub use crew_records::*;
+
+mod crew_records;
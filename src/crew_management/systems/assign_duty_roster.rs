@@ -0,0 +1,90 @@
+use bevy::prelude::{Entity, Parent, Query, Res, With};
+
+use crate::crew_management::utils::{apply_station_orders, draw_up_duty_roster};
+use crate::crew_management::{CrewMember, CrewRole, ShipCrew, Station};
+use crate::crew_orders::{CrewOrder, OrderTarget};
+use crate::crew_watches::{Watch, WatchSchedule};
+use crate::utils::find_top_level_entity;
+
+/// Keeps the duty roster of each ship up to date.
+///
+/// Stations whose crew member is no longer aboard are vacated. Crew members the player has
+/// ordered to a station of their ship are then given it, relieving whoever worked it. The
+/// stations of each ship are then handed to crew members of the matching role from the watch
+/// on duty who are not yet assigned to a station, so the watch coming on deck takes over the
+/// stations at each change of watch. Crew members ordered to a station count as on duty.
+///
+/// # Parameters
+/// - `schedule`: Resource describing which watch is on duty.
+/// - `ships`: Query to retrieve the crew of ships.
+/// - `crew`: Query to retrieve the roles and watches of crew members.
+/// - `orders`: Query to retrieve the orders the player has given to crew members.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each station.
+/// - `stations`: Query to fetch and modify the `Station` components.
+pub fn assign_duty_roster(
+    schedule: Res<WatchSchedule>,
+    ships: Query<(Entity, &ShipCrew)>,
+    crew: Query<(&CrewRole, Option<&Watch>), With<CrewMember>>,
+    orders: Query<&CrewOrder>,
+    parent_query: Query<&Parent>,
+    mut stations: Query<(Entity, &mut Station)>,
+) {
+    for (_, mut station) in stations.iter_mut() {
+        if station
+            .crew
+            .is_some_and(|crew_member| !crew.contains(crew_member))
+        {
+            station.crew = None;
+            station.manned = false;
+        }
+    }
+
+    for (ship, ship_crew) in ships.iter() {
+        let mut ship_stations: Vec<(Entity, CrewRole, Option<Entity>)> = stations
+            .iter()
+            .filter(|(station, _)| find_top_level_entity(*station, &parent_query) == ship)
+            .map(|(entity, station)| (entity, station.role, station.crew))
+            .collect();
+
+        let station_orders: Vec<(Entity, Entity)> = ship_crew
+            .members
+            .iter()
+            .filter_map(|member| match orders.get(*member) {
+                Ok(CrewOrder {
+                    target: OrderTarget::Station(station),
+                }) => Some((*member, *station)),
+                _ => None,
+            })
+            .collect();
+
+        for (station, crew_member) in apply_station_orders(&ship_stations, &station_orders) {
+            if let Ok((_, mut station)) = stations.get_mut(station) {
+                station.crew = crew_member;
+                station.manned = false;
+            }
+            for (ship_station, _, crew) in ship_stations.iter_mut() {
+                if *ship_station == station {
+                    *crew = crew_member;
+                }
+            }
+        }
+
+        let ship_crew_roles: Vec<(Entity, CrewRole, bool)> = ship_crew
+            .members
+            .iter()
+            .filter_map(|member| {
+                let (role, watch) = crew.get(*member).ok()?;
+                let on_duty = schedule.is_on_duty(watch)
+                    || station_orders.iter().any(|(ordered, _)| ordered == member);
+                Some((*member, *role, on_duty))
+            })
+            .collect();
+
+        for (station, crew_member) in draw_up_duty_roster(&ship_stations, &ship_crew_roles) {
+            if let Ok((_, mut station)) = stations.get_mut(station) {
+                station.crew = Some(crew_member);
+                station.manned = false;
+            }
+        }
+    }
+}
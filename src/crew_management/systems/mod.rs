@@ -1,3 +1,10 @@
-pub use spawn_crew_members::*;
+pub use {
+    assign_duty_roster::*, save_crew_skills::*, spawn_crew_members::*, train_crew_skills::*,
+    update_manning::*,
+};
 
+mod assign_duty_roster;
+mod save_crew_skills;
 mod spawn_crew_members;
+mod train_crew_skills;
+mod update_manning;
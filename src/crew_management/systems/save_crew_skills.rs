@@ -0,0 +1,35 @@
+use bevy::app::AppExit;
+use bevy::core::Name;
+use bevy::prelude::{EventReader, Query, Res};
+
+use crate::crew_management::{CrewRecords, CrewRole, CrewSkills, ShipCrew};
+
+/// Saves the skills of the crew on each ship's roster as the game exits.
+///
+/// The role and skills of every crew member still on a ship's roster are written to the
+/// `CrewRecords` under the ship's name, so the crew spawned aboard the ship on the next voyage
+/// keep their experience. Crew members who died are no longer on the roster and are not saved.
+///
+/// # Parameters
+/// - `exits`: Event reader for the `AppExit` event.
+/// - `records`: Resource keeping the crew records.
+/// - `ships`: Query to fetch the name and roster of each ship.
+/// - `crew`: Query to fetch the role and skills of crew members.
+pub fn save_crew_skills(
+    mut exits: EventReader<AppExit>,
+    records: Res<CrewRecords>,
+    ships: Query<(&Name, &ShipCrew)>,
+    crew: Query<(&CrewRole, &CrewSkills)>,
+) {
+    if exits.read().last().is_none() {
+        return;
+    }
+
+    for (name, ship_crew) in ships.iter() {
+        let skills: Vec<(CrewRole, CrewSkills)> = crew
+            .iter_many(&ship_crew.members)
+            .map(|(role, skills)| (*role, skills.clone()))
+            .collect();
+        records.save(name.as_str(), &skills);
+    }
+}
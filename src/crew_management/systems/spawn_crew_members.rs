@@ -2,7 +2,7 @@ use bevy::asset::Assets;
 use bevy::core::Name;
 use bevy::math::Vec3;
 use bevy::pbr::{PbrBundle, StandardMaterial};
-use bevy::prelude::{default, Capsule3d, Color, Commands, Mesh, ResMut, Transform};
+use bevy::prelude::{default, Added, Capsule3d, Commands, Mesh, Query, Res, ResMut, Transform};
 use bevy_tnua::controller::TnuaControllerBundle;
 use bevy_tnua_xpbd3d::TnuaXpbd3dSensorShape;
 use bevy_xpbd_3d::components::{LockedAxes, RigidBody};
@@ -11,85 +11,186 @@ use big_brain::actions::Steps;
 use big_brain::pickers::FirstToScore;
 use big_brain::prelude::Thinker;
 
-use crate::ai_eating_behavior::{Eat, Hunger, HungerScorer};
-use crate::ai_navigation::{NavigationPath, SeekFoodBehavior, SeekSleepAreaBehavior};
-use crate::ai_sleeping_behavior::{Fatigue, FatigueScorer, Sleep};
-use crate::crew_management::CrewMember;
+use crate::ai_gunnery_behavior::{ReloadCannon, ReloadScorer};
+use crate::ai_healing_behavior::{InjuryScorer, Recover};
+use crate::ai_morale_behavior::{MoraleScorer, Shirk};
+use crate::ai_navigation::{
+    NavigationPath, SeekBilgePumpBehavior, SeekCannonBehavior, SeekDamagedBehavior,
+    SeekFoodBehavior, SeekOrderBehavior, SeekSickbayBehavior, SeekSleepAreaBehavior,
+    SeekStationBehavior,
+};
+use crate::ai_need_behavior::{Eat, Fatigue, FatigueScorer, Hunger, HungerScorer, Need, Sleep};
+use crate::ai_order_behavior::{FollowOrder, FollowOrderScorer};
+use crate::ai_production_behavior::{Produce, ProductionScorer};
+use crate::ai_pumping_behavior::{FloodingScorer, PumpBilge};
+use crate::ai_repair_behavior::{Repair, RepairScorer};
+use crate::ai_station_behavior::{ManStation, StationScorer};
+use crate::crew_health::{Falling, Health};
+use crate::crew_management::{
+    sign_on_crew_skills, CrewMember, CrewRecords, CrewRole, ShipCrew, WATCH_BILL,
+};
+use crate::crew_morale::Morale;
+use crate::crew_orders::CREW_COLOR;
+use crate::crew_watches::assign_watches;
 
-/// Spawns a set of crew members in the game world.
+/// Spawns the crew of each ship that has just been given a `ShipCrew`.
 ///
-/// Each crew member is configured with basic AI to handle eating and sleeping routines. The AI is managed
-/// using the `big_brain` crate, which allows for defining scorers and actions. The crew also utilize
-/// `bevy_xpbd_3d` for physics and `bevy_tnua` for movement and control.
+/// The ship's complement of crew members is spawned in a row on its deck, and the spawned
+/// members are recorded in the `ShipCrew`. Each crew member is rated for a role down the
+/// `WATCH_BILL`, which decides the stations the duty roster assigns them to, and signs on
+/// with experience in the skill that role calls for, or with the skills of a veteran of the
+/// same role saved in the `CrewRecords` from the ship's last voyage. The hands of each role are
+/// split between the starboard and larboard watches as they sign on.
+///
+/// Each crew member is configured with basic AI to follow the player's orders above all else, to
+/// seek treatment when injured, to man the bilge pumps when the ship floods, to shirk when
+/// disgruntled or in mutiny, to repair damage, to handle eating and sleeping routines, to
+/// reload the guns, to work the galley when there is cooking to do and otherwise to work their
+/// station. The AI is managed using the `big_brain` crate, which allows for defining
+/// scorers and actions. The crew also utilize `bevy_xpbd_3d` for physics and `bevy_tnua` for
+/// movement and control.
 pub fn spawn_crew_members(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    records: Res<CrewRecords>,
+    mut ships: Query<(&mut ShipCrew, &Transform, Option<&Name>), Added<ShipCrew>>,
 ) {
-    // Define the starting point for the NPCs.
+    // Define the starting point for the NPCs, relative to the ship.
     let start_position = Vec3::new(0.0, 8.0, -4.0);
     let spacing = 1.0; // Spacing between each NPC.
 
-    let num_npcs = 8;
-    for i in 0..num_npcs {
-        let move_and_eat = Steps::build()
-            .label("MoveAndEat")
-            .step(SeekFoodBehavior {
-                speed: 1.5,
-                _marker: std::marker::PhantomData,
-            })
-            .step(Eat {
-                until: 10.0,
-                per_second: 10.0,
-            });
+    for (mut ship_crew, ship_transform, name) in ships.iter_mut() {
+        let roles: Vec<CrewRole> = (0..ship_crew.complement as usize)
+            .map(|i| WATCH_BILL[i % WATCH_BILL.len()])
+            .collect();
+        let watches = assign_watches(&roles);
+        let veterans = name.map_or_else(Vec::new, |name| records.load(name.as_str()));
+        let skills = sign_on_crew_skills(&roles, veterans);
+
+        for i in 0..ship_crew.complement {
+            let move_and_eat = Steps::build()
+                .label("MoveAndEat")
+                .step(SeekFoodBehavior {
+                    speed: 1.5,
+                    _marker: std::marker::PhantomData,
+                })
+                .step(Eat::new(10.0, 10.0));
+
+            let move_and_sleep = Steps::build()
+                .label("MoveAndSleep")
+                .step(SeekSleepAreaBehavior {
+                    speed: 1.5,
+                    _marker: std::marker::PhantomData,
+                })
+                .step(Sleep::new(10.0, 15.0));
+
+            let move_and_pump = Steps::build()
+                .label("MoveAndPump")
+                .step(SeekBilgePumpBehavior {
+                    speed: 1.5,
+                    _marker: std::marker::PhantomData,
+                })
+                .step(PumpBilge { until: 0.01 });
+
+            let move_and_repair = Steps::build()
+                .label("MoveAndRepair")
+                .step(SeekDamagedBehavior {
+                    speed: 1.5,
+                    _marker: std::marker::PhantomData,
+                })
+                .step(Repair {
+                    per_second: 0.05,
+                    target: None,
+                });
+
+            let move_and_reload = Steps::build()
+                .label("MoveAndReload")
+                .step(SeekCannonBehavior {
+                    speed: 1.5,
+                    _marker: std::marker::PhantomData,
+                })
+                .step(ReloadCannon);
+
+            let move_and_recover = Steps::build()
+                .label("MoveAndRecover")
+                .step(SeekSickbayBehavior {
+                    speed: 1.0,
+                    _marker: std::marker::PhantomData,
+                })
+                .step(Recover { until: 1.0 });
+
+            let follow_order = Steps::build()
+                .label("FollowOrder")
+                .step(SeekOrderBehavior { speed: 2.0 })
+                .step(FollowOrder::default());
+
+            let move_and_produce = Steps::build()
+                .label("MoveAndProduce")
+                .step(SeekStationBehavior { speed: 1.5 })
+                .step(Produce);
 
-        let move_and_sleep = Steps::build()
-            .label("MoveAndSleep")
-            .step(SeekSleepAreaBehavior {
-                speed: 1.5,
-                _marker: std::marker::PhantomData,
-            })
-            .step(Sleep {
-                until: 10.0,
-                per_second: 15.0,
-            });
+            let move_and_man_station = Steps::build()
+                .label("MoveAndManStation")
+                .step(SeekStationBehavior { speed: 1.5 })
+                .step(ManStation);
 
-        let position = start_position + Vec3::new(0.0, 0.0, spacing * i as f32);
+            let role = roles[i as usize];
+            let position = ship_transform
+                .transform_point(start_position + Vec3::new(0.0, 0.0, spacing * i as f32));
 
-        commands.spawn((
-            Name::new("Crew Member"),
-            PbrBundle {
-                mesh: meshes.add(Capsule3d {
-                    radius: 0.4,
-                    ..default()
-                }),
-                material: materials.add(Color::YELLOW),
-                transform: Transform::from_translation(position),
-                ..default()
-            },
-            RigidBody::Dynamic,
-            Collider::capsule(0.5, 0.5),
-            TnuaControllerBundle::default(),
-            TnuaXpbd3dSensorShape(Collider::cylinder(0.0, 0.49)),
-            LockedAxes::ROTATION_LOCKED,
-            CrewMember,
-            Hunger {
-                is_eating: false,
-                per_second: 4.0,
-                level: 0.0,
-            },
-            Fatigue {
-                is_sleeping: false,
-                per_second: 4.0,
-                level: 0.0,
-            },
-            NavigationPath::default(),
-            Thinker::build()
-                .label("Crew Member Thinker")
-                // Selects the action with the highest score that is above the threshold
-                .picker(FirstToScore::new(0.6))
-                .when(FatigueScorer, move_and_sleep)
-                .when(HungerScorer, move_and_eat),
-        ));
+            let crew_member = commands
+                .spawn((
+                    Name::new("Crew Member"),
+                    PbrBundle {
+                        mesh: meshes.add(Capsule3d {
+                            radius: 0.4,
+                            ..default()
+                        }),
+                        material: materials.add(CREW_COLOR),
+                        transform: Transform::from_translation(position),
+                        ..default()
+                    },
+                    RigidBody::Dynamic,
+                    Collider::capsule(0.5, 0.5),
+                    TnuaControllerBundle::default(),
+                    TnuaXpbd3dSensorShape(Collider::cylinder(0.0, 0.49)),
+                    LockedAxes::ROTATION_LOCKED,
+                    (
+                        CrewMember,
+                        role,
+                        skills[i as usize].clone(),
+                        Health::default(),
+                        Falling::default(),
+                        Morale::default(),
+                        watches[i as usize],
+                    ),
+                    Need::<Hunger>::new(4.0),
+                    Need::<Fatigue>::new(4.0),
+                    NavigationPath::default(),
+                    Thinker::build()
+                        .label("Crew Member Thinker")
+                        // Selects the action with the highest score that is above the threshold
+                        .picker(FirstToScore::new(0.6))
+                        .when(FollowOrderScorer, follow_order)
+                        .when(InjuryScorer, move_and_recover)
+                        .when(FloodingScorer::new(0.6, 0.1), move_and_pump)
+                        .when(
+                            MoraleScorer,
+                            Shirk {
+                                until: 0.3,
+                                per_second: 0.002,
+                            },
+                        )
+                        .when(RepairScorer, move_and_repair)
+                        .when(FatigueScorer::new(0.6, 0.1), move_and_sleep)
+                        .when(HungerScorer::new(0.6, 0.1), move_and_eat)
+                        .when(ReloadScorer, move_and_reload)
+                        .when(ProductionScorer, move_and_produce)
+                        .when(StationScorer, move_and_man_station),
+                ))
+                .id();
+            ship_crew.members.push(crew_member);
+        }
     }
 }
@@ -0,0 +1,34 @@
+use bevy::prelude::{Query, Res, Time};
+
+use crate::crew_management::{CrewRole, CrewSkills, Station};
+
+/// Gives the crew experience for the time they spend working their stations.
+///
+/// Each crew member manning a station gains experience in the skill the station's role calls
+/// for, one second of experience for every second at work. Carpenters are the exception, as
+/// they only learn from the repairs they actually make, which `repair_action` accounts for,
+/// rather than from standing at their bench.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `stations`: Query to retrieve the stations.
+/// - `crew`: Query to fetch and modify the `CrewSkills` of crew members.
+pub fn train_crew_skills(
+    time: Res<Time>,
+    stations: Query<&Station>,
+    mut crew: Query<&mut CrewSkills>,
+) {
+    for station in stations
+        .iter()
+        .filter(|station| station.manned && station.role != CrewRole::Carpenter)
+    {
+        let Some(mut skills) = station
+            .crew
+            .and_then(|crew_member| crew.get_mut(crew_member).ok())
+        else {
+            continue;
+        };
+
+        skills.gain_experience(station.role.skill(), time.delta_seconds());
+    }
+}
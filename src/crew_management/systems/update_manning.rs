@@ -0,0 +1,68 @@
+use bevy::prelude::{Entity, Parent, Query};
+
+use crate::crew_health::Health;
+use crate::crew_management::utils::calculate_manning_level;
+use crate::crew_management::{CrewRole, CrewSkills, Manning, Station};
+use crate::crew_morale::Morale;
+use crate::utils::find_top_level_entity;
+
+/// Updates how well the stations that work the helm and sails of each ship are manned.
+///
+/// The fraction of a role's stations that are manned is scaled by how skilled, healthy and
+/// content the crew manning them are on average, so green, injured or disgruntled hands work
+/// the ship less well than seasoned, healthy and willing ones.
+///
+/// # Parameters
+/// - `stations`: Query to retrieve the stations.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each station.
+/// - `crew`: Query to retrieve the skills, health and morale of crew members.
+/// - `ships`: Query to fetch and modify the `Manning` components of ships.
+pub fn update_manning(
+    stations: Query<(Entity, &Station)>,
+    parent_query: Query<&Parent>,
+    crew: Query<(Option<&CrewSkills>, Option<&Health>, Option<&Morale>)>,
+    mut ships: Query<(Entity, &mut Manning)>,
+) {
+    let station_ships: Vec<(Entity, &Station)> = stations
+        .iter()
+        .map(|(entity, station)| {
+            let ship = find_top_level_entity(entity, &parent_query);
+            (ship, station)
+        })
+        .collect();
+
+    for (ship, mut manning) in ships.iter_mut() {
+        let ship_stations = station_ships
+            .iter()
+            .filter(|(station_ship, _)| *station_ship == ship)
+            .map(|(_, station)| *station);
+
+        let role_level = |role: CrewRole| {
+            let effectiveness: Vec<f32> = ship_stations
+                .clone()
+                .filter(|station| station.role == role && station.manned)
+                .map(|station| {
+                    station
+                        .crew
+                        .and_then(|crew_member| crew.get(crew_member).ok())
+                        .map_or(1.0, |(skills, health, morale)| {
+                            skills.map_or(1.0, |skills| skills.effectiveness(role.skill()))
+                                * health.map_or(1.0, |health| health.work_rate())
+                                * morale.map_or(1.0, |morale| morale.work_rate())
+                        })
+                })
+                .collect();
+
+            let skill_level = if effectiveness.is_empty() {
+                1.0
+            } else {
+                effectiveness.iter().sum::<f32>() / effectiveness.len() as f32
+            };
+
+            (calculate_manning_level(ship_stations.clone(), role) * skill_level).clamp(0.0, 1.0)
+        };
+
+        manning.helm = role_level(CrewRole::Helmsman);
+        manning.sails = role_level(CrewRole::SailHandler);
+    }
+}
@@ -0,0 +1,80 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use bevy::asset::ron;
+
+use crate::crew_management::{CrewRole, CrewSkills};
+
+/// Loads the skills a ship's crew were saved with from disk.
+///
+/// # Arguments
+///
+/// * `directory` - The directory holding the crew records.
+/// * `ship` - The name of the ship the crew sailed aboard.
+///
+/// # Returns
+///
+/// `Some(Vec<(CrewRole, CrewSkills)>)` with the role and skills of each crew member if a
+/// readable record exists, or `None` otherwise.
+pub fn load_crew_records(directory: &Path, ship: &str) -> Option<Vec<(CrewRole, CrewSkills)>> {
+    let contents = fs::read_to_string(crew_record_path(directory, ship)).ok()?;
+    ron::de::from_str(&contents).ok()
+}
+
+/// Saves the skills of a ship's crew to disk, creating the record directory if needed.
+///
+/// # Arguments
+///
+/// * `directory` - The directory holding the crew records.
+/// * `ship` - The name of the ship the crew sail aboard.
+/// * `crew` - The role and skills of each crew member on the ship's roster.
+///
+/// # Returns
+///
+/// An `io::Result` indicating whether the skills were written.
+pub fn save_crew_records(
+    directory: &Path,
+    ship: &str,
+    crew: &[(CrewRole, CrewSkills)],
+) -> io::Result<()> {
+    let contents = ron::ser::to_string(crew).map_err(io::Error::other)?;
+
+    fs::create_dir_all(directory)?;
+    fs::write(crew_record_path(directory, ship), contents)
+}
+
+/// Hands out the skills of a crew signing on, taking on the veterans of a previous voyage.
+///
+/// Each role is filled by the first remaining veteran of the same role, so hands keep their
+/// experience from voyage to voyage. Roles no veteran is left for are filled by a fresh hand
+/// rated for the role.
+///
+/// # Arguments
+///
+/// * `roles` - The role of each crew member signing on, in order.
+/// * `veterans` - The role and skills of each crew member saved from a previous voyage.
+///
+/// # Returns
+///
+/// The skills of each crew member signing on, in the order of `roles`.
+pub fn sign_on_crew_skills(
+    roles: &[CrewRole],
+    mut veterans: Vec<(CrewRole, CrewSkills)>,
+) -> Vec<CrewSkills> {
+    roles
+        .iter()
+        .map(|&role| {
+            veterans
+                .iter()
+                .position(|(veteran_role, _)| *veteran_role == role)
+                .map(|index| veterans.remove(index).1)
+                .unwrap_or_else(|| CrewSkills::rated(role.skill()))
+        })
+        .collect()
+}
+
+/// Returns the path of the record file for the given ship.
+fn crew_record_path(directory: &Path, ship: &str) -> PathBuf {
+    directory.join(format!("{}.ron", ship.to_lowercase().replace(' ', "_")))
+}
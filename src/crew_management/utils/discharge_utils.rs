@@ -0,0 +1,56 @@
+use bevy::prelude::{Entity, Query};
+
+use crate::crew_management::{ShipCrew, Station};
+use crate::gunnery::Cannon;
+use crate::ship_items::BilgePump;
+
+/// Strikes a crew member who is leaving the ship for good from its books.
+///
+/// The crew member is removed from their ship's crew and from the duty roster, and leaves any
+/// bilge pump or cannon they were operating, so nothing is left waiting on them.
+///
+/// # Arguments
+/// - `crew_member`: The crew member leaving the ship.
+/// - `ships`: Query to retrieve and modify the crew of ships.
+/// - `stations`: Query to retrieve and modify the stations.
+/// - `pumps`: Query to retrieve and modify the bilge pumps.
+/// - `cannons`: Query to retrieve and modify the cannons.
+///
+/// # Returns
+/// The ship the crew member served aboard, if any.
+pub fn discharge_crew_member(
+    crew_member: Entity,
+    ships: &mut Query<(Entity, &mut ShipCrew)>,
+    stations: &mut Query<&mut Station>,
+    pumps: &mut Query<&mut BilgePump>,
+    cannons: &mut Query<&mut Cannon>,
+) -> Option<Entity> {
+    let mut ship = None;
+    for (ship_entity, mut ship_crew) in ships.iter_mut() {
+        if ship_crew.members.contains(&crew_member) {
+            ship_crew.members.retain(|member| *member != crew_member);
+            ship = Some(ship_entity);
+        }
+    }
+
+    for mut station in stations.iter_mut() {
+        if station.crew == Some(crew_member) {
+            station.crew = None;
+            station.manned = false;
+        }
+    }
+
+    for mut pump in pumps.iter_mut() {
+        if pump.operator == Some(crew_member) {
+            pump.operator = None;
+        }
+    }
+
+    for mut cannon in cannons.iter_mut() {
+        if cannon.operator == Some(crew_member) {
+            cannon.operator = None;
+        }
+    }
+
+    ship
+}
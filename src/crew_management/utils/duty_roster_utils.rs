@@ -0,0 +1,143 @@
+use bevy::prelude::Entity;
+
+use crate::crew_management::{CrewRole, Station};
+
+/// Draws up the duty roster for the stations of a ship.
+///
+/// The stations are kept by the hands of the watch on duty. A station that is vacant, or whose
+/// crew member's watch has gone below, is handed to the first crew member of its role on duty
+/// who is not already assigned to a station, so the stations change hands at each change of
+/// watch. A station no one on duty can take is kept by whoever has it, and a vacant one is
+/// given to the first free crew member of its role from the watch below. Stations are filled
+/// in the order they are given, so when a role is short-handed the first stations of that
+/// role are manned and the rest are left vacant.
+///
+/// # Arguments
+/// - `stations`: Each station of the ship with its role and the crew member assigned to it.
+/// - `crew`: Each crew member of the ship with their role and whether they are on duty.
+///
+/// # Returns
+/// The new assignments, as pairs of a station and the crew member assigned to it.
+pub fn draw_up_duty_roster(
+    stations: &[(Entity, CrewRole, Option<Entity>)],
+    crew: &[(Entity, CrewRole, bool)],
+) -> Vec<(Entity, Entity)> {
+    let is_on_duty = |crew_member: &Entity| {
+        crew.iter()
+            .any(|(other, _, on_duty)| other == crew_member && *on_duty)
+    };
+    let mut assigned: Vec<Entity> = stations
+        .iter()
+        .filter_map(|(_, _, crew)| *crew)
+        .filter(is_on_duty)
+        .collect();
+    let mut assignments = Vec::new();
+    let mut vacant = Vec::new();
+
+    for (station, role, station_crew) in stations
+        .iter()
+        .filter(|(_, _, crew)| !crew.as_ref().is_some_and(is_on_duty))
+    {
+        let relief = crew.iter().find(|(crew_member, crew_role, on_duty)| {
+            crew_role == role && *on_duty && !assigned.contains(crew_member)
+        });
+
+        match (relief, station_crew) {
+            (Some((crew_member, _, _)), _) => {
+                assigned.push(*crew_member);
+                assignments.push((*station, *crew_member));
+            }
+            (None, Some(crew_member)) => assigned.push(*crew_member),
+            (None, None) => vacant.push((*station, *role)),
+        }
+    }
+
+    for (station, role) in vacant {
+        let Some((crew_member, _, _)) = crew.iter().find(|(crew_member, crew_role, _)| {
+            *crew_role == role && !assigned.contains(crew_member)
+        }) else {
+            continue;
+        };
+
+        assigned.push(*crew_member);
+        assignments.push((station, *crew_member));
+    }
+
+    assignments
+}
+
+/// Hands the stations the player has ordered crew members to over to them.
+///
+/// A crew member ordered to a station takes it over, whatever its role, leaving the station
+/// they worked before vacant and relieving whoever worked the ordered station. The relieved
+/// crew member is free to be drawn up for a vacant station of their role again. Orders for
+/// stations that are not in the list are ignored.
+///
+/// # Arguments
+/// - `stations`: Each station of the ship with its role and the crew member assigned to it.
+/// - `orders`: Each crew member ordered to a station, with the station.
+///
+/// # Returns
+/// The changed assignments, as pairs of a station and the crew member now assigned to it, in
+/// the order they are to be applied.
+pub fn apply_station_orders(
+    stations: &[(Entity, CrewRole, Option<Entity>)],
+    orders: &[(Entity, Entity)],
+) -> Vec<(Entity, Option<Entity>)> {
+    let mut roster: Vec<(Entity, Option<Entity>)> = stations
+        .iter()
+        .map(|(station, _, crew)| (*station, *crew))
+        .collect();
+    let mut changes = Vec::new();
+
+    for (crew_member, ordered_station) in orders {
+        if !roster
+            .iter()
+            .any(|(station, crew)| station == ordered_station && *crew != Some(*crew_member))
+        {
+            continue;
+        }
+
+        for (station, crew) in roster.iter_mut() {
+            let new_crew = if station == ordered_station {
+                Some(*crew_member)
+            } else if *crew == Some(*crew_member) {
+                None
+            } else {
+                continue;
+            };
+
+            *crew = new_crew;
+            changes.push((*station, new_crew));
+        }
+    }
+
+    changes
+}
+
+/// Calculates how well the stations of a role are manned.
+///
+/// # Arguments
+/// - `stations`: The stations aboard a ship.
+/// - `role`: The role to calculate the manning of.
+///
+/// # Returns
+/// The fraction of the stations of the role that are manned, from `0.0` to `1.0`. A ship with
+/// no stations of the role is treated as fully manned.
+pub fn calculate_manning_level<'a>(
+    stations: impl IntoIterator<Item = &'a Station>,
+    role: CrewRole,
+) -> f32 {
+    let (manned, total) = stations
+        .into_iter()
+        .filter(|station| station.role == role)
+        .fold((0, 0), |(manned, total), station| {
+            (manned + usize::from(station.manned), total + 1)
+        });
+
+    if total == 0 {
+        1.0
+    } else {
+        manned as f32 / total as f32
+    }
+}
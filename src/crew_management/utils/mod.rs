@@ -0,0 +1,10 @@
+pub use {
+    crew_record_utils::*, discharge_utils::*, duty_roster_utils::*, ship_crew_utils::*,
+    skill_utils::*,
+};
+
+mod crew_record_utils;
+mod discharge_utils;
+mod duty_roster_utils;
+mod ship_crew_utils;
+mod skill_utils;
@@ -0,0 +1,30 @@
+use bevy::prelude::{Entity, Parent, Query};
+
+use crate::crew_management::ShipCrew;
+use crate::utils::find_top_level_entity;
+
+/// Returns whether something is aboard a crew member's own ship, or aboard no ship at all.
+///
+/// Items such as cannons, bilge pumps and hammocks are part of the hierarchy of the ship
+/// carrying them, so the top-level entity of the item is the ship. The crew of a ship only
+/// work and rest aboard it, and leave the items of other ships alone.
+///
+/// # Arguments
+/// - `crew_member`: The crew member looking to use the item.
+/// - `item`: The item the crew member is looking to use.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying the item.
+/// - `ships`: Query to retrieve the crew of ships.
+///
+/// # Returns
+/// `false` if the item is aboard a ship the crew member does not serve aboard.
+pub fn is_aboard_own_ship(
+    crew_member: Entity,
+    item: Entity,
+    parent_query: &Query<&Parent>,
+    ships: &Query<&ShipCrew>,
+) -> bool {
+    ships
+        .get(find_top_level_entity(item, parent_query))
+        .ok()
+        .is_none_or(|ship_crew| ship_crew.members.contains(&crew_member))
+}
@@ -0,0 +1,16 @@
+use crate::crew_management::{NOVICE_EFFECTIVENESS, SKILL_EXPERIENCE_SCALE};
+
+/// Calculates how effective a crew member's work in a skill is from their experience.
+///
+/// Effectiveness rises quickly with the first hours of experience and levels off towards
+/// `1.0`, so a novice learns fast and a veteran still improves, but slowly.
+///
+/// # Arguments
+/// - `experience`: The seconds of experience in the skill.
+///
+/// # Returns
+/// The effectiveness of the work, from `NOVICE_EFFECTIVENESS` with no experience to `1.0`.
+pub fn calculate_skill_effectiveness(experience: f32) -> f32 {
+    let learned = 1.0 - (-experience.max(0.0) / SKILL_EXPERIENCE_SCALE).exp();
+    NOVICE_EFFECTIVENESS + (1.0 - NOVICE_EFFECTIVENESS) * learned
+}
@@ -0,0 +1,4 @@
+pub use {morale::*, ship_morale::*};
+
+mod morale;
+mod ship_morale;
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::crew_morale::utils::calculate_morale_work_rate;
+use crate::crew_morale::INITIAL_MORALE;
+
+/// Tracks how content a crew member is with life aboard.
+///
+/// The `Morale` component is added to crew members. Morale wears down over a long voyage and
+/// falls faster while the crew member goes hungry or tired, loses shipmates or comes under
+/// fire. It recovers with meals and rest, and is lifted by a tot of rum, pay and victory.
+/// Crew with low morale work less well, and crew who stay discontented for too long desert.
+///
+/// # Fields
+/// - `level`: The crew member's morale, from `0.0` (mutinous) to `1.0` (in high spirits).
+/// - `discontent`: The seconds the crew member has spent discontented without a break.
+///
+/// # Methods
+/// - `raise(&mut self, amount: f32)`: Raises morale, up to `1.0`.
+/// - `lower(&mut self, amount: f32)`: Lowers morale, down to `0.0`.
+/// - `work_rate(&self) -> f32`: Returns how well the crew member works given their morale.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Morale {
+    pub level: f32,
+    pub discontent: f32,
+}
+
+impl Morale {
+    /// Raises morale by the given amount, up to `1.0`.
+    pub fn raise(&mut self, amount: f32) {
+        self.level = (self.level + amount.max(0.0)).min(1.0);
+    }
+
+    /// Lowers morale by the given amount, down to `0.0`.
+    pub fn lower(&mut self, amount: f32) {
+        self.level = (self.level - amount.max(0.0)).max(0.0);
+    }
+
+    /// Returns how well the crew member works given their morale, from `LOW_MORALE_WORK_RATE`
+    /// to `1.0`.
+    pub fn work_rate(&self) -> f32 {
+        calculate_morale_work_rate(self.level)
+    }
+}
+
+impl Default for Morale {
+    fn default() -> Self {
+        Self {
+            level: INITIAL_MORALE,
+            discontent: 0.0,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Tracks the morale of a ship's crew as a whole.
+///
+/// The `ShipMorale` component is added to ships alongside their `ShipCrew`, and updated from
+/// the morale of the crew members aboard.
+///
+/// # Fields
+/// - `average`: The average morale of the crew, from `0.0` to `1.0`.
+/// - `mutinous`: Whether too many of the crew are discontented and the ship is in mutiny.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct ShipMorale {
+    pub average: f32,
+    pub mutinous: bool,
+}
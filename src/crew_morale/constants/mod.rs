@@ -0,0 +1,3 @@
+pub use morale_settings::*;
+
+mod morale_settings;
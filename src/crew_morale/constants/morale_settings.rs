@@ -0,0 +1,61 @@
+/// The morale crew members sign on with.
+pub const INITIAL_MORALE: f32 = 0.8;
+
+/// The morale per second the crew lose to the wear of a long voyage.
+pub const VOYAGE_MORALE_DECAY: f32 = 0.0005;
+
+/// The hunger or fatigue level, out of `100.0`, above which a need counts as unmet.
+pub const UNMET_NEED_LEVEL: f32 = 80.0;
+
+/// The morale per second a crew member loses for each unmet need.
+pub const UNMET_NEED_MORALE_DECAY: f32 = 0.002;
+
+/// The morale per second a crew member gains while eating.
+pub const MEAL_MORALE_RATE: f32 = 0.004;
+
+/// The morale per second a crew member loses while starving, on top of the morale lost to
+/// their unmet hunger.
+pub const STARVATION_MORALE_LOSS: f32 = 0.004;
+
+/// The morale a crew member gains for each portion of a hot meal from the galley they eat, on
+/// top of what eating raw rations gives.
+pub const HOT_MEAL_MORALE_GAIN: f32 = 0.02;
+
+/// The morale per second a crew member gains while sleeping.
+pub const REST_MORALE_RATE: f32 = 0.002;
+
+/// The morale every crew member loses when a shipmate dies.
+pub const DEATH_MORALE_LOSS: f32 = 0.1;
+
+/// The morale a crew member loses when they are injured, scaled by the severity of the injury.
+pub const INJURY_MORALE_LOSS: f32 = 0.2;
+
+/// The morale every crew member loses when their ship is hit by shot.
+pub const HIT_MORALE_LOSS: f32 = 0.01;
+
+/// The morale every crew member gains when their ship boards another.
+pub const VICTORY_MORALE_GAIN: f32 = 0.3;
+
+/// The morale every crew member loses when their ship is boarded.
+pub const DEFEAT_MORALE_LOSS: f32 = 0.3;
+
+/// The morale every crew member gains from a tot of rum.
+pub const RUM_MORALE_GAIN: f32 = 0.15;
+
+/// The morale every crew member gains from being paid.
+pub const PAY_MORALE_GAIN: f32 = 0.25;
+
+/// The morale at or above which a crew member works at full effectiveness.
+pub const CONTENT_MORALE: f32 = 0.5;
+
+/// How well a crew member with no morale at all works, as a fraction of full effectiveness.
+pub const LOW_MORALE_WORK_RATE: f32 = 0.5;
+
+/// The morale below which a crew member is discontented.
+pub const DISCONTENT_MORALE: f32 = 0.2;
+
+/// The seconds a crew member stays discontented before they desert.
+pub const DESERTION_TIME: f32 = 180.0;
+
+/// The fraction of a ship's crew who have to be discontented for the ship to mutiny.
+pub const MUTINY_CREW_FRACTION: f32 = 0.5;
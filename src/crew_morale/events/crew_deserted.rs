@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Event sent when a discontented crew member deserts their ship.
+///
+/// This event is sent by the `desert_ship` system once the crew member has been struck from
+/// their ship's crew, just before they are de-spawned.
+///
+/// # Fields
+/// - `crew_member`: The crew member who deserted. The entity no longer exists once the event is read.
+/// - `ship`: The ship the crew member deserted, if any.
+#[derive(Event, Debug, Clone)]
+pub struct CrewDeserted {
+    pub crew_member: Entity,
+    pub ship: Option<Entity>,
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+/// Event to pay the crew of a ship.
+///
+/// This event is processed by the `apply_morale_events` system, which lifts the morale of
+/// every crew member aboard.
+///
+/// # Fields
+/// - `ship`: The ship whose crew are paid.
+#[derive(Event, Debug, Clone)]
+pub struct CrewPaid {
+    pub ship: Entity,
+}
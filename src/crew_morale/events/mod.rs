@@ -0,0 +1,6 @@
+pub use {crew_deserted::*, crew_paid::*, mutiny::*, rum_issued::*};
+
+mod crew_deserted;
+mod crew_paid;
+mod mutiny;
+mod rum_issued;
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+/// Event sent when the crew of a ship rise in mutiny.
+///
+/// This event is sent by the `update_ship_morale` system when too many of a ship's crew have
+/// become discontented. While the mutiny lasts the ship's `ShipMorale` is marked as mutinous,
+/// and its crew refuse the player's orders and down tools until enough of them are won back.
+///
+/// # Fields
+/// - `ship`: The ship whose crew have mutinied.
+#[derive(Event, Debug, Clone)]
+pub struct Mutiny {
+    pub ship: Entity,
+}
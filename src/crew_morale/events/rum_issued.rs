@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+/// Event to issue a tot of rum to the crew of a ship.
+///
+/// This event is processed by the `apply_morale_events` system, which lifts the morale of
+/// every crew member aboard.
+///
+/// # Fields
+/// - `ship`: The ship whose crew are issued rum.
+#[derive(Event, Debug, Clone)]
+pub struct RumIssued {
+    pub ship: Entity,
+}
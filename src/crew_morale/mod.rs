@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+pub use events::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod events;
+mod systems;
+mod utils;
+
+/// Plugin for the morale of the crew.
+///
+/// The `CrewMoralePlugin` tracks how content each crew member is. Morale wears down over a
+/// long voyage, with unmet needs, lost shipmates and enemy fire, and is restored by meals,
+/// rest, rum, pay and victory. Crew with low morale work less well, discontented crew
+/// eventually desert, and a ship with too many discontented hands mutinies, its crew refusing
+/// orders and downing tools until the mutiny is over.
+///
+/// # Components
+/// - `Morale`: Tracks how content a crew member is with life aboard.
+/// - `ShipMorale`: Tracks the morale of a ship's crew as a whole.
+///
+/// # Events
+/// - `CrewDeserted`: Sent when a discontented crew member deserts their ship.
+/// - `CrewPaid`: Pays the crew of a ship.
+/// - `Mutiny`: Sent when the crew of a ship rise in mutiny.
+/// - `RumIssued`: Issues a tot of rum to the crew of a ship.
+///
+/// # Systems
+/// - `apply_morale_events`: Changes the morale of a ship's crew in response to what befalls the ship
+///   and its crew.
+/// - `desert_ship`: Has crew members who have stayed discontented for too long desert their ship.
+/// - `issue_rum_and_pay`: Lets the player issue rum to and pay the crew of their ship.
+/// - `update_morale`: Updates the morale of crew members from day to day life aboard.
+/// - `update_ship_morale`: Updates the morale of each ship's crew as a whole.
+///
+/// All systems are run during the `Update` stage and only when the application is in the `AppStates::Running` state.
+pub struct CrewMoralePlugin;
+
+impl Plugin for CrewMoralePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Morale>()
+            .register_type::<ShipMorale>()
+            .add_event::<CrewDeserted>()
+            .add_event::<CrewPaid>()
+            .add_event::<Mutiny>()
+            .add_event::<RumIssued>()
+            .add_systems(
+                Update,
+                apply_morale_events.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, desert_ship.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                issue_rum_and_pay.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, update_morale.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                update_ship_morale.run_if(in_state(AppStates::Running)),
+            );
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::{Entity, EventReader, Query};
+
+use crate::ai_captain_behavior::ShipBoarded;
+use crate::crew_health::{CrewDied, CrewInjured};
+use crate::crew_management::ShipCrew;
+use crate::crew_morale::{
+    CrewPaid, Morale, RumIssued, DEATH_MORALE_LOSS, DEFEAT_MORALE_LOSS, HIT_MORALE_LOSS,
+    INJURY_MORALE_LOSS, PAY_MORALE_GAIN, RUM_MORALE_GAIN, VICTORY_MORALE_GAIN,
+};
+use crate::gunnery::ProjectileHit;
+
+/// Changes the morale of a ship's crew in response to what befalls the ship.
+///
+/// - `CrewDied`: The dead crew member's shipmates lose heart.
+/// - `CrewInjured`: The injured crew member loses heart, the more so the worse the injury.
+/// - `ProjectileHit`: The crew of a ship that is hit lose heart.
+/// - `ShipBoarded`: The crew of the boarding ship are lifted by victory, and the crew of the
+///   boarded ship lose heart.
+/// - `RumIssued` and `CrewPaid`: The crew of the ship are lifted.
+///
+/// # Parameters
+/// - `crew_died_events`: Event reader for `CrewDied` events.
+/// - `crew_injured_events`: Event reader for `CrewInjured` events.
+/// - `projectile_hit_events`: Event reader for `ProjectileHit` events.
+/// - `ship_boarded_events`: Event reader for `ShipBoarded` events.
+/// - `rum_issued_events`: Event reader for `RumIssued` events.
+/// - `crew_paid_events`: Event reader for `CrewPaid` events.
+/// - `ships`: Query to retrieve the crew of ships.
+/// - `crew`: Query to retrieve and modify the morale of crew members.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_morale_events(
+    mut crew_died_events: EventReader<CrewDied>,
+    mut crew_injured_events: EventReader<CrewInjured>,
+    mut projectile_hit_events: EventReader<ProjectileHit>,
+    mut ship_boarded_events: EventReader<ShipBoarded>,
+    mut rum_issued_events: EventReader<RumIssued>,
+    mut crew_paid_events: EventReader<CrewPaid>,
+    ships: Query<&ShipCrew>,
+    mut crew: Query<&mut Morale>,
+) {
+    for event in crew_injured_events.read() {
+        if let Ok(mut morale) = crew.get_mut(event.crew_member) {
+            morale.lower(INJURY_MORALE_LOSS * event.severity);
+        }
+    }
+
+    let mut changes: Vec<(Entity, f32)> = Vec::new();
+
+    changes.extend(
+        crew_died_events
+            .read()
+            .filter_map(|event| event.ship)
+            .map(|ship| (ship, -DEATH_MORALE_LOSS)),
+    );
+    changes.extend(
+        projectile_hit_events
+            .read()
+            .map(|hit| (hit.target, -HIT_MORALE_LOSS)),
+    );
+    for event in ship_boarded_events.read() {
+        changes.push((event.boarder, VICTORY_MORALE_GAIN));
+        changes.push((event.target, -DEFEAT_MORALE_LOSS));
+    }
+    changes.extend(
+        rum_issued_events
+            .read()
+            .map(|event| (event.ship, RUM_MORALE_GAIN)),
+    );
+    changes.extend(
+        crew_paid_events
+            .read()
+            .map(|event| (event.ship, PAY_MORALE_GAIN)),
+    );
+
+    for (ship, change) in changes {
+        let Ok(ship_crew) = ships.get(ship) else {
+            continue;
+        };
+
+        for crew_member in ship_crew.members.iter() {
+            let Ok(mut morale) = crew.get_mut(*crew_member) else {
+                continue;
+            };
+
+            if change >= 0.0 {
+                morale.raise(change);
+            } else {
+                morale.lower(-change);
+            }
+        }
+    }
+}
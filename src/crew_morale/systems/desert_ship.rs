@@ -0,0 +1,46 @@
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Commands, Entity, EventWriter, Query};
+
+use crate::crew_management::{discharge_crew_member, ShipCrew, Station};
+use crate::crew_morale::{CrewDeserted, Morale, DESERTION_TIME};
+use crate::gunnery::Cannon;
+use crate::ship_items::BilgePump;
+
+/// Has crew members who have stayed discontented for too long desert their ship.
+///
+/// A deserter is struck from their ship's crew and from the duty roster, leaves any bilge pump
+/// or cannon they were operating, and is de-spawned. A `CrewDeserted` event is sent for them.
+///
+/// # Parameters
+/// - `commands`: Commands for de-spawning deserters.
+/// - `crew_deserted_events`: Event writer for `CrewDeserted` events.
+/// - `crew`: Query to retrieve the morale of crew members.
+/// - `ships`: Query to retrieve and modify the crew of ships.
+/// - `stations`: Query to retrieve and modify the stations.
+/// - `pumps`: Query to retrieve and modify the bilge pumps.
+/// - `cannons`: Query to retrieve and modify the cannons.
+pub fn desert_ship(
+    mut commands: Commands,
+    mut crew_deserted_events: EventWriter<CrewDeserted>,
+    crew: Query<(Entity, &Morale)>,
+    mut ships: Query<(Entity, &mut ShipCrew)>,
+    mut stations: Query<&mut Station>,
+    mut pumps: Query<&mut BilgePump>,
+    mut cannons: Query<&mut Cannon>,
+) {
+    for (crew_member, _) in crew
+        .iter()
+        .filter(|(_, morale)| morale.discontent >= DESERTION_TIME)
+    {
+        let ship = discharge_crew_member(
+            crew_member,
+            &mut ships,
+            &mut stations,
+            &mut pumps,
+            &mut cannons,
+        );
+
+        crew_deserted_events.send(CrewDeserted { crew_member, ship });
+        commands.entity(crew_member).despawn_recursive();
+    }
+}
@@ -0,0 +1,32 @@
+use bevy::input::ButtonInput;
+use bevy::prelude::{Entity, EventWriter, KeyCode, Query, Res, With};
+
+use crate::crew_morale::{CrewPaid, RumIssued};
+use crate::ship::PlayerShip;
+
+/// Lets the player issue rum to and pay the crew of their ship.
+///
+/// - `U`: Issue a tot of rum to the crew.
+/// - `Y`: Pay the crew.
+///
+/// # Parameters
+/// - `keyboard_input`: Resource capturing the current state of keyboard inputs.
+/// - `rum_issued_events`: Event writer for `RumIssued` events.
+/// - `crew_paid_events`: Event writer for `CrewPaid` events.
+/// - `ships`: Query to retrieve the player's ship.
+pub fn issue_rum_and_pay(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut rum_issued_events: EventWriter<RumIssued>,
+    mut crew_paid_events: EventWriter<CrewPaid>,
+    ships: Query<Entity, With<PlayerShip>>,
+) {
+    for ship in ships.iter() {
+        if keyboard_input.just_pressed(KeyCode::KeyU) {
+            rum_issued_events.send(RumIssued { ship });
+        }
+
+        if keyboard_input.just_pressed(KeyCode::KeyY) {
+            crew_paid_events.send(CrewPaid { ship });
+        }
+    }
+}
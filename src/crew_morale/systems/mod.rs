@@ -0,0 +1,10 @@
+pub use {
+    apply_morale_events::*, desert_ship::*, issue_rum_and_pay::*, update_morale::*,
+    update_ship_morale::*,
+};
+
+mod apply_morale_events;
+mod desert_ship;
+mod issue_rum_and_pay;
+mod update_morale;
+mod update_ship_morale;
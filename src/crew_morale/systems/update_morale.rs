@@ -0,0 +1,39 @@
+use bevy::prelude::{Query, Res, Time};
+
+use crate::ai_need_behavior::{Fatigue, Hunger, Need};
+use crate::crew_morale::utils::calculate_morale_change;
+use crate::crew_morale::{Morale, DISCONTENT_MORALE};
+
+/// Updates the morale of crew members from day to day life aboard.
+///
+/// Morale wears down over the voyage and with unmet needs, and recovers while the crew member
+/// eats and sleeps. The time each crew member spends discontented is tracked, and reset once
+/// their morale recovers.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `crew`: Query to retrieve and modify the morale of crew members, along with their needs.
+#[allow(clippy::type_complexity)]
+pub fn update_morale(
+    time: Res<Time>,
+    mut crew: Query<(&mut Morale, Option<&Need<Hunger>>, Option<&Need<Fatigue>>)>,
+) {
+    for (mut morale, hunger, fatigue) in crew.iter_mut() {
+        let change = calculate_morale_change(
+            hunger.map(|hunger| (hunger.level, hunger.is_satisfying)),
+            fatigue.map(|fatigue| (fatigue.level, fatigue.is_satisfying)),
+        ) * time.delta_seconds();
+
+        if change >= 0.0 {
+            morale.raise(change);
+        } else {
+            morale.lower(-change);
+        }
+
+        if morale.level < DISCONTENT_MORALE {
+            morale.discontent += time.delta_seconds();
+        } else {
+            morale.discontent = 0.0;
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::{Entity, EventWriter, Query};
+
+use crate::crew_management::ShipCrew;
+use crate::crew_morale::{Morale, Mutiny, ShipMorale, DISCONTENT_MORALE, MUTINY_CREW_FRACTION};
+
+/// Updates the morale of each ship's crew as a whole.
+///
+/// A ship mutinies when more than `MUTINY_CREW_FRACTION` of its crew are discontented, and a
+/// `Mutiny` event is sent as the mutiny breaks out. The mutiny ends once enough of the crew
+/// have been won back.
+///
+/// # Parameters
+/// - `mutiny_events`: Event writer for `Mutiny` events.
+/// - `crew`: Query to retrieve the morale of crew members.
+/// - `ships`: Query to retrieve the crew of ships and modify their `ShipMorale`.
+pub fn update_ship_morale(
+    mut mutiny_events: EventWriter<Mutiny>,
+    crew: Query<&Morale>,
+    mut ships: Query<(Entity, &ShipCrew, &mut ShipMorale)>,
+) {
+    for (ship, ship_crew, mut ship_morale) in ships.iter_mut() {
+        let levels: Vec<f32> = ship_crew
+            .members
+            .iter()
+            .filter_map(|member| crew.get(*member).ok())
+            .map(|morale| morale.level)
+            .collect();
+
+        if levels.is_empty() {
+            continue;
+        }
+
+        let discontented = levels
+            .iter()
+            .filter(|level| **level < DISCONTENT_MORALE)
+            .count();
+        let mutinous = discontented as f32 / levels.len() as f32 > MUTINY_CREW_FRACTION;
+
+        if mutinous && !ship_morale.mutinous {
+            mutiny_events.send(Mutiny { ship });
+        }
+
+        ship_morale.average = levels.iter().sum::<f32>() / levels.len() as f32;
+        ship_morale.mutinous = mutinous;
+    }
+}
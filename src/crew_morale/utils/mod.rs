@@ -0,0 +1,3 @@
+pub use morale_utils::*;
+
+mod morale_utils;
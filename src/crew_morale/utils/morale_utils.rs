@@ -0,0 +1,56 @@
+use bevy::prelude::{Entity, Query};
+
+use crate::crew_management::ShipCrew;
+use crate::crew_morale::{
+    ShipMorale, CONTENT_MORALE, LOW_MORALE_WORK_RATE, MEAL_MORALE_RATE, REST_MORALE_RATE,
+    UNMET_NEED_LEVEL, UNMET_NEED_MORALE_DECAY, VOYAGE_MORALE_DECAY,
+};
+
+/// Calculates how well a crew member works given their morale.
+///
+/// # Arguments
+/// - `level`: The crew member's morale, from `0.0` to `1.0`.
+///
+/// # Returns
+/// The fraction of full effectiveness the crew member works at, from `LOW_MORALE_WORK_RATE`
+/// with no morale to `1.0` at `CONTENT_MORALE` and above.
+pub fn calculate_morale_work_rate(level: f32) -> f32 {
+    let contentment = (level / CONTENT_MORALE).clamp(0.0, 1.0);
+    LOW_MORALE_WORK_RATE + (1.0 - LOW_MORALE_WORK_RATE) * contentment
+}
+
+/// Calculates how fast a crew member's morale changes from day to day life aboard.
+///
+/// Morale wears down steadily over the voyage, and faster for each of the crew member's needs
+/// that goes unmet. Eating and sleeping restore it.
+///
+/// # Arguments
+/// - `hunger`: The crew member's hunger level, out of `100.0`, and whether they are eating.
+/// - `fatigue`: The crew member's fatigue level, out of `100.0`, and whether they are sleeping.
+///
+/// # Returns
+/// The change in morale per second, which is negative while morale is falling.
+pub fn calculate_morale_change(hunger: Option<(f32, bool)>, fatigue: Option<(f32, bool)>) -> f32 {
+    let need_change = |need: Option<(f32, bool)>, satisfied_rate: f32| match need {
+        Some((_, true)) => satisfied_rate,
+        Some((level, false)) if level > UNMET_NEED_LEVEL => -UNMET_NEED_MORALE_DECAY,
+        _ => 0.0,
+    };
+
+    need_change(hunger, MEAL_MORALE_RATE) + need_change(fatigue, REST_MORALE_RATE)
+        - VOYAGE_MORALE_DECAY
+}
+
+/// Returns whether a crew member serves aboard a ship that is in mutiny.
+///
+/// # Arguments
+/// - `crew_member`: The crew member to check.
+/// - `ships`: Query to retrieve the crew of ships and their `ShipMorale`.
+///
+/// # Returns
+/// `true` if the crew member's ship is in mutiny.
+pub fn is_in_mutiny(crew_member: Entity, ships: &Query<(&ShipCrew, &ShipMorale)>) -> bool {
+    ships.iter().any(|(ship_crew, ship_morale)| {
+        ship_morale.mutinous && ship_crew.members.contains(&crew_member)
+    })
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// An order the player has given to a crew member.
+///
+/// The `CrewOrder` component is added to a crew member when the player orders them to a
+/// station, a compartment or a task. While a crew member has an order, following it takes
+/// priority over everything else they would otherwise do. The order is removed once it has
+/// been carried out, or when the player cancels it.
+///
+/// # Fields
+/// - `target`: Where the crew member has been ordered to, or what they have been ordered to do.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct CrewOrder {
+    pub target: OrderTarget,
+}
+
+/// The things a crew member can be ordered to.
+///
+/// - `Station`: Take over a station. The order is carried out once the crew member reaches
+///   it, after which they work it as their own.
+/// - `Compartment`: Go to a compartment of a ship's hull and stay there until the order is
+///   cancelled.
+/// - `Task`: Operate a bilge pump until its compartment is pumped dry, or load a cannon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum OrderTarget {
+    Station(Entity),
+    Compartment { ship: Entity, compartment: usize },
+    Task(Entity),
+}
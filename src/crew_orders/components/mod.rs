@@ -0,0 +1,4 @@
+pub use {crew_order::*, selected::*};
+
+mod crew_order;
+mod selected;
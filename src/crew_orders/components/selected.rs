@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A marker component indicating that the player has selected a crew member.
+///
+/// Orders the player issues are given to every selected crew member at once.
+#[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Selected;
@@ -0,0 +1,7 @@
+use bevy::prelude::Color;
+
+/// The colour of crew members the player has not selected.
+pub const CREW_COLOR: Color = Color::YELLOW;
+
+/// The colour of crew members the player has selected.
+pub const SELECTED_CREW_COLOR: Color = Color::CYAN;
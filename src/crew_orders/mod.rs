@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod systems;
+mod utils;
+
+/// Plugin for letting the player command the crew.
+///
+/// The `CrewOrdersPlugin` lets the player select crew members of their ship and order them
+/// to stations, compartments or tasks. A crew member follows their order ahead of anything
+/// else they would do, until it has been carried out or the player cancels it.
+///
+/// # Components
+/// - `CrewOrder`: An order the player has given to a crew member.
+/// - `Selected`: A marker component indicating that the player has selected a crew member.
+///
+/// # Systems
+/// - `highlight_selected_crew`: Colours the crew members the player has selected.
+/// - `issue_crew_orders`: Gives orders to the selected crew members, pointing out targets around the player.
+/// - `select_crew_members`: Selects the crew members of the player's ship that orders are given to.
+///
+/// All systems are run during the `Update` stage and only when the application is in the `AppStates::Running` state.
+pub struct CrewOrdersPlugin;
+
+impl Plugin for CrewOrdersPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CrewOrder>()
+            .register_type::<Selected>()
+            .add_systems(
+                Update,
+                highlight_selected_crew.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                issue_crew_orders.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                select_crew_members.run_if(in_state(AppStates::Running)),
+            );
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::asset::{Assets, Handle};
+use bevy::pbr::StandardMaterial;
+use bevy::prelude::{Added, Query, RemovedComponents, ResMut, With};
+
+use crate::crew_management::CrewMember;
+use crate::crew_orders::{Selected, CREW_COLOR, SELECTED_CREW_COLOR};
+
+/// Colours the crew members the player has selected, so the player can see who their orders
+/// go to.
+///
+/// # Parameters
+/// - `materials`: Resource containing the materials of the crew members.
+/// - `newly_selected`: Query to retrieve the materials of crew members that have just been selected.
+/// - `deselected`: The crew members that have been deselected since the last update.
+/// - `crew`: Query to retrieve the materials of crew members.
+pub fn highlight_selected_crew(
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    newly_selected: Query<&Handle<StandardMaterial>, (With<CrewMember>, Added<Selected>)>,
+    mut deselected: RemovedComponents<Selected>,
+    crew: Query<&Handle<StandardMaterial>, With<CrewMember>>,
+) {
+    for crew_member in deselected.read() {
+        if let Some(material) = crew
+            .get(crew_member)
+            .ok()
+            .and_then(|handle| materials.get_mut(handle))
+        {
+            material.base_color = CREW_COLOR;
+        }
+    }
+
+    for handle in newly_selected.iter() {
+        if let Some(material) = materials.get_mut(handle) {
+            material.base_color = SELECTED_CREW_COLOR;
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use bevy::input::ButtonInput;
+use bevy::log::debug;
+use bevy::prelude::{Commands, Entity, GlobalTransform, KeyCode, Parent, Query, Res, Vec3, With};
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::crew_management::{CrewMember, Station};
+use crate::crew_orders::utils::assign_nearest_targets;
+use crate::crew_orders::{CrewOrder, OrderTarget, Selected};
+use crate::flooding::Flooding;
+use crate::gunnery::Cannon;
+use crate::player::Player;
+use crate::ship::PlayerShip;
+use crate::ship_items::BilgePump;
+use crate::utils::find_top_level_entity;
+
+/// Gives orders to the selected crew members, pointing out targets around the player.
+///
+/// - `Z`: Order the selected crew to the stations nearest the player. Each crew member takes
+///   over a different station, and the duty roster is redrawn around them: the station they
+///   leave falls vacant and whoever worked the station they take over is relieved.
+/// - `C`: Order the selected crew to the compartment of the ship the player is standing in.
+/// - `V`: Order the selected crew to the bilge pumps and cannons nearest the player, one each.
+///   Pumps and cannons someone else is already operating are passed over.
+/// - `X`: Cancel the orders of the selected crew.
+///
+/// The selected crew serve aboard the player's ship, so only the stations, compartments, pumps
+/// and cannons of the player's ship are pointed out.
+///
+/// # Parameters
+/// - `commands`: Commands for giving and cancelling orders.
+/// - `keyboard_input`: Resource capturing the current state of keyboard inputs.
+/// - `players`: Query to retrieve the global transform of the player.
+/// - `selected`: Query to retrieve the selected crew members.
+/// - `stations`: Query to retrieve the global transforms of the stations.
+/// - `pumps`: Query to retrieve the bilge pumps and their global transforms.
+/// - `cannons`: Query to retrieve the cannons and their global transforms.
+/// - `hulls`: Query to retrieve the flooding state, voxels and global transforms of ships.
+/// - `player_ships`: Query to identify the player's ship.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each target.
+#[allow(clippy::too_many_arguments)]
+pub fn issue_crew_orders(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    players: Query<&GlobalTransform, With<Player>>,
+    selected: Query<Entity, (With<Selected>, With<CrewMember>)>,
+    stations: Query<(Entity, &GlobalTransform), With<Station>>,
+    pumps: Query<(Entity, &BilgePump, &GlobalTransform)>,
+    cannons: Query<(Entity, &Cannon, &GlobalTransform)>,
+    hulls: Query<(&Flooding, &Buoyancy, &GlobalTransform)>,
+    player_ships: Query<Entity, With<PlayerShip>>,
+    parent_query: Query<&Parent>,
+) {
+    let Ok(player_transform) = players.get_single() else {
+        return;
+    };
+    let player_position = player_transform.translation();
+    let Ok(player_ship) = player_ships.get_single() else {
+        return;
+    };
+    let is_aboard = |entity: Entity| find_top_level_entity(entity, &parent_query) == player_ship;
+
+    let mut crew: Vec<Entity> = selected.iter().collect();
+    if crew.is_empty() {
+        return;
+    }
+    crew.sort();
+
+    if keyboard_input.just_pressed(KeyCode::KeyZ) {
+        let targets: Vec<(Entity, Vec3)> = stations
+            .iter()
+            .filter(|(station, _)| is_aboard(*station))
+            .map(|(station, transform)| (station, transform.translation()))
+            .collect();
+
+        // The duty roster hands the stations over once it sees the orders
+        for (crew_member, target) in assign_nearest_targets(&crew, player_position, &targets) {
+            debug!("Ordering {:?} to station {:?}.", crew_member, target);
+            commands.entity(crew_member).insert(CrewOrder {
+                target: OrderTarget::Station(target),
+            });
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::KeyC) {
+        let target = hulls
+            .get(player_ship)
+            .ok()
+            .and_then(|(flooding, buoyancy, transform)| {
+                let local_position = transform
+                    .affine()
+                    .inverse()
+                    .transform_point3(player_position);
+                flooding.compartment_at(&buoyancy.voxels, local_position)
+            })
+            .map(|compartment| OrderTarget::Compartment {
+                ship: player_ship,
+                compartment,
+            });
+
+        match target {
+            Some(target) => {
+                for crew_member in crew.iter() {
+                    debug!("Ordering {:?} to {:?}.", crew_member, target);
+                    commands.entity(*crew_member).insert(CrewOrder { target });
+                }
+            }
+            None => debug!("The player is not standing in a compartment."),
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::KeyV) {
+        let pump_targets = pumps
+            .iter()
+            .map(|(task, pump, transform)| (task, pump.operator, transform));
+        let cannon_targets = cannons
+            .iter()
+            .map(|(task, cannon, transform)| (task, cannon.operator, transform));
+        let targets: Vec<(Entity, Vec3)> = pump_targets
+            .chain(cannon_targets)
+            .filter(|(task, operator, _)| {
+                is_aboard(*task) && operator.is_none_or(|operator| crew.contains(&operator))
+            })
+            .map(|(task, _, transform)| (task, transform.translation()))
+            .collect();
+
+        for (crew_member, target) in assign_nearest_targets(&crew, player_position, &targets) {
+            debug!("Ordering {:?} to task {:?}.", crew_member, target);
+            commands.entity(crew_member).insert(CrewOrder {
+                target: OrderTarget::Task(target),
+            });
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::KeyX) {
+        for crew_member in crew.iter() {
+            debug!("Cancelling the orders of {:?}.", crew_member);
+            commands.entity(*crew_member).remove::<CrewOrder>();
+        }
+    }
+}
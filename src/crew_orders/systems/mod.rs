@@ -0,0 +1,5 @@
+pub use {highlight_selected_crew::*, issue_crew_orders::*, select_crew_members::*};
+
+mod highlight_selected_crew;
+mod issue_crew_orders;
+mod select_crew_members;
@@ -0,0 +1,59 @@
+use bevy::input::ButtonInput;
+use bevy::prelude::{Commands, Entity, KeyCode, Query, Res, With};
+
+use crate::crew_management::ShipCrew;
+use crate::crew_orders::utils::find_next_crew_member;
+use crate::crew_orders::Selected;
+use crate::ship::PlayerShip;
+
+/// Selects the crew members of the player's ship that orders are given to.
+///
+/// - `Tab`: Select the next crew member on their own.
+/// - `Shift` + `Tab`: Add the next crew member to the selection.
+///
+/// # Parameters
+/// - `commands`: Commands for selecting and deselecting crew members.
+/// - `keyboard_input`: Resource capturing the current state of keyboard inputs.
+/// - `ships`: Query to retrieve the crew of the player's ship.
+/// - `selected`: Query to retrieve the crew members that are currently selected.
+pub fn select_crew_members(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    ships: Query<&ShipCrew, With<PlayerShip>>,
+    selected: Query<Entity, With<Selected>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Tab) {
+        return;
+    }
+
+    let Ok(ship_crew) = ships.get_single() else {
+        return;
+    };
+
+    let is_adding =
+        keyboard_input.pressed(KeyCode::ShiftLeft) || keyboard_input.pressed(KeyCode::ShiftRight);
+    let currently_selected: Vec<Entity> = selected.iter().collect();
+
+    let next = if is_adding {
+        find_next_crew_member(&ship_crew.members, &currently_selected)
+    } else {
+        let last_selected: Vec<Entity> = ship_crew
+            .members
+            .iter()
+            .rev()
+            .find(|member| currently_selected.contains(member))
+            .into_iter()
+            .copied()
+            .collect();
+
+        for crew_member in currently_selected {
+            commands.entity(crew_member).remove::<Selected>();
+        }
+
+        find_next_crew_member(&ship_crew.members, &last_selected)
+    };
+
+    if let Some(crew_member) = next {
+        commands.entity(crew_member).insert(Selected);
+    }
+}
@@ -0,0 +1,3 @@
+pub use order_utils::*;
+
+mod order_utils;
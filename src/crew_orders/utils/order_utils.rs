@@ -0,0 +1,93 @@
+use bevy::prelude::{Entity, GlobalTransform, Query, Vec3};
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::crew_orders::OrderTarget;
+use crate::flooding::Flooding;
+
+/// Finds the crew member the selection moves on to next.
+///
+/// The selection moves through the crew in the order they are listed, starting after the
+/// last selected crew member and wrapping around to the start of the list. Crew members who
+/// are already selected are passed over.
+///
+/// # Arguments
+/// - `members`: The crew members of the ship, in order.
+/// - `selected`: The crew members that are currently selected.
+///
+/// # Returns
+/// The next crew member to select, or `None` if every crew member is already selected.
+pub fn find_next_crew_member(members: &[Entity], selected: &[Entity]) -> Option<Entity> {
+    let start = members
+        .iter()
+        .rposition(|member| selected.contains(member))
+        .map_or(0, |last| last + 1);
+
+    members
+        .iter()
+        .cycle()
+        .skip(start)
+        .take(members.len())
+        .find(|member| !selected.contains(member))
+        .copied()
+}
+
+/// Pairs crew members with the targets nearest to a position.
+///
+/// The first crew member is given the nearest target, the second the next nearest, and so on,
+/// so that no two crew members are sent to the same target. Crew members left over once
+/// every target has been handed out are not paired.
+///
+/// # Arguments
+/// - `crew`: The crew members to give targets to.
+/// - `position`: The position the targets are measured from.
+/// - `targets`: The targets with their positions.
+///
+/// # Returns
+/// The pairs of a crew member and the target they are given.
+pub fn assign_nearest_targets(
+    crew: &[Entity],
+    position: Vec3,
+    targets: &[(Entity, Vec3)],
+) -> Vec<(Entity, Entity)> {
+    let mut targets = targets.to_vec();
+    targets.sort_by(|(_, a), (_, b)| {
+        a.distance_squared(position)
+            .total_cmp(&b.distance_squared(position))
+    });
+
+    crew.iter()
+        .copied()
+        .zip(targets.into_iter().map(|(target, _)| target))
+        .collect()
+}
+
+/// Calculates where a crew member has to go to carry out an order.
+///
+/// # Arguments
+/// - `target`: The target of the order.
+/// - `transforms`: Query to retrieve the global transforms of stations, tasks and ships.
+/// - `hulls`: Query to retrieve the flooding state and voxels of hulls.
+///
+/// # Returns
+/// The position of the station or task, or the centre of the compartment, in world space.
+/// Returns `None` if the target no longer exists.
+pub fn calculate_order_destination(
+    target: &OrderTarget,
+    transforms: &Query<&GlobalTransform>,
+    hulls: &Query<(&Flooding, &Buoyancy)>,
+) -> Option<Vec3> {
+    match *target {
+        OrderTarget::Station(entity) | OrderTarget::Task(entity) => transforms
+            .get(entity)
+            .ok()
+            .map(|transform| transform.translation()),
+        OrderTarget::Compartment { ship, compartment } => {
+            let (flooding, buoyancy) = hulls.get(ship).ok()?;
+            let centre = flooding.compartment_centre(compartment, &buoyancy.voxels)?;
+            transforms
+                .get(ship)
+                .ok()
+                .map(|transform| transform.transform_point(centre))
+        }
+    }
+}
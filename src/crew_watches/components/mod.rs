@@ -0,0 +1,3 @@
+pub use watch::*;
+
+mod watch;
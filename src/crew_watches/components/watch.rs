@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The watch a crew member stands.
+///
+/// The crew of a ship is split into two watches that take turns on deck. While one watch is
+/// on duty and holds the stations, the other is below, free to sleep and eat.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Component, Reflect, Serialize, Deserialize, Default,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub enum Watch {
+    #[default]
+    Starboard,
+    Larboard,
+}
+
+impl Watch {
+    /// Returns the watch at the given place in the rotation, which alternates between the
+    /// starboard and larboard watch.
+    pub fn nth(index: usize) -> Self {
+        match index % 2 {
+            0 => Watch::Starboard,
+            _ => Watch::Larboard,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub use watch_settings::*;
+
+mod watch_settings;
@@ -0,0 +1,12 @@
+/// The hours of the in-game day a watch stands before it is relieved.
+pub const WATCH_HOURS: f32 = 4.0;
+
+/// How much more urgent the needs of a crew member feel while their watch is below.
+pub const OFF_WATCH_NEED_BIAS: f32 = 1.25;
+
+/// How much less urgent the needs of a crew member feel while their watch is on duty.
+pub const ON_WATCH_NEED_BIAS: f32 = 0.75;
+
+/// The score for manning a station while the crew member's watch is below. It falls short of
+/// the threshold the crew's thinker acts on, so crew below stand down from their stations.
+pub const OFF_WATCH_STATION_SCORE: f32 = 0.4;
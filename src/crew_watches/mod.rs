@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+pub use resources::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod resources;
+mod systems;
+mod utils;
+
+/// Plugin for running the crew in watches.
+///
+/// The `CrewWatchesPlugin` splits the crew of each ship into a starboard and a larboard watch,
+/// role by role, which take turns on duty by the in-game clock. The watch on duty holds the
+/// stations, the duty roster handing them over to its hands at each change of watch, while
+/// the watch below is more inclined to sleep and eat, so the ship always has crew on duty.
+///
+/// # Components
+/// - `Watch`: The watch a crew member stands.
+///
+/// # Resources
+/// - `WatchSchedule`: Describes which watch is on duty and how long each watch stands.
+///
+/// # Systems
+/// - `update_watch_schedule`: Hands the deck over to the next watch as the in-game clock moves on.
+///
+/// All systems are run during the `Update` stage and only when the application is in the `AppStates::Running` state.
+pub struct CrewWatchesPlugin;
+
+impl Plugin for CrewWatchesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Watch>()
+            .register_type::<WatchSchedule>()
+            .insert_resource(WatchSchedule::new(WATCH_HOURS))
+            .add_systems(
+                Update,
+                update_watch_schedule.run_if(in_state(AppStates::Running)),
+            );
+    }
+}
@@ -0,0 +1,3 @@
+pub use watch_schedule::*;
+
+mod watch_schedule;
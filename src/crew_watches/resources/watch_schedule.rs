@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::crew_watches::{Watch, OFF_WATCH_NEED_BIAS, ON_WATCH_NEED_BIAS, WATCH_HOURS};
+
+/// A resource describing which watch of the crew is on duty.
+///
+/// The `WatchSchedule` resource follows the in-game clock of the `SunCycleTimer`, handing the
+/// deck from one watch to the other every `watch_hours`. The crew's scorers read it to bias
+/// their scores, so that the watch on duty holds the stations while the watch below sleeps and
+/// eats, and the ship always has crew on duty.
+///
+/// # Fields
+/// - `watch_hours`: The hours a watch stands before it is relieved.
+/// - `on_duty`: The watch currently on duty. Updated by `update_watch_schedule`.
+///
+/// # Methods
+/// - `new(watch_hours: f32) -> Self`:
+///   Creates a new `WatchSchedule` with the starboard watch on duty.
+/// - `watch_at(&self, hour: f32) -> Watch`:
+///   Returns the watch on duty at the given hour of the day.
+/// - `is_on_duty(&self, watch: Option<&Watch>) -> bool`:
+///   Returns whether a crew member of the given watch is on duty. Crew who stand no watch are
+///   always on duty.
+/// - `need_bias(&self, watch: Option<&Watch>) -> f32`:
+///   Returns the factor the need scores of a crew member of the given watch are scaled by.
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct WatchSchedule {
+    pub watch_hours: f32,
+    pub on_duty: Watch,
+}
+
+impl WatchSchedule {
+    /// Creates a new `WatchSchedule`.
+    ///
+    /// # Parameters
+    /// - `watch_hours`: The hours a watch stands before it is relieved.
+    ///
+    /// # Returns
+    /// A new `WatchSchedule` instance with the starboard watch on duty.
+    pub fn new(watch_hours: f32) -> Self {
+        Self {
+            watch_hours,
+            on_duty: Watch::Starboard,
+        }
+    }
+
+    /// Returns the watch on duty at the given hour of the day.
+    ///
+    /// The starboard watch takes the first watch after midnight, and the watches alternate
+    /// from there.
+    ///
+    /// # Parameters
+    /// - `hour`: The hour of the day, from `0.0` up to `24.0`.
+    ///
+    /// # Returns
+    /// The watch on duty.
+    pub fn watch_at(&self, hour: f32) -> Watch {
+        let watches_since_midnight = (hour / self.watch_hours).floor() as usize;
+        Watch::nth(watches_since_midnight)
+    }
+
+    /// Returns whether a crew member of the given watch is on duty.
+    ///
+    /// # Parameters
+    /// - `watch`: The watch of the crew member, if they stand one.
+    ///
+    /// # Returns
+    /// `true` if their watch is on duty or they stand no watch, `false` otherwise.
+    pub fn is_on_duty(&self, watch: Option<&Watch>) -> bool {
+        watch.is_none_or(|watch| *watch == self.on_duty)
+    }
+
+    /// Returns the factor the need scores of a crew member of the given watch are scaled by.
+    ///
+    /// Needs feel more urgent while the watch is below and less urgent while it is on duty.
+    /// The needs of crew who stand no watch are left as they are.
+    ///
+    /// # Parameters
+    /// - `watch`: The watch of the crew member, if they stand one.
+    ///
+    /// # Returns
+    /// The factor to scale need scores by.
+    pub fn need_bias(&self, watch: Option<&Watch>) -> f32 {
+        match watch {
+            None => 1.0,
+            Some(watch) if *watch == self.on_duty => ON_WATCH_NEED_BIAS,
+            Some(_) => OFF_WATCH_NEED_BIAS,
+        }
+    }
+}
+
+impl Default for WatchSchedule {
+    /// Creates a `WatchSchedule` of `WATCH_HOURS` long watches with the starboard watch on duty.
+    fn default() -> Self {
+        Self::new(WATCH_HOURS)
+    }
+}
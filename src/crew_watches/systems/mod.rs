@@ -0,0 +1,3 @@
+pub use update_watch_schedule::*;
+
+mod update_watch_schedule;
@@ -0,0 +1,25 @@
+use bevy::log::debug;
+use bevy::prelude::{Res, ResMut};
+
+use crate::crew_watches::WatchSchedule;
+use crate::sun::SunCycleTimer;
+
+/// Hands the deck over to the next watch as the in-game clock moves on.
+///
+/// The watch on duty is read off the hour of the day kept by the `SunCycleTimer`, so the
+/// watches keep time with the sun, even when the day is sped up or paused.
+///
+/// # Parameters
+/// - `timer`: Resource keeping the in-game clock.
+/// - `schedule`: Resource describing which watch is on duty.
+pub fn update_watch_schedule(timer: Res<SunCycleTimer>, mut schedule: ResMut<WatchSchedule>) {
+    let watch = schedule.watch_at(timer.hour_of_day());
+
+    if watch != schedule.on_duty {
+        debug!(
+            "The {:?} watch relieves the {:?} watch.",
+            watch, schedule.on_duty
+        );
+        schedule.on_duty = watch;
+    }
+}
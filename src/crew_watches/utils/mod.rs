@@ -0,0 +1,3 @@
+pub use watch_utils::*;
+
+mod watch_utils;
@@ -0,0 +1,24 @@
+use crate::crew_management::CrewRole;
+use crate::crew_watches::Watch;
+
+/// Splits a ship's crew between the watches, role by role.
+///
+/// The hands of each role take turns between the starboard and larboard watch in the order
+/// they are listed, so each watch has its share of every role and the stations of a role can
+/// be worked whichever watch is on duty.
+///
+/// # Arguments
+/// - `roles`: The role of each crew member, in the order they signed on.
+///
+/// # Returns
+/// The watch each crew member stands, in the same order.
+pub fn assign_watches(roles: &[CrewRole]) -> Vec<Watch> {
+    roles
+        .iter()
+        .enumerate()
+        .map(|(index, role)| {
+            let hands_before = roles[..index].iter().filter(|other| *other == role).count();
+            Watch::nth(hands_before)
+        })
+        .collect()
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Marker component for a ship system or hull that needs repairing.
+///
+/// The `Damaged` component is kept up to date by the `mark_damaged` system, so the crew can
+/// find their way to whatever needs their attention.
+#[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Damaged;
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Tracks how intact each section of a ship's hull is.
+///
+/// The `HullIntegrity` component is added to ships once their `Flooding` is set up. The hull
+/// is divided into one section per compartment of the `Flooding`, in the same order, followed
+/// by a last section for the voxels outside every compartment. A hull without compartments
+/// has a single section. Shot striking the hull damages the section it hits as well as holing
+/// it, and repairing a section plugs the holes in it.
+///
+/// # Fields
+/// - `sections`: How intact each section is, from `0.0` (destroyed) to `1.0` (intact).
+///
+/// # Methods
+/// - `new(compartments: usize) -> Self`:
+///   Creates an intact `HullIntegrity` for a hull with the given number of compartments.
+/// - `section(compartment: Option<usize>) -> usize`:
+///   Returns the section holding a compartment, or the voxels outside every compartment.
+/// - `compartment(&self, section: usize) -> Option<usize>`:
+///   Returns the compartment of a section, or `None` for the voxels outside every compartment.
+/// - `damage(&mut self, section: usize, amount: f32)`: Reduces a section's integrity.
+/// - `repair(&mut self, section: usize, amount: f32)`: Restores a section's integrity.
+/// - `most_damaged_section(&self) -> Option<usize>`: Returns the section most in need of repair.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct HullIntegrity {
+    pub sections: Vec<f32>,
+}
+
+impl HullIntegrity {
+    /// Creates an intact `HullIntegrity` for a hull with the given number of compartments.
+    pub fn new(compartments: usize) -> Self {
+        Self {
+            sections: vec![1.0; compartments + 1],
+        }
+    }
+
+    /// Returns the section holding a compartment, or the voxels outside every compartment.
+    pub fn section(&self, compartment: Option<usize>) -> usize {
+        let outside = self.sections.len().saturating_sub(1);
+        compartment
+            .filter(|&compartment| compartment < outside)
+            .unwrap_or(outside)
+    }
+
+    /// Returns the compartment of a section, or `None` for the voxels outside every compartment.
+    pub fn compartment(&self, section: usize) -> Option<usize> {
+        (section + 1 < self.sections.len()).then_some(section)
+    }
+
+    /// Reduces the integrity of a section, down to `0.0`.
+    pub fn damage(&mut self, section: usize, amount: f32) {
+        if let Some(level) = self.sections.get_mut(section) {
+            *level = (*level - amount.max(0.0)).max(0.0);
+        }
+    }
+
+    /// Restores the integrity of a section, up to `1.0`.
+    pub fn repair(&mut self, section: usize, amount: f32) {
+        if let Some(level) = self.sections.get_mut(section) {
+            *level = (*level + amount.max(0.0)).min(1.0);
+        }
+    }
+
+    /// Returns the damaged section with the lowest integrity, if any section is damaged.
+    pub fn most_damaged_section(&self) -> Option<usize> {
+        self.sections
+            .iter()
+            .enumerate()
+            .filter(|(_, level)| **level < 1.0)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(section, _)| section)
+    }
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Tracks how intact a ship system is.
+///
+/// The `Integrity` component is added to the entities of the systems a ship depends on: its
+/// sails, rudder, guns and bilge pumps. Damaged systems work less well in proportion to
+/// their integrity, so torn sails catch less wind, a damaged rudder steers less, damaged guns
+/// load slower and damaged pumps move less water. A destroyed system does not work at all
+/// until the crew repair it. The rudder's integrity is kept on the ship entity itself, alongside
+/// the `HullIntegrity` of its hull.
+///
+/// # Fields
+/// - `level`: How intact the system is, from `0.0` (destroyed) to `1.0` (intact).
+///
+/// # Methods
+/// - `damage(&mut self, amount: f32)`: Reduces the integrity, down to `0.0`.
+/// - `repair(&mut self, amount: f32)`: Restores the integrity, up to `1.0`.
+/// - `is_damaged(&self) -> bool`: Returns whether the system needs repairing.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Integrity {
+    pub level: f32,
+}
+
+impl Integrity {
+    /// Reduces the integrity of the system, down to `0.0`.
+    pub fn damage(&mut self, amount: f32) {
+        self.level = (self.level - amount.max(0.0)).max(0.0);
+    }
+
+    /// Restores the integrity of the system, up to `1.0`.
+    pub fn repair(&mut self, amount: f32) {
+        self.level = (self.level + amount.max(0.0)).min(1.0);
+    }
+
+    /// Returns whether the system is damaged and needs repairing.
+    pub fn is_damaged(&self) -> bool {
+        self.level < 1.0
+    }
+}
+
+impl Default for Integrity {
+    fn default() -> Self {
+        Self { level: 1.0 }
+    }
+}
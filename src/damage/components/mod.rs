@@ -0,0 +1,5 @@
+pub use {damaged::*, hull_integrity::*, integrity::*};
+
+mod damaged;
+mod hull_integrity;
+mod integrity;
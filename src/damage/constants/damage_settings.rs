@@ -0,0 +1,40 @@
+/// The speed of shot, in metres per second, at which it does its listed damage. Faster shot
+/// does more damage and slower shot less, in proportion to its energy.
+pub const REFERENCE_SHOT_SPEED: f32 = 120.0;
+
+/// The area in square metres of the hole round shot punches in a hull.
+pub const ROUND_SHOT_BREACH_AREA: f32 = 0.05;
+
+/// The integrity a hull section loses to a hit from round shot.
+pub const ROUND_SHOT_HULL_DAMAGE: f32 = 0.15;
+
+/// The integrity a ship system loses to a direct hit from round shot.
+pub const ROUND_SHOT_SYSTEM_DAMAGE: f32 = 0.5;
+
+/// The distance in metres from a hit at which round shot damages ship systems.
+pub const ROUND_SHOT_SYSTEM_RADIUS: f32 = 3.0;
+
+/// The integrity a hull section loses to a hit from chain shot.
+pub const CHAIN_SHOT_HULL_DAMAGE: f32 = 0.02;
+
+/// The integrity a ship system loses to a direct hit from chain shot.
+pub const CHAIN_SHOT_SYSTEM_DAMAGE: f32 = 0.6;
+
+/// The distance in metres from a hit at which chain shot damages ship systems. Chain shot
+/// tumbles through the rigging, so it reaches the sails high above where it struck.
+pub const CHAIN_SHOT_SYSTEM_RADIUS: f32 = 15.0;
+
+/// The integrity a ship system loses to a direct hit from a ball of grape shot.
+pub const GRAPE_SHOT_SYSTEM_DAMAGE: f32 = 0.05;
+
+/// The distance in metres from a hit at which grape shot damages ship systems.
+pub const GRAPE_SHOT_SYSTEM_RADIUS: f32 = 1.5;
+
+/// The area of breach in square metres plugged for every unit of integrity repaired.
+pub const BREACH_AREA_PER_REPAIR: f32 = 0.5;
+
+/// How far a crew member can be from a ship system, in metres, and still repair it.
+pub const REPAIR_DISTANCE: f32 = 3.0;
+
+/// How far a crew member can be from the centre of a hull, in metres, and still repair it.
+pub const HULL_REPAIR_DISTANCE: f32 = 30.0;
@@ -0,0 +1,3 @@
+pub use damage_settings::*;
+
+mod damage_settings;
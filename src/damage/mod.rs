@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+pub use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod systems;
+mod utils;
+
+/// Plugin for the damage ships take from gunfire.
+///
+/// The `DamagePlugin` tracks the integrity of each section of a ship's hull and of the
+/// systems the ship depends on: its sails, rudder, guns and bilge pumps. Projectile hits
+/// reported by the gunnery systems damage the hull section they strike and hole it through
+/// `HullBreached` events, and damage the systems around them. Damaged systems work less
+/// well until the crew repair them, and repairing the hull plugs its holes.
+///
+/// # Components
+/// - `Damaged`: Marks a ship system or hull that needs repairing.
+/// - `HullIntegrity`: Tracks how intact each section of a ship's hull is.
+/// - `Integrity`: Tracks how intact a ship system is.
+///
+/// # Systems
+/// - `apply_projectile_damage`: Damages hulls and ship systems hit by projectiles.
+/// - `initialize_hull_integrity`: Sections the hulls of ships once they are ready to flood.
+/// - `initialize_integrity`: Gives every new ship system an intact `Integrity`.
+/// - `mark_damaged`: Keeps the `Damaged` marker on the systems and hulls that need repairing.
+///
+/// This plugin is added to the app during the application setup and is configured to
+/// operate during the `AppStates::Running` state.
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Damaged>()
+            .register_type::<HullIntegrity>()
+            .register_type::<Integrity>()
+            .add_systems(
+                Update,
+                apply_projectile_damage.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                initialize_hull_integrity.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                initialize_integrity.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                mark_damaged
+                    .after(apply_projectile_damage)
+                    .run_if(in_state(AppStates::Running)),
+            );
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::{Entity, EventReader, EventWriter, GlobalTransform, Parent, Query, Transform};
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::damage::utils::{calculate_hit_effect, calculate_system_damage};
+use crate::damage::{HullIntegrity, Integrity};
+use crate::flooding::{find_nearest_solid_voxel, Flooding, HullBreached};
+use crate::gunnery::ProjectileHit;
+use crate::hydrodynamics::Rudder;
+use crate::utils::find_top_level_entity;
+
+/// Damages hulls and ship systems in response to `ProjectileHit` events.
+///
+/// For each hit on a hull, the section holding the solid voxel nearest to the hit loses
+/// integrity, and shot that holes the hull sends a `HullBreached` event so water can flood in.
+/// Every system of the struck ship near the hit is damaged as well, less so the further it is
+/// from the hit, while the systems of other ships nearby are left alone.
+/// The rudder is damaged by hits near the rudder itself rather than the ship's centre.
+///
+/// # Parameters
+/// - `projectile_hit_events`: Event reader for `ProjectileHit` events.
+/// - `hull_breached_events`: Event writer for `HullBreached` events.
+/// - `hulls`: Query to retrieve the voxels, flooding state, transform and integrity of hulls.
+/// - `systems`: Query to retrieve ship systems, their transforms and integrity.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each system.
+pub fn apply_projectile_damage(
+    mut projectile_hit_events: EventReader<ProjectileHit>,
+    mut hull_breached_events: EventWriter<HullBreached>,
+    mut hulls: Query<(&Buoyancy, &Flooding, &Transform, &mut HullIntegrity)>,
+    mut systems: Query<(Entity, &mut Integrity, &GlobalTransform, Option<&Rudder>)>,
+    parent_query: Query<&Parent>,
+) {
+    for hit in projectile_hit_events.read() {
+        let effect = calculate_hit_effect(hit.shot, hit.velocity.length());
+
+        if let Ok((buoyancy, flooding, transform, mut hull_integrity)) = hulls.get_mut(hit.target) {
+            let local_position =
+                transform.rotation.inverse() * (hit.position - transform.translation);
+
+            if let Some(voxel) = find_nearest_solid_voxel(&buoyancy.voxels, local_position) {
+                let section = hull_integrity.section(flooding.voxel_compartments[voxel]);
+                hull_integrity.damage(section, effect.hull_damage);
+            }
+
+            if effect.breach_area > 0.0 {
+                hull_breached_events.send(HullBreached {
+                    entity: hit.target,
+                    position: hit.position,
+                    area: effect.breach_area,
+                });
+            }
+        }
+
+        for (system, mut integrity, system_transform, rudder) in systems.iter_mut() {
+            if find_top_level_entity(system, &parent_query) != hit.target {
+                continue;
+            }
+
+            let system_position = match rudder {
+                Some(rudder) => system_transform.transform_point(rudder.position),
+                None => system_transform.translation(),
+            };
+
+            let damage = calculate_system_damage(&effect, system_position.distance(hit.position));
+            if damage > 0.0 {
+                integrity.damage(damage);
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use bevy::prelude::{Added, Commands, Entity, Query, With};
+
+use crate::damage::HullIntegrity;
+use crate::flooding::Flooding;
+use crate::ship::Ship;
+
+/// Gives ships an intact `HullIntegrity` once their hull is ready to flood.
+///
+/// The hull is divided into a section per compartment of its `Flooding`, so this system waits
+/// for the `Flooding` component to be set up before sectioning the hull.
+///
+/// # Parameters
+/// - `commands`: Commands for inserting the `HullIntegrity` component.
+/// - `query`: Query to retrieve ships whose `Flooding` has just been added.
+#[allow(clippy::type_complexity)]
+pub fn initialize_hull_integrity(
+    mut commands: Commands,
+    query: Query<(Entity, &Flooding), (Added<Flooding>, With<Ship>)>,
+) {
+    for (entity, flooding) in query.iter() {
+        commands
+            .entity(entity)
+            .insert(HullIntegrity::new(flooding.compartments.len()));
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::{Added, Commands, Entity, Or, Query, Without};
+
+use crate::damage::Integrity;
+use crate::gunnery::Cannon;
+use crate::hydrodynamics::Rudder;
+use crate::sailing::Sail;
+use crate::ship_items::BilgePump;
+
+/// Gives every new ship system an intact `Integrity`.
+///
+/// Sails, rudders, cannons and bilge pumps can all be damaged, however they were spawned,
+/// so this system adds the `Integrity` component to them as soon as they appear.
+///
+/// # Parameters
+/// - `commands`: Commands for inserting the `Integrity` component.
+/// - `query`: Query to retrieve newly added ship systems without an `Integrity`.
+#[allow(clippy::type_complexity)]
+pub fn initialize_integrity(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        (
+            Or<(Added<Sail>, Added<Rudder>, Added<Cannon>, Added<BilgePump>)>,
+            Without<Integrity>,
+        ),
+    >,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).insert(Integrity::default());
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::{Changed, Commands, Entity, Or, Query};
+
+use crate::damage::utils::needs_repair;
+use crate::damage::{Damaged, HullIntegrity, Integrity};
+
+/// Keeps the `Damaged` marker on the ship systems and hulls that need repairing.
+///
+/// Whenever the integrity of a system or hull changes, the marker is added if it is damaged
+/// and removed once it has been fully repaired. A hull that carries a system, like the rudder
+/// on the ship entity, keeps the marker while either of them is damaged.
+///
+/// # Parameters
+/// - `commands`: Commands for adding and removing the `Damaged` marker.
+/// - `query`: Query to retrieve ship systems and hulls whose `Integrity` or `HullIntegrity`
+///   has changed.
+#[allow(clippy::type_complexity)]
+pub fn mark_damaged(
+    mut commands: Commands,
+    query: Query<
+        (Entity, Option<&Integrity>, Option<&HullIntegrity>),
+        Or<(Changed<Integrity>, Changed<HullIntegrity>)>,
+    >,
+) {
+    for (entity, integrity, hull_integrity) in query.iter() {
+        if needs_repair(integrity, hull_integrity) {
+            commands.entity(entity).insert(Damaged);
+        } else {
+            commands.entity(entity).remove::<Damaged>();
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub use {
+    apply_projectile_damage::*, initialize_hull_integrity::*, initialize_integrity::*,
+    mark_damaged::*,
+};
+
+mod apply_projectile_damage;
+mod initialize_hull_integrity;
+mod initialize_integrity;
+mod mark_damaged;
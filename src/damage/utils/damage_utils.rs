@@ -0,0 +1,159 @@
+use crate::damage::constants::{
+    CHAIN_SHOT_HULL_DAMAGE, CHAIN_SHOT_SYSTEM_DAMAGE, CHAIN_SHOT_SYSTEM_RADIUS,
+    GRAPE_SHOT_SYSTEM_DAMAGE, GRAPE_SHOT_SYSTEM_RADIUS, REFERENCE_SHOT_SPEED,
+    ROUND_SHOT_BREACH_AREA, ROUND_SHOT_HULL_DAMAGE, ROUND_SHOT_SYSTEM_DAMAGE,
+    ROUND_SHOT_SYSTEM_RADIUS,
+};
+use crate::damage::{HullIntegrity, Integrity, BREACH_AREA_PER_REPAIR};
+use crate::flooding::Flooding;
+use crate::gunnery::ShotType;
+
+/// The damage done by a single projectile hit.
+///
+/// # Fields
+/// - `breach_area`: The area of the hole punched in the hull, in square metres.
+/// - `hull_damage`: The integrity lost by the hull section that was hit.
+/// - `system_damage`: The integrity lost by a ship system at the point of the hit.
+/// - `system_radius`: The distance in metres over which ship systems are damaged, falling
+///   off to nothing at its edge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HitEffect {
+    pub breach_area: f32,
+    pub hull_damage: f32,
+    pub system_damage: f32,
+    pub system_radius: f32,
+}
+
+/// Calculates the damage done by a projectile hit.
+///
+/// Round shot holes the hull, chain shot wrecks the rigging and grape shot does little damage
+/// to the ship itself. The damage grows with the energy of the shot, relative to shot striking
+/// at the reference speed.
+///
+/// # Arguments
+/// - `shot`: The type of shot that hit.
+/// - `speed`: The speed of the projectile when it hit, in metres per second.
+///
+/// # Returns
+/// The `HitEffect` of the hit.
+pub fn calculate_hit_effect(shot: ShotType, speed: f32) -> HitEffect {
+    let energy = (speed / REFERENCE_SHOT_SPEED).powi(2);
+
+    let (breach_area, hull_damage, system_damage, system_radius) = match shot {
+        ShotType::RoundShot => (
+            ROUND_SHOT_BREACH_AREA,
+            ROUND_SHOT_HULL_DAMAGE,
+            ROUND_SHOT_SYSTEM_DAMAGE,
+            ROUND_SHOT_SYSTEM_RADIUS,
+        ),
+        ShotType::ChainShot => (
+            0.0,
+            CHAIN_SHOT_HULL_DAMAGE,
+            CHAIN_SHOT_SYSTEM_DAMAGE,
+            CHAIN_SHOT_SYSTEM_RADIUS,
+        ),
+        ShotType::GrapeShot => (0.0, 0.0, GRAPE_SHOT_SYSTEM_DAMAGE, GRAPE_SHOT_SYSTEM_RADIUS),
+    };
+
+    HitEffect {
+        breach_area: breach_area * energy,
+        hull_damage: hull_damage * energy,
+        system_damage: system_damage * energy,
+        system_radius,
+    }
+}
+
+/// Calculates the damage a ship system takes from a hit some distance away.
+///
+/// # Arguments
+/// - `effect`: The damage done by the hit.
+/// - `distance`: The distance from the hit to the system, in metres.
+///
+/// # Returns
+/// The integrity lost by the system, falling off linearly to nothing at the effect's radius.
+pub fn calculate_system_damage(effect: &HitEffect, distance: f32) -> f32 {
+    if effect.system_radius <= 0.0 {
+        return 0.0;
+    }
+
+    effect.system_damage * (1.0 - distance / effect.system_radius).max(0.0)
+}
+
+/// Returns whether a ship system or hull needs repairing.
+///
+/// A ship's hull and a system such as its rudder can share an entity, so the entity needs
+/// repairing while either of them is damaged.
+///
+/// # Arguments
+/// - `integrity`: The `Integrity` of the entity, if it is a ship system.
+/// - `hull_integrity`: The `HullIntegrity` of the entity, if it is a hull.
+///
+/// # Returns
+/// `true` if the system or any section of the hull is damaged.
+pub fn needs_repair(integrity: Option<&Integrity>, hull_integrity: Option<&HullIntegrity>) -> bool {
+    integrity.is_some_and(|integrity| integrity.is_damaged())
+        || hull_integrity
+            .is_some_and(|hull_integrity| hull_integrity.most_damaged_section().is_some())
+}
+
+/// The outcome of a spell of repair work.
+///
+/// # Fields
+/// - `repaired`: The integrity actually restored, which falls short of the work put in once
+///   the last of the damage has been repaired.
+/// - `is_repaired`: Whether neither the system nor the hull needs repairing any more.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RepairProgress {
+    pub repaired: f32,
+    pub is_repaired: bool,
+}
+
+/// Repairs a ship system or hull by the given amount.
+///
+/// A holed hull is patched before any system sharing its entity, as it lets water in.
+/// Repairing a hull section plugs the holes in it, and a fully repaired section is made
+/// watertight again. Once the hull is sound, the system is repaired.
+///
+/// # Arguments
+/// - `integrity`: The `Integrity` of the entity, if it is a ship system.
+/// - `hull`: The `HullIntegrity` and `Flooding` of the entity, if it is a hull.
+/// - `amount`: The integrity to restore.
+///
+/// # Returns
+/// The `RepairProgress` made, with the integrity actually restored.
+pub fn repair_damage(
+    mut integrity: Option<&mut Integrity>,
+    mut hull: Option<(&mut HullIntegrity, &mut Flooding)>,
+    amount: f32,
+) -> RepairProgress {
+    let damaged_section = hull
+        .as_ref()
+        .and_then(|(hull_integrity, _)| hull_integrity.most_damaged_section());
+
+    let repaired = match (damaged_section, hull.as_mut()) {
+        (Some(section), Some((hull_integrity, flooding))) => {
+            let before = hull_integrity.sections[section];
+            let compartment = hull_integrity.compartment(section);
+            hull_integrity.repair(section, amount);
+            flooding.plug_breaches(compartment, amount * BREACH_AREA_PER_REPAIR);
+
+            if hull_integrity.sections[section] >= 1.0 {
+                flooding.plug_breaches(compartment, f32::INFINITY);
+            }
+            hull_integrity.sections[section] - before
+        }
+        _ => integrity.as_mut().map_or(0.0, |integrity| {
+            let before = integrity.level;
+            integrity.repair(amount);
+            integrity.level - before
+        }),
+    };
+
+    RepairProgress {
+        repaired,
+        is_repaired: !needs_repair(
+            integrity.as_deref(),
+            hull.as_ref().map(|(hull_integrity, _)| &**hull_integrity),
+        ),
+    }
+}
@@ -0,0 +1,3 @@
+pub use damage_utils::*;
+
+mod damage_utils;
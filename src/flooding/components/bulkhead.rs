@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A watertight bulkhead inside a hull.
+///
+/// The `Bulkhead` component is created from a `BulkheadMarker` and lives on a child of the
+/// hull it belongs to. When the hull's `Flooding` is set up, every connection between hull
+/// voxels that passes through the bulkhead's bounds is cut. If the bulkhead also has a `Door`,
+/// the cut connections are reopened whenever the door is open.
+///
+/// # Fields
+/// - `min`: The minimum corner of the bulkhead's bounds, in hull-local space.
+/// - `max`: The maximum corner of the bulkhead's bounds, in hull-local space.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Bulkhead {
+    pub min: Vec3,
+    pub max: Vec3,
+}
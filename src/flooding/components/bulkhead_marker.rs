@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A marker component used to designate a watertight bulkhead of a hull.
+///
+/// The `BulkheadMarker` component is read from the glTF file through `bevy_gltf_components`.
+/// The marked node's mesh outlines the bulkhead, and water cannot flow between hull voxels on
+/// either side of it. A `Door` authored alongside the marker lets the bulkhead be opened.
+///
+/// # Usages
+/// - Adding the component to a node with a mesh marks the mesh's bounds as a bulkhead.
+/// - Used by the flooding systems to cut the connections between hull voxels.
+#[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct BulkheadMarker;
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A named, watertight compartment of a hull.
+///
+/// The `Compartment` component is created from a `CompartmentMarker` and lives on a child of
+/// the hull it belongs to. When the hull's `Flooding` is set up, every hull voxel inside the
+/// compartment's bounds is assigned to it.
+///
+/// # Fields
+/// - `name`: The name of the compartment, such as "Hold" or "Magazine".
+/// - `min`: The minimum corner of the compartment's bounds, in hull-local space.
+/// - `max`: The maximum corner of the compartment's bounds, in hull-local space.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Compartment {
+    pub name: String,
+    pub min: Vec3,
+    pub max: Vec3,
+}
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A marker component used to designate a watertight compartment of a hull.
+///
+/// The `CompartmentMarker` component is read from the glTF file through `bevy_gltf_components`.
+/// The marked node's mesh outlines the compartment, and its `Name` names it. The
+/// `read_flooding_markers` system turns the marker into a `Compartment` of the hull it belongs to.
+///
+/// # Usages
+/// - Adding the component to a node with a mesh marks the mesh's bounds as a compartment.
+/// - Used by the flooding systems to group hull voxels into named compartments.
+#[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct CompartmentMarker;
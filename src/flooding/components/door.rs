@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A door through a bulkhead.
+///
+/// The `Door` component can be authored in the glTF file alongside a `BulkheadMarker`. While
+/// the door is open, water flows through the bulkhead as if it were not there. Changing
+/// `is_open` updates the flooding of the hull on the next frame.
+///
+/// # Fields
+/// - `is_open`: Whether the door is open.
+#[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Door {
+    pub is_open: bool,
+}
@@ -0,0 +1,299 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::buoyancy_physics::Voxel;
+use crate::flooding::utils::{
+    find_nearest_solid_voxel, find_voxel_connections, segment_intersects_bounds,
+};
+
+/// Tracks the water inside a buoyant hull.
+///
+/// The `Flooding` component treats the voxels of a `Buoyancy` component as a compartment
+/// model, with one entry per voxel in the same order. Water enters the hull through breached
+/// voxels, spreads between connected solid voxels and adds weight to the hull as it fills,
+/// so a damaged ship lists, settles and eventually sinks. Bulkheads cut the connections
+/// between voxels, dividing the hull into named compartments that flood separately, and
+/// doors in bulkheads reconnect them while open.
+///
+/// # Fields
+/// - `fill_levels`: How full of water each voxel is, from `0.0` (dry) to `1.0` (flooded).
+/// - `breach_areas`: The area of the hole in each voxel's hull, in square metres. `0.0` is intact.
+/// - `connections`: The indices of the solid voxels each voxel is connected to, which water
+///   can flow between.
+/// - `compartments`: The names of the hull's compartments.
+/// - `voxel_compartments`: The index of the compartment each voxel belongs to, if any.
+/// - `doors`: The doors through the hull's bulkheads and the connections they control.
+///
+/// # Methods
+/// - `from_voxels(voxels: &[Voxel], voxel_size: f32) -> Self`:
+///   Creates a dry, intact `Flooding` with every neighbouring solid voxel connected.
+/// - `breach(&mut self, voxel: usize, area: f32)`:
+///   Opens a hole of the given area in a voxel, adding to any existing hole.
+/// - `flooded_volume(&self, voxel_size: f32) -> f32`:
+///   Returns the total volume of water inside the hull.
+/// - `add_compartment(&mut self, name: &str, voxels: &[Voxel], min: Vec3, max: Vec3)`:
+///   Assigns the solid voxels within the bounds to a new named compartment.
+/// - `add_bulkhead(&mut self, voxels: &[Voxel], min: Vec3, max: Vec3, door: Option<(Entity, bool)>)`:
+///   Cuts the connections passing through the bounds, optionally behind a door.
+/// - `set_door_open(&mut self, bulkhead: Entity, is_open: bool)`:
+///   Opens or closes the door through a bulkhead.
+/// - `compartment_fill_level(&self, name: &str) -> Option<f32>`:
+///   Returns how full of water a compartment is.
+/// - `fill_level(&self, compartment: Option<&str>, voxels: &[Voxel]) -> Option<f32>`:
+///   Returns how full of water a compartment, or the whole hull, is.
+/// - `pump_out(&mut self, compartment: Option<&str>, volume: f32, voxel_size: f32) -> f32`:
+///   Removes water from a compartment, or the whole hull, returning the volume removed.
+/// - `plug_breaches(&mut self, compartment: Option<usize>, area: f32)`:
+///   Shrinks the holes in the voxels of a compartment, or of the voxels outside every compartment.
+/// - `compartment_at(&self, voxels: &[Voxel], position: Vec3) -> Option<usize>`:
+///   Returns the compartment of the solid voxel nearest to a position.
+/// - `compartment_centre(&self, compartment: usize, voxels: &[Voxel]) -> Option<Vec3>`:
+///   Returns the centre of a compartment's voxels.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Flooding {
+    pub fill_levels: Vec<f32>,
+    pub breach_areas: Vec<f32>,
+    pub connections: Vec<Vec<usize>>,
+    pub compartments: Vec<String>,
+    pub voxel_compartments: Vec<Option<usize>>,
+    pub doors: Vec<FloodDoor>,
+}
+
+/// A door through a bulkhead, and the voxel connections it opens.
+///
+/// # Fields
+/// - `bulkhead`: The bulkhead entity the door belongs to.
+/// - `connections`: The pairs of voxels connected through the door while it is open.
+/// - `is_open`: Whether the door is open.
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+pub struct FloodDoor {
+    pub bulkhead: Entity,
+    pub connections: Vec<(usize, usize)>,
+    pub is_open: bool,
+}
+
+impl Flooding {
+    /// Creates a new `Flooding` component for the given voxels with no water and no breaches.
+    pub fn from_voxels(voxels: &[Voxel], voxel_size: f32) -> Self {
+        Self {
+            fill_levels: vec![0.0; voxels.len()],
+            breach_areas: vec![0.0; voxels.len()],
+            connections: find_voxel_connections(voxels, voxel_size),
+            compartments: Vec::new(),
+            voxel_compartments: vec![None; voxels.len()],
+            doors: Vec::new(),
+        }
+    }
+
+    /// Opens a hole of the given area in the hull at a voxel.
+    pub fn breach(&mut self, voxel: usize, area: f32) {
+        if let Some(breach_area) = self.breach_areas.get_mut(voxel) {
+            *breach_area += area.max(0.0);
+        }
+    }
+
+    /// Returns the total volume of water inside the hull.
+    pub fn flooded_volume(&self, voxel_size: f32) -> f32 {
+        self.fill_levels.iter().sum::<f32>() * voxel_size.powi(3)
+    }
+
+    /// Assigns every solid voxel whose centre lies within the bounds to a new compartment.
+    pub fn add_compartment(&mut self, name: &str, voxels: &[Voxel], min: Vec3, max: Vec3) {
+        let compartment = self.compartments.len();
+        self.compartments.push(name.to_string());
+
+        for (voxel, voxel_compartment) in voxels.iter().zip(self.voxel_compartments.iter_mut()) {
+            let inside = voxel.position.cmpge(min).all() && voxel.position.cmple(max).all();
+            if voxel.is_solid && inside {
+                *voxel_compartment = Some(compartment);
+            }
+        }
+    }
+
+    /// Cuts every connection between voxels that passes through the bounds of a bulkhead.
+    ///
+    /// If the bulkhead has a door, the cut connections are kept with the door so they can be
+    /// reopened, and the door starts open or closed as given.
+    pub fn add_bulkhead(
+        &mut self,
+        voxels: &[Voxel],
+        min: Vec3,
+        max: Vec3,
+        door: Option<(Entity, bool)>,
+    ) {
+        let mut cut_connections = Vec::new();
+
+        for (index, connections) in self.connections.iter_mut().enumerate() {
+            connections.retain(|&neighbour| {
+                let crosses = segment_intersects_bounds(
+                    voxels[index].position,
+                    voxels[neighbour].position,
+                    min,
+                    max,
+                );
+                if crosses && index < neighbour {
+                    cut_connections.push((index, neighbour));
+                }
+                !crosses
+            });
+        }
+
+        if let Some((bulkhead, is_open)) = door {
+            self.doors.push(FloodDoor {
+                bulkhead,
+                connections: cut_connections,
+                is_open: false,
+            });
+            self.set_door_open(bulkhead, is_open);
+        }
+    }
+
+    /// Opens or closes the door through a bulkhead, reconnecting or cutting the voxels behind it.
+    pub fn set_door_open(&mut self, bulkhead: Entity, is_open: bool) {
+        let Some(door) = self.doors.iter_mut().find(|door| door.bulkhead == bulkhead) else {
+            return;
+        };
+
+        if door.is_open == is_open {
+            return;
+        }
+        door.is_open = is_open;
+
+        for &(a, b) in &door.connections {
+            if is_open {
+                self.connections[a].push(b);
+                self.connections[b].push(a);
+            } else {
+                self.connections[a].retain(|&neighbour| neighbour != b);
+                self.connections[b].retain(|&neighbour| neighbour != a);
+            }
+        }
+    }
+
+    /// Returns how full of water a compartment is, from `0.0` (dry) to `1.0` (flooded).
+    pub fn compartment_fill_level(&self, name: &str) -> Option<f32> {
+        let compartment = self.compartments.iter().position(|other| other == name)?;
+
+        let fill_levels: Vec<f32> = self
+            .voxel_compartments
+            .iter()
+            .zip(self.fill_levels.iter())
+            .filter(|(voxel_compartment, _)| **voxel_compartment == Some(compartment))
+            .map(|(_, fill)| *fill)
+            .collect();
+
+        if fill_levels.is_empty() {
+            return Some(0.0);
+        }
+
+        Some(fill_levels.iter().sum::<f32>() / fill_levels.len() as f32)
+    }
+
+    /// Returns how full of water a compartment is, or the whole hull if no compartment is given.
+    ///
+    /// The whole hull's fill level is averaged over its solid voxels, which are the only ones
+    /// that can hold water.
+    pub fn fill_level(&self, compartment: Option<&str>, voxels: &[Voxel]) -> Option<f32> {
+        if let Some(name) = compartment {
+            return self.compartment_fill_level(name);
+        }
+
+        let solid_voxels = voxels.iter().filter(|voxel| voxel.is_solid).count();
+        if solid_voxels == 0 {
+            return Some(0.0);
+        }
+
+        Some(self.fill_levels.iter().sum::<f32>() / solid_voxels as f32)
+    }
+
+    /// Removes up to the given volume of water from a compartment, or from the whole hull if
+    /// no compartment is given.
+    ///
+    /// Every wet voxel is drained in proportion to the water it holds. Returns the volume of
+    /// water actually removed, which is less than requested once the compartment runs dry.
+    pub fn pump_out(&mut self, compartment: Option<&str>, volume: f32, voxel_size: f32) -> f32 {
+        let compartment = match compartment {
+            Some(name) => match self.compartments.iter().position(|other| other == name) {
+                Some(index) => Some(index),
+                None => return 0.0,
+            },
+            None => None,
+        };
+
+        let is_pumped = |voxel_compartment: &Option<usize>| {
+            compartment.is_none() || *voxel_compartment == compartment
+        };
+
+        let voxel_volume = voxel_size.powi(3);
+        let water_volume = self
+            .voxel_compartments
+            .iter()
+            .zip(self.fill_levels.iter())
+            .filter(|(voxel_compartment, _)| is_pumped(voxel_compartment))
+            .map(|(_, fill)| fill * voxel_volume)
+            .sum::<f32>();
+
+        if water_volume <= 0.0 {
+            return 0.0;
+        }
+
+        let removed = volume.clamp(0.0, water_volume);
+        let remaining = 1.0 - removed / water_volume;
+
+        for (voxel_compartment, fill) in self
+            .voxel_compartments
+            .iter()
+            .zip(self.fill_levels.iter_mut())
+        {
+            if is_pumped(voxel_compartment) {
+                *fill *= remaining;
+            }
+        }
+
+        removed
+    }
+
+    /// Shrinks every hole in the voxels of a compartment by the given area.
+    ///
+    /// Unlike `pump_out`, `None` selects only the voxels outside every compartment, so each
+    /// part of the hull can be patched up separately.
+    pub fn plug_breaches(&mut self, compartment: Option<usize>, area: f32) {
+        for (voxel_compartment, breach_area) in self
+            .voxel_compartments
+            .iter()
+            .zip(self.breach_areas.iter_mut())
+        {
+            if *voxel_compartment == compartment {
+                *breach_area = (*breach_area - area.max(0.0)).max(0.0);
+            }
+        }
+    }
+
+    /// Returns the compartment the solid voxel nearest to a position belongs to.
+    ///
+    /// The position is in the hull's local space, like the voxels. Returns `None` if the
+    /// nearest voxel lies outside every compartment, or the hull has no solid voxels.
+    pub fn compartment_at(&self, voxels: &[Voxel], position: Vec3) -> Option<usize> {
+        let voxel = find_nearest_solid_voxel(voxels, position)?;
+        self.voxel_compartments.get(voxel).copied().flatten()
+    }
+
+    /// Returns the centre of the voxels that make up a compartment, in the hull's local space.
+    ///
+    /// Returns `None` if the compartment has no voxels.
+    pub fn compartment_centre(&self, compartment: usize, voxels: &[Voxel]) -> Option<Vec3> {
+        let positions: Vec<Vec3> = voxels
+            .iter()
+            .zip(self.voxel_compartments.iter())
+            .filter(|(_, voxel_compartment)| **voxel_compartment == Some(compartment))
+            .map(|(voxel, _)| voxel.position)
+            .collect();
+
+        if positions.is_empty() {
+            return None;
+        }
+
+        Some(positions.iter().sum::<Vec3>() / positions.len() as f32)
+    }
+}
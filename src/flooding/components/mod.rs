@@ -0,0 +1,10 @@
+pub use {
+    bulkhead::*, bulkhead_marker::*, compartment::*, compartment_marker::*, door::*, flooding::*,
+};
+
+mod bulkhead;
+mod bulkhead_marker;
+mod compartment;
+mod compartment_marker;
+mod door;
+mod flooding;
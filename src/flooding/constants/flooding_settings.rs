@@ -0,0 +1,7 @@
+/// The discharge coefficient of a hole in the hull, accounting for the flow narrowing as it
+/// passes through a sharp-edged opening.
+pub const BREACH_DISCHARGE_COEFFICIENT: f32 = 0.6;
+
+/// How quickly water spreads between connected voxels, in cubic metres per second for each
+/// metre of difference in water level across a square metre of opening.
+pub const FLOOD_SPREAD_RATE: f32 = 1.0;
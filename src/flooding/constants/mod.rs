@@ -0,0 +1,3 @@
+pub use flooding_settings::*;
+
+mod flooding_settings;
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+/// Event to open a hole in the hull of a buoyant body.
+///
+/// This event is sent by anything that damages a hull, such as a cannon ball striking a ship.
+/// It is processed by the `apply_hull_breaches` system, which breaches the solid voxel nearest
+/// to the given position so water can start flooding in.
+///
+/// # Fields
+/// - `entity`: The buoyant body that was damaged.
+/// - `position`: The world position of the damage.
+/// - `area`: The area of the hole in square metres.
+#[derive(Event, Debug, Clone)]
+pub struct HullBreached {
+    pub entity: Entity,
+    pub position: Vec3,
+    pub area: f32,
+}
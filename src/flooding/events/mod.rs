@@ -0,0 +1,3 @@
+pub use hull_breached::*;
+
+mod hull_breached;
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use events::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod events;
+mod systems;
+mod utils;
+
+/// Plugin for hull breaches and progressive flooding.
+///
+/// The `FloodingPlugin` uses the voxels of each buoyant hull as a compartment model. Damage
+/// reported through `HullBreached` events opens holes in the hull, water pours in through
+/// them and spreads to connected voxels over time, and the weight of the water drags the
+/// hull down. A holed ship lists towards the flooded side, settles lower in the water and
+/// eventually sinks. Compartments and bulkheads authored in the glTF file divide the hull into
+/// watertight sections, with doors that can be opened or closed to control the flooding.
+///
+/// # Components
+/// - `Bulkhead`: A watertight bulkhead inside a hull.
+/// - `BulkheadMarker`: Marks a glTF node as a bulkhead.
+/// - `Compartment`: A named, watertight compartment of a hull.
+/// - `CompartmentMarker`: Marks a glTF node as a compartment.
+/// - `Door`: A door through a bulkhead.
+/// - `Flooding`: Tracks the water, breaches and connections between the voxels of a hull.
+///
+/// # Events
+/// - `HullBreached`: Opens a hole in the hull of a buoyant body.
+///
+/// # Systems
+/// - `apply_flood_water_weight`: Applies the weight of the water inside flooded hulls.
+/// - `apply_hull_breaches`: Breaches the hull voxels nearest to reported damage.
+/// - `initialize_flooding`: Prepares buoyant hulls for flooding once their voxels are known.
+/// - `read_flooding_markers`: Turns compartment and bulkhead markers into compartments and bulkheads.
+/// - `update_bulkhead_doors`: Opens and closes bulkhead doors in the flooding model.
+/// - `update_flooding`: Lets water flow into breached hulls and spread through them.
+///
+/// This plugin is added to the app during the application setup and is configured to
+/// operate during the `AppStates::Running` state.
+pub struct FloodingPlugin;
+
+impl Plugin for FloodingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Bulkhead>()
+            .register_type::<BulkheadMarker>()
+            .register_type::<Compartment>()
+            .register_type::<CompartmentMarker>()
+            .register_type::<Door>()
+            .register_type::<Flooding>()
+            .add_event::<HullBreached>()
+            .add_systems(
+                Update,
+                apply_flood_water_weight.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                apply_hull_breaches.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                initialize_flooding.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                read_flooding_markers
+                    .before(initialize_flooding)
+                    .run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                update_bulkhead_doors.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                update_flooding
+                    .after(apply_hull_breaches)
+                    .run_if(in_state(AppStates::Running)),
+            );
+    }
+}
@@ -0,0 +1,43 @@
+use bevy::math::Vec3;
+use bevy::prelude::{Query, Transform};
+use bevy_xpbd_3d::components::{CenterOfMass, ExternalForce};
+
+use crate::buoyancy_physics::{Buoyancy, GRAVITY, WATER_DENSITY};
+use crate::flooding::Flooding;
+
+/// Applies the weight of the water inside flooded hulls.
+///
+/// The water in each voxel pulls the hull down at the centre of the water it holds, which
+/// cancels out the buoyancy of the flooded volume. As water gathers on the low side of a
+/// hull, its weight makes the hull list further, and a hull that takes on enough water sinks.
+///
+/// # Parameters
+/// - `query`: Query to retrieve the voxels, flooding state, transform, centre of mass and
+///   external force of hulls.
+pub fn apply_flood_water_weight(
+    mut query: Query<(
+        &Buoyancy,
+        &Flooding,
+        &Transform,
+        &CenterOfMass,
+        &mut ExternalForce,
+    )>,
+) {
+    for (buoyancy, flooding, transform, center_of_mass, mut external_force) in query.iter_mut() {
+        let voxel_size = buoyancy.voxel_size;
+        let voxel_volume = voxel_size.powi(3);
+        let world_center_of_mass = transform.translation + transform.rotation * center_of_mass.0;
+
+        for (voxel, &fill) in buoyancy.voxels.iter().zip(flooding.fill_levels.iter()) {
+            if fill <= 0.0 {
+                continue;
+            }
+
+            let world_position = transform.translation + transform.rotation * voxel.position;
+            let water_center = world_position - Vec3::Y * (1.0 - fill) * voxel_size / 2.0;
+            let weight = Vec3::NEG_Y * GRAVITY * WATER_DENSITY * fill * voxel_volume;
+
+            external_force.apply_force_at_point(weight, water_center, world_center_of_mass);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::log::warn;
+use bevy::prelude::{EventReader, Query, Transform};
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::flooding::utils::find_nearest_solid_voxel;
+use crate::flooding::{Flooding, HullBreached};
+
+/// Opens holes in hulls in response to `HullBreached` events.
+///
+/// For each event, the damage position is converted into the hull's local space and the
+/// nearest solid voxel is breached with the area of the hole, letting water flood in.
+///
+/// # Parameters
+/// - `hull_breached_events`: Event reader for `HullBreached` events.
+/// - `query`: Query to retrieve the voxels, transform and flooding state of damaged hulls.
+pub fn apply_hull_breaches(
+    mut hull_breached_events: EventReader<HullBreached>,
+    mut query: Query<(&Buoyancy, &Transform, &mut Flooding)>,
+) {
+    for event in hull_breached_events.read() {
+        let Ok((buoyancy, transform, mut flooding)) = query.get_mut(event.entity) else {
+            warn!(
+                "Unable to breach {:?}, it has no flooding hull.",
+                event.entity
+            );
+            continue;
+        };
+
+        let local_position =
+            transform.rotation.inverse() * (event.position - transform.translation);
+        if let Some(voxel) = find_nearest_solid_voxel(&buoyancy.voxels, local_position) {
+            flooding.breach(voxel, event.area);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::{Added, Commands, DetectChanges, Entity, Parent, Query, Ref};
+use bevy::utils::HashSet;
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::flooding::{Bulkhead, Compartment, Door, Flooding};
+
+/// Prepares buoyant hulls for flooding once their voxels are known.
+///
+/// Whenever the voxels of a `Buoyancy` component change and their solidity is up to date,
+/// this system inserts a dry, intact `Flooding` component with the connections between the
+/// hull's solid voxels. The hull's compartments are then assigned their voxels, and its
+/// bulkheads cut the connections passing through them, behind a door if they have one.
+///
+/// Compartments and bulkheads whose meshes load after the hull has been voxelized are added
+/// to a hull later, so the hull's `Flooding` is set up again whenever one of them arrives. The
+/// water and holes already in the hull are carried over to the new `Flooding`, as long as its
+/// voxels are the same.
+///
+/// # Parameters
+/// - `commands`: Commands for inserting the `Flooding` component.
+/// - `query`: Query to retrieve buoyant bodies, whether their `Buoyancy` has changed and any
+///   `Flooding` they already have.
+/// - `compartment_query`: Query to retrieve compartments and the hulls they belong to.
+/// - `bulkhead_query`: Query to retrieve bulkheads, their doors and the hulls they belong to.
+/// - `added_compartments`: Query to find hulls that have gained a compartment.
+/// - `added_bulkheads`: Query to find hulls that have gained a bulkhead.
+pub fn initialize_flooding(
+    mut commands: Commands,
+    query: Query<(Entity, Ref<Buoyancy>, Option<&Flooding>)>,
+    compartment_query: Query<(&Parent, &Compartment)>,
+    bulkhead_query: Query<(Entity, &Parent, &Bulkhead, Option<&Door>)>,
+    added_compartments: Query<&Parent, Added<Compartment>>,
+    added_bulkheads: Query<&Parent, Added<Bulkhead>>,
+) {
+    let divided_hulls: HashSet<Entity> = added_compartments
+        .iter()
+        .chain(added_bulkheads.iter())
+        .map(Parent::get)
+        .collect();
+
+    for (entity, buoyancy, existing) in query.iter() {
+        if buoyancy.needs_update || !(buoyancy.is_changed() || divided_hulls.contains(&entity)) {
+            continue;
+        }
+
+        let mut flooding = Flooding::from_voxels(&buoyancy.voxels, buoyancy.voxel_size);
+        if let Some(existing) = existing.filter(|existing| {
+            existing.fill_levels.len() == buoyancy.voxels.len()
+                && existing.breach_areas.len() == buoyancy.voxels.len()
+        }) {
+            flooding.fill_levels.clone_from(&existing.fill_levels);
+            flooding.breach_areas.clone_from(&existing.breach_areas);
+        }
+
+        for (parent, compartment) in compartment_query.iter() {
+            if parent.get() == entity {
+                flooding.add_compartment(
+                    &compartment.name,
+                    &buoyancy.voxels,
+                    compartment.min,
+                    compartment.max,
+                );
+            }
+        }
+
+        for (bulkhead_entity, parent, bulkhead, door) in bulkhead_query.iter() {
+            if parent.get() == entity {
+                flooding.add_bulkhead(
+                    &buoyancy.voxels,
+                    bulkhead.min,
+                    bulkhead.max,
+                    door.map(|door| (bulkhead_entity, door.is_open)),
+                );
+            }
+        }
+
+        commands.entity(entity).insert(flooding);
+    }
+}
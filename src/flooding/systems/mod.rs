@@ -0,0 +1,11 @@
+pub use {
+    apply_flood_water_weight::*, apply_hull_breaches::*, initialize_flooding::*,
+    read_flooding_markers::*, update_bulkhead_doors::*, update_flooding::*,
+};
+
+mod apply_flood_water_weight;
+mod apply_hull_breaches;
+mod initialize_flooding;
+mod read_flooding_markers;
+mod update_bulkhead_doors;
+mod update_flooding;
@@ -0,0 +1,99 @@
+use bevy::asset::{Assets, Handle};
+use bevy::hierarchy::{BuildChildren, Children, Parent};
+use bevy::log::{debug, error};
+use bevy::prelude::*;
+
+use crate::flooding::{Bulkhead, BulkheadMarker, Compartment, CompartmentMarker};
+use crate::utils::{calculate_transformed_mesh_bounds, find_mesh, find_top_level_entity};
+
+/// System to process compartment and bulkhead markers authored in glTF files.
+///
+/// This system handles the `CompartmentMarker` and `BulkheadMarker` components, measuring
+/// the bounds of each marked mesh in hull-local space, including the rotation and scale of
+/// the marker's transform, and turning the markers into
+/// `Compartment` and `Bulkhead` components. The markers are re-parented to the top-level
+/// entity of their hierarchy, which is the hull they divide up, and hidden.
+///
+/// # Parameters
+/// - `compartment_marker_query`: Query to retrieve unprocessed entities with `CompartmentMarker`
+///   components, their transforms and names.
+/// - `bulkhead_marker_query`: Query to retrieve unprocessed entities with `BulkheadMarker` components
+///   and their transforms.
+/// - `commands`: Commands for modifying entities and their components.
+/// - `children`: Query to retrieve the children of entities.
+/// - `meshes`: Resource containing the assets of meshes.
+/// - `mesh_handles`: Query to retrieve mesh handles from entities.
+/// - `parent_query`: Query to navigate up the hierarchy to find the hull entity.
+///
+/// # Details
+/// Compartments are named after the `Name` of the marked node. A marker whose mesh has not
+/// finished loading is left as it is and tried again on the next frame, until its mesh is
+/// available. The system runs before `initialize_flooding`, which also picks up compartments
+/// and bulkheads that arrive after the hull's `Flooding` has been set up.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn read_flooding_markers(
+    compartment_marker_query: Query<
+        (Entity, &Transform, Option<&Name>),
+        (With<CompartmentMarker>, Without<Compartment>),
+    >,
+    bulkhead_marker_query: Query<(Entity, &Transform), (With<BulkheadMarker>, Without<Bulkhead>)>,
+    mut commands: Commands,
+    children: Query<&Children>,
+    meshes: Res<Assets<Mesh>>,
+    mesh_handles: Query<&Handle<Mesh>>,
+    parent_query: Query<&Parent>,
+) {
+    let find_bounds = |entity: Entity, transform: &Transform| -> Option<(Vec3, Vec3)> {
+        let mesh_handle = mesh_handles
+            .get(entity)
+            .ok()
+            .cloned()
+            .or_else(|| find_mesh(entity, &children, &mesh_handles))?;
+        Some(calculate_transformed_mesh_bounds(
+            meshes.get(&mesh_handle)?,
+            transform,
+        ))
+    };
+
+    let find_hull = |entity: Entity| -> Option<Entity> {
+        let parent = parent_query.get(entity).ok()?;
+        Some(find_top_level_entity(parent.get(), &parent_query))
+    };
+
+    for (entity, transform, name) in compartment_marker_query.iter() {
+        let Some((min, max)) = find_bounds(entity, transform) else {
+            debug!("Waiting for the mesh of compartment marker {:?}", entity);
+            continue;
+        };
+        let Some(hull) = find_hull(entity) else {
+            error!("No hull entity found for the compartment marker");
+            continue;
+        };
+
+        commands.entity(hull).add_child(entity);
+        commands.entity(entity).insert((
+            Compartment {
+                name: name.map_or_else(|| "Compartment".to_string(), |name| name.to_string()),
+                min,
+                max,
+            },
+            Visibility::Hidden,
+        ));
+    }
+
+    for (entity, transform) in bulkhead_marker_query.iter() {
+        let Some((min, max)) = find_bounds(entity, transform) else {
+            debug!("Waiting for the mesh of bulkhead marker {:?}", entity);
+            continue;
+        };
+        let Some(hull) = find_hull(entity) else {
+            error!("No hull entity found for the bulkhead marker");
+            continue;
+        };
+
+        commands.entity(hull).add_child(entity);
+        commands
+            .entity(entity)
+            .insert((Bulkhead { min, max }, Visibility::Hidden));
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::{Changed, Entity, Parent, Query};
+
+use crate::flooding::{Door, Flooding};
+
+/// Opens and closes bulkhead doors in the flooding model of their hull.
+///
+/// Whenever a `Door` changes, the connections between the hull voxels on either side of
+/// its bulkhead are reopened or cut to match.
+///
+/// # Parameters
+/// - `door_query`: Query to retrieve doors that have changed and the hulls they belong to.
+/// - `flooding_query`: Query to retrieve the flooding state of hulls.
+pub fn update_bulkhead_doors(
+    door_query: Query<(Entity, &Parent, &Door), Changed<Door>>,
+    mut flooding_query: Query<&mut Flooding>,
+) {
+    for (bulkhead, parent, door) in door_query.iter() {
+        if let Ok(mut flooding) = flooding_query.get_mut(parent.get()) {
+            flooding.set_door_open(bulkhead, door.is_open);
+        }
+    }
+}
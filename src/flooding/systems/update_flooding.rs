@@ -0,0 +1,39 @@
+use bevy::prelude::{Query, Res, Time, Transform};
+use bevy_water::WaterParam;
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::flooding::utils::simulate_flooding;
+use crate::flooding::Flooding;
+use crate::utils::get_water_height_at_position;
+
+/// Lets water flow into breached hulls and spread through them over time.
+///
+/// Hulls without any breaches or water inside are skipped. For the rest, the flooding is
+/// advanced by the time since the last frame using the height of the waves outside each breach.
+///
+/// # Parameters
+/// - `time`: Resource providing the time elapsed since the last frame.
+/// - `water`: A parameter used to sample the height of the waves.
+/// - `query`: Query to retrieve the voxels, transform and flooding state of hulls.
+pub fn update_flooding(
+    time: Res<Time>,
+    water: WaterParam,
+    mut query: Query<(&Buoyancy, &Transform, &mut Flooding)>,
+) {
+    for (buoyancy, transform, mut flooding) in query.iter_mut() {
+        let is_breached = flooding.breach_areas.iter().any(|area| *area > 0.0);
+        let is_flooded = flooding.fill_levels.iter().any(|fill| *fill > 0.0);
+        if !is_breached && !is_flooded {
+            continue;
+        }
+
+        simulate_flooding(
+            &mut flooding,
+            &buoyancy.voxels,
+            buoyancy.voxel_size,
+            transform,
+            |position| get_water_height_at_position(position, &water),
+            time.delta_seconds(),
+        );
+    }
+}
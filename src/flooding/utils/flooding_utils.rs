@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+
+use bevy::math::{IVec3, Vec3};
+use bevy::prelude::Transform;
+
+use crate::buoyancy_physics::{Voxel, GRAVITY};
+use crate::flooding::constants::{BREACH_DISCHARGE_COEFFICIENT, FLOOD_SPREAD_RATE};
+use crate::flooding::Flooding;
+
+/// The grid offsets of the six neighbours of a voxel.
+const NEIGHBOUR_OFFSETS: [IVec3; 6] = [
+    IVec3::X,
+    IVec3::NEG_X,
+    IVec3::Y,
+    IVec3::NEG_Y,
+    IVec3::Z,
+    IVec3::NEG_Z,
+];
+
+/// Finds the solid neighbours of each solid voxel, which water can flow between.
+///
+/// # Arguments
+///
+/// * `voxels` - The voxels of the hull, laid out on a regular grid.
+/// * `voxel_size` - The spacing of the voxel grid.
+///
+/// # Returns
+///
+/// The indices of the connected voxels for each voxel, in the same order as `voxels`.
+/// Non-solid voxels have no connections.
+pub fn find_voxel_connections(voxels: &[Voxel], voxel_size: f32) -> Vec<Vec<usize>> {
+    let Some(origin) = voxels.first().map(|voxel| voxel.position) else {
+        return Vec::new();
+    };
+    let grid_cell = |position: Vec3| ((position - origin) / voxel_size).round().as_ivec3();
+
+    let solid_cells: HashMap<IVec3, usize> = voxels
+        .iter()
+        .enumerate()
+        .filter(|(_, voxel)| voxel.is_solid)
+        .map(|(index, voxel)| (grid_cell(voxel.position), index))
+        .collect();
+
+    voxels
+        .iter()
+        .map(|voxel| {
+            if !voxel.is_solid {
+                return Vec::new();
+            }
+
+            let cell = grid_cell(voxel.position);
+            NEIGHBOUR_OFFSETS
+                .iter()
+                .filter_map(|offset| solid_cells.get(&(cell + *offset)).copied())
+                .collect()
+        })
+        .collect()
+}
+
+/// Determines whether the line segment between two points passes through a box.
+///
+/// # Arguments
+///
+/// * `start` - The start of the segment.
+/// * `end` - The end of the segment.
+/// * `min` - The minimum corner of the box.
+/// * `max` - The maximum corner of the box.
+///
+/// # Returns
+///
+/// `true` if any part of the segment lies within the box, `false` otherwise.
+pub fn segment_intersects_bounds(start: Vec3, end: Vec3, min: Vec3, max: Vec3) -> bool {
+    let direction = end - start;
+    let (mut entry, mut exit) = (0.0_f32, 1.0_f32);
+
+    for axis in 0..3 {
+        if direction[axis].abs() < f32::EPSILON {
+            if start[axis] < min[axis] || start[axis] > max[axis] {
+                return false;
+            }
+            continue;
+        }
+
+        let near = (min[axis] - start[axis]) / direction[axis];
+        let far = (max[axis] - start[axis]) / direction[axis];
+        entry = entry.max(near.min(far));
+        exit = exit.min(near.max(far));
+
+        if entry > exit {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Finds the solid voxel closest to a position.
+///
+/// # Arguments
+///
+/// * `voxels` - The voxels of the hull.
+/// * `position` - The position to search from, in the same space as the voxels.
+///
+/// # Returns
+///
+/// The index of the nearest solid voxel, or `None` if there are no solid voxels.
+pub fn find_nearest_solid_voxel(voxels: &[Voxel], position: Vec3) -> Option<usize> {
+    voxels
+        .iter()
+        .enumerate()
+        .filter(|(_, voxel)| voxel.is_solid)
+        .min_by(|(_, a), (_, b)| {
+            a.position
+                .distance_squared(position)
+                .total_cmp(&b.position.distance_squared(position))
+        })
+        .map(|(index, _)| index)
+}
+
+/// Calculates the volume of water passing through a hole in the hull.
+///
+/// The flow follows Torricelli's law for an orifice, driven by the difference between the
+/// water level outside the hull and the water level inside it.
+///
+/// # Arguments
+///
+/// * `area` - The area of the hole in square metres.
+/// * `head` - The outside water level minus the inside water level, in metres.
+/// * `delta_seconds` - The time the water flows for.
+///
+/// # Returns
+///
+/// The volume of water flowing in, or a negative volume if water is draining out.
+pub fn calculate_breach_flow(area: f32, head: f32, delta_seconds: f32) -> f32 {
+    let speed = (2.0 * GRAVITY * head.abs()).sqrt();
+    head.signum() * BREACH_DISCHARGE_COEFFICIENT * area * speed * delta_seconds
+}
+
+/// Advances the flooding of a hull by one step.
+///
+/// First water flows in or out through each breach according to the difference between the
+/// sea and the water surface inside the breached voxel. Water that does not fit in a flooded
+/// voxel overflows into the lowest connected voxels with room. Then the water level in every
+/// voxel is worked out in world space, with flooded voxels passing on the pressure of the
+/// water above them, or of the sea outside a breach, to the flooded voxels they connect to.
+/// Water spreads between connected voxels from the higher level to the lower one, so it can
+/// be pushed up into dry voxels as well as fall into lower ones. Because levels are measured
+/// in world space, water runs to the low side of a listing hull.
+///
+/// # Arguments
+///
+/// * `flooding` - The flooding state of the hull to update.
+/// * `voxels` - The voxels of the hull, in body-local space.
+/// * `voxel_size` - The size of each voxel.
+/// * `transform` - The transform of the hull in the game world.
+/// * `water_height_at` - Returns the height of the sea surface at a world position.
+/// * `delta_seconds` - The time to advance the flooding by.
+pub fn simulate_flooding(
+    flooding: &mut Flooding,
+    voxels: &[Voxel],
+    voxel_size: f32,
+    transform: &Transform,
+    water_height_at: impl Fn(Vec3) -> f32,
+    delta_seconds: f32,
+) {
+    if flooding.fill_levels.len() != voxels.len()
+        || flooding.breach_areas.len() != voxels.len()
+        || flooding.connections.len() != voxels.len()
+    {
+        return;
+    }
+
+    let voxel_volume = voxel_size.powi(3);
+    let face_area = voxel_size * voxel_size;
+    let world_positions: Vec<Vec3> = voxels
+        .iter()
+        .map(|voxel| transform.translation + transform.rotation * voxel.position)
+        .collect();
+    let sea_heights: Vec<Option<f32>> = world_positions
+        .iter()
+        .zip(flooding.breach_areas.iter())
+        .map(|(position, area)| (*area > 0.0).then(|| water_height_at(*position)))
+        .collect();
+
+    // Water flows through each breach until the level inside matches the sea outside
+    for (index, sea_height) in sea_heights.iter().enumerate() {
+        let Some(sea_height) = sea_height else {
+            continue;
+        };
+
+        let surface =
+            world_positions[index].y - voxel_size / 2.0 + flooding.fill_levels[index] * voxel_size;
+        let flow = calculate_breach_flow(
+            flooding.breach_areas[index],
+            sea_height - surface,
+            delta_seconds,
+        );
+
+        if flow > 0.0 {
+            pour_water(flooding, index, flow / voxel_volume, &world_positions);
+        } else {
+            flooding.fill_levels[index] =
+                (flooding.fill_levels[index] + flow / voxel_volume).max(0.0);
+        }
+    }
+
+    let levels = calculate_water_levels(flooding, &world_positions, &sea_heights, voxel_size);
+
+    // Water spreads between connected voxels from the higher level to the lower one
+    for index in 0..voxels.len() {
+        for neighbour_index in 0..flooding.connections[index].len() {
+            let neighbour = flooding.connections[index][neighbour_index];
+            if neighbour < index {
+                continue;
+            }
+
+            let (from, to) = if levels[index] > levels[neighbour] {
+                (index, neighbour)
+            } else {
+                (neighbour, index)
+            };
+
+            let volume =
+                (FLOOD_SPREAD_RATE * face_area * (levels[from] - levels[to]) * delta_seconds)
+                    .min(flooding.fill_levels[from] * voxel_volume)
+                    .min((1.0 - flooding.fill_levels[to]) * voxel_volume);
+
+            if volume > 0.0 {
+                flooding.fill_levels[from] -= volume / voxel_volume;
+                flooding.fill_levels[to] += volume / voxel_volume;
+            }
+        }
+    }
+}
+
+/// Pours water into a voxel, letting whatever does not fit overflow into connected voxels.
+///
+/// Overflow passes through flooded voxels and fills the lowest voxels with room first.
+fn pour_water(flooding: &mut Flooding, start: usize, mut fill: f32, world_positions: &[Vec3]) {
+    let mut visited = vec![false; flooding.fill_levels.len()];
+    let mut pending = vec![start];
+    let mut candidates = Vec::new();
+    visited[start] = true;
+
+    while let Some(index) = pending.pop() {
+        if flooding.fill_levels[index] < 1.0 {
+            candidates.push(index);
+            continue;
+        }
+
+        for &neighbour in &flooding.connections[index] {
+            if !visited[neighbour] {
+                visited[neighbour] = true;
+                pending.push(neighbour);
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| world_positions[*a].y.total_cmp(&world_positions[*b].y));
+
+    for index in candidates {
+        let poured = fill.min(1.0 - flooding.fill_levels[index]);
+        flooding.fill_levels[index] += poured;
+        fill -= poured;
+
+        if fill <= 0.0 {
+            break;
+        }
+    }
+}
+
+/// Works out the world height of the water surface acting on each voxel.
+///
+/// A voxel's level starts at the surface of the water it holds. Full voxels take on the
+/// highest level pressing on them from the water they connect to, or from the sea outside
+/// when they are breached.
+fn calculate_water_levels(
+    flooding: &Flooding,
+    world_positions: &[Vec3],
+    sea_heights: &[Option<f32>],
+    voxel_size: f32,
+) -> Vec<f32> {
+    let is_full = |index: usize| flooding.fill_levels[index] >= 1.0;
+
+    let mut levels: Vec<f32> = world_positions
+        .iter()
+        .zip(flooding.fill_levels.iter())
+        .map(|(position, fill)| position.y - voxel_size / 2.0 + fill * voxel_size)
+        .collect();
+
+    for (index, sea_height) in sea_heights.iter().enumerate() {
+        if let Some(sea_height) = sea_height {
+            if is_full(index) {
+                levels[index] = levels[index].max(*sea_height);
+            }
+        }
+    }
+
+    let mut pending: Vec<usize> = (0..levels.len())
+        .filter(|index| flooding.fill_levels[*index] > 0.0)
+        .collect();
+
+    while let Some(index) = pending.pop() {
+        for &neighbour in &flooding.connections[index] {
+            if is_full(neighbour) && levels[neighbour] < levels[index] {
+                levels[neighbour] = levels[index];
+                pending.push(neighbour);
+            }
+        }
+    }
+
+    levels
+}
@@ -0,0 +1,3 @@
+pub use flooding_utils::*;
+
+mod flooding_utils;
@@ -0,0 +1,48 @@
+/// The casks of salt pork a ship puts to sea with for each member of its crew.
+pub const SALT_PORK_PER_CREW: f32 = 25.0;
+
+/// The ship's biscuit a ship puts to sea with for each member of its crew.
+pub const BISCUIT_PER_CREW: f32 = 25.0;
+
+/// The fresh water a ship puts to sea with for each member of its crew.
+pub const WATER_PER_CREW: f32 = 10.0;
+
+/// The meals the galley can keep hot at a time.
+pub const MEAL_CAPACITY: f32 = 40.0;
+
+/// The fraction of the salt pork left in a stockpile that spoils each second.
+pub const SALT_PORK_SPOIL_RATE: f32 = 0.0002;
+
+/// The fraction of the ship's biscuit left in a stockpile that spoils each second.
+pub const BISCUIT_SPOIL_RATE: f32 = 0.0005;
+
+/// The fraction of the fresh water left in a stockpile that goes foul each second.
+pub const WATER_SPOIL_RATE: f32 = 0.0003;
+
+/// The fraction of the meals left in the galley that goes cold and spoils each second.
+pub const MEAL_SPOIL_RATE: f32 = 0.005;
+
+/// The hunger a portion of salt pork satisfies.
+pub const SALT_PORK_NOURISHMENT: f32 = 25.0;
+
+/// The hunger a portion of ship's biscuit satisfies.
+pub const BISCUIT_NOURISHMENT: f32 = 20.0;
+
+/// The hunger a portion of raw rations satisfies. The crew eat a portion in the time it
+/// takes them to satisfy this much hunger at their usual rate, whatever they are eating.
+pub const RATION_NOURISHMENT: f32 = 25.0;
+
+/// The hunger a portion of a cooked meal satisfies.
+pub const MEAL_NOURISHMENT: f32 = 50.0;
+
+/// The seconds of work a fully effective cook takes to cook a batch of meals.
+pub const MEAL_COOKING_TIME: f32 = 30.0;
+
+/// The portions of meals a batch cooked in the galley makes.
+pub const MEALS_PER_BATCH: f32 = 6.0;
+
+/// The distance in metres within which a crew member can take food from a stockpile.
+pub const FOOD_STORE_DISTANCE: f32 = 2.0;
+
+/// The crew that can eat from the same stockpile of food at a time.
+pub const FOOD_STORE_CAPACITY: usize = 4;
@@ -0,0 +1,3 @@
+pub use food_settings::*;
+
+mod food_settings;
@@ -1,28 +1,41 @@
 use bevy::prelude::*;
 
 pub use components::*;
+pub use constants::*;
 use systems::*;
+pub use utils::*;
 
 use crate::asset_management::states::app_states::AppStates;
 
 mod components;
+mod constants;
 mod systems;
+mod utils;
 
 /// Plugin for managing food within the game world.
 ///
-/// The `FoodPlugin` handles the creation and management of food items, setting up the necessary
-/// components and systems to spawn food entities in the game world.
+/// The `FoodPlugin` handles the provisions ships carry, setting up the necessary components and
+/// systems to stow stockpiles of provisions aboard each ship and to mark those the crew can eat
+/// from. The crew eat raw rations from the hold, or the meals the cook makes in the galley,
+/// which satisfy more hunger, until the provisions run out.
 ///
 /// # Components
 /// - `Food`: A marker component indicating that an entity is a food item.
 ///
 /// # Systems
-/// - `spawn_food`: Spawns food items in the game world and configures their initial properties and behavior.
+/// - `spawn_food`: Stows stockpiles of provisions aboard each ship that has just been given a crew.
+/// - `update_food_stores`: Marks the stockpiles the crew can eat from as `Food`.
+///
+/// All systems are run during the `Update` stage and only when the application is in the `AppStates::Running` state.
 pub struct FoodPlugin;
 
 impl Plugin for FoodPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Food>()
-            .add_systems(OnEnter(AppStates::Running), spawn_food);
+            .add_systems(Update, spawn_food.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                update_food_stores.run_if(in_state(AppStates::Running)),
+            );
     }
 }
@@ -1,3 +1,4 @@
-pub use spawn_food::*;
+pub use {spawn_food::*, update_food_stores::*};
 
 mod spawn_food;
+mod update_food_stores;
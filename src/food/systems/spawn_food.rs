@@ -1,45 +1,101 @@
 use bevy::asset::Assets;
 use bevy::core::Name;
+use bevy::hierarchy::BuildChildren;
 use bevy::pbr::{PbrBundle, StandardMaterial};
-use bevy::prelude::{default, Color, Commands, Mesh, Meshable, ResMut, Sphere, Transform};
-use bevy_xpbd_3d::components::{Friction, RigidBody};
-use bevy_xpbd_3d::prelude::Collider;
+use bevy::prelude::{
+    default, Added, Color, Commands, Cuboid, Entity, Mesh, Query, ResMut, Transform,
+};
 
-use crate::food::Food;
+use crate::claims::Claimable;
+use crate::crew_management::ShipCrew;
+use crate::food::{
+    BISCUIT_PER_CREW, BISCUIT_SPOIL_RATE, FOOD_STORE_CAPACITY, MEAL_CAPACITY, MEAL_SPOIL_RATE,
+    SALT_PORK_PER_CREW, SALT_PORK_SPOIL_RATE, WATER_PER_CREW, WATER_SPOIL_RATE,
+};
+use crate::production::{Goods, Stockpile};
 
-/// System to spawn food entities in the game.
+/// System to provision each ship that has just been given a `ShipCrew`.
 ///
-/// This system creates food entities with specified properties, including a mesh,
-/// material, transform, and physical properties. The food entities are configured
-/// to have a dynamic rigid body, friction, and a spherical collider for physics interactions.
+/// This system stows casks of salt pork, biscuit and water in the hold of each newly crewed
+/// ship, holding enough for each member of its complement, and sets out an empty stockpile
+/// beside the galley for the meals the cook makes from them. The stockpiles are children of
+/// the ship, so they sail with it, and the crew eat from them until they run out.
 ///
 /// # Parameters
 /// - `commands`: Commands for spawning and configuring entities.
 /// - `meshes`: Resource to store and manage meshes.
 /// - `materials`: Resource to store and manage materials.
+/// - `ships`: Query to retrieve the ships that have just been given a crew.
 ///
 /// # Details
-/// The food entities are created with the following characteristics:
-/// - Name: "Food"
-/// - Mesh: A red sphere with a radius of 0.2 units.
-/// - Transform: Positioned at coordinates (13.167, 7.1885, 0.0).
-/// - Physics: Dynamic rigid body, friction of 1.0, and a spherical collider.
+/// The stockpiles are created with the following characteristics:
+/// - Name: "Salt Pork", "Biscuit", "Water" and "Meals"
+/// - Mesh: A cask of 0.6 units to a side.
+/// - Transform: Positioned in the hold around (13.167, 7.1885, 0.0) relative to the ship, and
+///   beside the galley for the meals.
+/// - Claims: Up to `FOOD_STORE_CAPACITY` crew members can claim a stockpile to eat from at once.
 pub fn spawn_food(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    ships: Query<(Entity, &ShipCrew), Added<ShipCrew>>,
 ) {
-    commands.spawn((
-        Name::new("Food"),
-        Food,
-        PbrBundle {
-            mesh: meshes.add(Sphere::new(0.2).mesh().ico(5).unwrap()),
-            material: materials.add(Color::RED),
-            transform: Transform::from_xyz(13.167, 7.1885, 0.0),
-            ..default()
-        },
-        RigidBody::Dynamic,
-        Friction::new(1.0),
-        Collider::sphere(0.2),
-    ));
+    for (ship, ship_crew) in ships.iter() {
+        let complement = ship_crew.complement as f32;
+
+        for (name, goods, quantity, spoil_rate, color, transform) in [
+            (
+                "Salt Pork",
+                Goods::SaltPork,
+                complement * SALT_PORK_PER_CREW,
+                SALT_PORK_SPOIL_RATE,
+                Color::RED,
+                Transform::from_xyz(13.167, 7.1885, -0.8),
+            ),
+            (
+                "Biscuit",
+                Goods::Biscuit,
+                complement * BISCUIT_PER_CREW,
+                BISCUIT_SPOIL_RATE,
+                Color::BEIGE,
+                Transform::from_xyz(13.167, 7.1885, 0.0),
+            ),
+            (
+                "Water",
+                Goods::Water,
+                complement * WATER_PER_CREW,
+                WATER_SPOIL_RATE,
+                Color::BLUE,
+                Transform::from_xyz(13.167, 7.1885, 0.8),
+            ),
+            (
+                "Meals",
+                Goods::Meal,
+                0.0,
+                MEAL_SPOIL_RATE,
+                Color::ORANGE,
+                Transform::from_xyz(3.0, 9.0, -2.5),
+            ),
+        ] {
+            let stockpile = commands
+                .spawn((
+                    Name::new(name),
+                    Stockpile {
+                        goods,
+                        quantity,
+                        capacity: quantity.max(MEAL_CAPACITY),
+                        spoil_rate,
+                    },
+                    Claimable::new(FOOD_STORE_CAPACITY),
+                    PbrBundle {
+                        mesh: meshes.add(Cuboid::new(0.6, 0.6, 0.6)),
+                        material: materials.add(color),
+                        transform,
+                        ..default()
+                    },
+                ))
+                .id();
+            commands.entity(ship).add_child(stockpile);
+        }
+    }
 }
@@ -0,0 +1,29 @@
+use bevy::prelude::{Commands, Entity, Has, Query};
+
+use crate::food::utils::calculate_nourishment;
+use crate::food::Food;
+use crate::production::Stockpile;
+
+/// Marks the stockpiles the crew can eat from as `Food`.
+///
+/// A stockpile of edible goods is `Food` while there is something left in it. Once it has run
+/// out it is no longer `Food`, so crew members stop seeking it out, and it is `Food` again
+/// once it has been restocked, such as when the galley has cooked a batch of meals.
+///
+/// # Parameters
+/// - `commands`: Commands for marking stockpiles as `Food` or not.
+/// - `stockpiles`: Query to retrieve the stockpiles, and whether they are `Food`.
+pub fn update_food_stores(
+    mut commands: Commands,
+    stockpiles: Query<(Entity, &Stockpile, Has<Food>)>,
+) {
+    for (entity, stockpile, is_food) in stockpiles.iter() {
+        let is_edible = calculate_nourishment(stockpile.goods).is_some() && !stockpile.is_empty();
+
+        if is_food && !is_edible {
+            commands.entity(entity).remove::<Food>();
+        } else if is_edible && !is_food {
+            commands.entity(entity).insert(Food);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::crew_management::Skill;
+use crate::food::{
+    BISCUIT_NOURISHMENT, MEALS_PER_BATCH, MEAL_COOKING_TIME, MEAL_NOURISHMENT, RATION_NOURISHMENT,
+    SALT_PORK_NOURISHMENT,
+};
+use crate::production::{Goods, Recipe, Stockpile};
+
+/// Calculates how much hunger a portion of goods satisfies.
+///
+/// # Arguments
+/// - `goods`: The goods eaten.
+///
+/// # Returns
+/// The hunger a portion satisfies, or `None` if the goods cannot be eaten.
+pub fn calculate_nourishment(goods: Goods) -> Option<f32> {
+    match goods {
+        Goods::SaltPork => Some(SALT_PORK_NOURISHMENT),
+        Goods::Biscuit => Some(BISCUIT_NOURISHMENT),
+        Goods::Meal => Some(MEAL_NOURISHMENT),
+        Goods::Water => None,
+    }
+}
+
+/// Eats from a stockpile of food at the crew's usual pace.
+///
+/// The crew eat a portion of whatever is in the stockpile in the time it takes them to satisfy
+/// `RATION_NOURISHMENT` of hunger, so food that is more nourishing than raw rations, such as
+/// a hot meal, relieves more hunger in the same time.
+///
+/// # Arguments
+/// - `stockpile`: The stockpile eaten from.
+/// - `appetite`: The hunger the crew member would relieve eating raw rations meanwhile.
+///
+/// # Returns
+/// The hunger relieved by the portions eaten, which is nothing if the goods cannot be eaten
+/// and less than usual once the stockpile runs out.
+pub fn eat_from_stockpile(stockpile: &mut Stockpile, appetite: f32) -> f32 {
+    let Some(nourishment) = calculate_nourishment(stockpile.goods) else {
+        return 0.0;
+    };
+
+    stockpile.take(appetite / RATION_NOURISHMENT) * nourishment
+}
+
+/// Creates the recipe the galley cooks meals by.
+///
+/// A batch of meals is cooked from salt pork, biscuit and water, and feeds the crew for more
+/// than the raw rations that go into it would.
+///
+/// # Returns
+/// The recipe for a batch of meals.
+pub fn create_meal_recipe() -> Recipe {
+    Recipe {
+        inputs: vec![
+            (Goods::SaltPork, 2.0),
+            (Goods::Biscuit, 2.0),
+            (Goods::Water, 1.0),
+        ],
+        output: Goods::Meal,
+        output_quantity: MEALS_PER_BATCH,
+        work_time: MEAL_COOKING_TIME,
+        skill: Skill::Cooking,
+    }
+}
@@ -0,0 +1,3 @@
+pub use food_utils::*;
+
+mod food_utils;
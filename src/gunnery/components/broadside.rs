@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The sides of a ship a broadside can be fired from.
+///
+/// - `Port`: The guns on the left side of the ship, looking forward.
+/// - `Starboard`: The guns on the right side of the ship, looking forward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum Broadside {
+    Port,
+    Starboard,
+}
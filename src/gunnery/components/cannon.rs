@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::gunnery::constants::{DEFAULT_MUZZLE_VELOCITY, DEFAULT_RELOAD_TIME};
+use crate::gunnery::ShotType;
+
+/// Represents a cannon mounted on a ship.
+///
+/// The `Cannon` component is a ship item added to entities in a ship's hierarchy, either in
+/// code or from a `CannonMarker` in the ship's glTF file. The barrel points along the entity's
+/// local X axis, raised by the cannon's elevation. A cannon has to be loaded by a crew member
+/// stationed at it before it can be aimed and fired, and it fires physics projectiles whose
+/// hits are reported through `ProjectileHit` events.
+///
+/// # Fields
+/// - `state`: Whether the cannon is empty, being loaded, aimed or about to fire.
+/// - `shot`: The type of shot the cannon is loaded with, or will be loaded with next.
+/// - `reload_time`: The seconds a crew member needs to load the cannon.
+/// - `reload_progress`: How far loading has come, from `0.0` (empty) to `1.0` (loaded).
+/// - `muzzle_velocity`: The speed of round shot leaving the barrel, in metres per second.
+/// - `elevation`: The angle of the barrel above the horizontal, in radians.
+/// - `operator`: The crew member stationed at the cannon, if any.
+///
+/// # Methods
+/// - `new(reload_time: f32, muzzle_velocity: f32) -> Self`:
+///   Creates a new `Cannon` loaded with round shot.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Cannon {
+    pub state: CannonState,
+    pub shot: ShotType,
+    pub reload_time: f32,
+    pub reload_progress: f32,
+    pub muzzle_velocity: f32,
+    pub elevation: f32,
+    pub operator: Option<Entity>,
+}
+
+/// The stages a cannon goes through between shots.
+///
+/// - `Empty`: The cannon has been fired and waits for a crew member to load it.
+/// - `Loading`: A crew member is loading the cannon.
+/// - `Aiming`: The cannon is loaded and can be laid and fired.
+/// - `Firing`: The cannon has been ordered to fire and will do so this frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize, Default)]
+pub enum CannonState {
+    #[default]
+    Empty,
+    Loading,
+    Aiming,
+    Firing,
+}
+
+impl Cannon {
+    /// Creates a new `Cannon`, loaded with round shot.
+    ///
+    /// Ships put to sea with their guns loaded, so the crew only has to reload them once
+    /// they have been fired.
+    ///
+    /// # Parameters
+    /// - `reload_time`: The seconds a crew member needs to load the cannon.
+    /// - `muzzle_velocity`: The speed of round shot leaving the barrel, in metres per second.
+    ///
+    /// # Returns
+    /// A new loaded `Cannon` with a level barrel.
+    pub fn new(reload_time: f32, muzzle_velocity: f32) -> Self {
+        Self {
+            state: CannonState::Aiming,
+            shot: ShotType::RoundShot,
+            reload_time,
+            reload_progress: 1.0,
+            muzzle_velocity,
+            elevation: 0.0,
+            operator: None,
+        }
+    }
+}
+
+impl Default for Cannon {
+    fn default() -> Self {
+        Self::new(DEFAULT_RELOAD_TIME, DEFAULT_MUZZLE_VELOCITY)
+    }
+}
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A marker component used to designate a cannon on a ship.
+///
+/// The `CannonMarker` component is read from the glTF file through `bevy_gltf_components`.
+/// The marked node is the gun itself, oriented so its local X axis runs out along the barrel.
+/// The `read_cannon_markers` system turns the marker into a `Cannon`.
+///
+/// # Usages
+/// - Adding the component to a node in a ship's glTF file mounts a cannon there.
+/// - Used by the gunnery systems to place the ship's guns.
+#[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct CannonMarker;
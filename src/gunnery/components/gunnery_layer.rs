@@ -0,0 +1,38 @@
+use bevy_xpbd_3d::prelude::PhysicsLayer;
+
+/// The collision layers that keep shot from striking the ship that fired it.
+///
+/// Every collider in a ship's hierarchy is placed on the layer of the ship's side, and fired
+/// shot only collides with the colliders of the opposing side and with anything that has no
+/// layers of its own. Shot is placed on a layer of its own, so projectiles never collide
+/// with each other.
+///
+/// - `PlayerSide`: The colliders of the player's ship.
+/// - `EnemySide`: The colliders of enemy ships.
+/// - `Shot`: Projectiles fired from cannons.
+///
+/// # Methods
+/// - `side(is_player: bool) -> Self`: Returns the layer of a ship's side.
+/// - `opposing_side(is_player: bool) -> Self`: Returns the layer of the side a ship fights.
+#[derive(PhysicsLayer, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GunneryLayer {
+    PlayerSide,
+    EnemySide,
+    Shot,
+}
+
+impl GunneryLayer {
+    /// Returns the layer of a ship's side.
+    pub fn side(is_player: bool) -> Self {
+        if is_player {
+            GunneryLayer::PlayerSide
+        } else {
+            GunneryLayer::EnemySide
+        }
+    }
+
+    /// Returns the layer of the side a ship fights.
+    pub fn opposing_side(is_player: bool) -> Self {
+        Self::side(!is_player)
+    }
+}
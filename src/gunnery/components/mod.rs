@@ -0,0 +1,10 @@
+pub use {
+    broadside::*, cannon::*, cannon_marker::*, gunnery_layer::*, projectile::*, shot_type::*,
+};
+
+mod broadside;
+mod cannon;
+mod cannon_marker;
+mod gunnery_layer;
+mod projectile;
+mod shot_type;
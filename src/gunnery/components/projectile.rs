@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::gunnery::ShotType;
+
+/// Represents a projectile fired from a cannon.
+///
+/// The `Projectile` component is added to the dynamic rigid bodies spawned when a cannon
+/// fires. The physics engine carries them along their ballistic flight, and they are removed
+/// when they hit something, fall into the sea or run out of time.
+///
+/// # Fields
+/// - `shot`: The type of shot the projectile is part of.
+/// - `source`: The ship that fired the projectile, which it cannot hit.
+/// - `age`: The seconds since the projectile was fired.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Projectile {
+    pub shot: ShotType,
+    pub source: Entity,
+    pub age: f32,
+}
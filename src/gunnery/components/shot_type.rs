@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The types of shot a cannon can be loaded with.
+///
+/// - `RoundShot`: A single solid iron ball, used to hole hulls.
+/// - `ChainShot`: Two half balls joined by a chain, which tumble through rigging and sails.
+/// - `GrapeShot`: A bag of small balls that spread out, used against crews.
+///
+/// # Methods
+/// - `projectile_count(&self) -> usize`: Returns how many projectiles a single shot spawns.
+/// - `radius(&self) -> f32`: Returns the radius of each projectile in metres.
+/// - `mass(&self) -> f32`: Returns the mass of each projectile in kilograms.
+/// - `velocity_factor(&self) -> f32`: Returns the muzzle velocity relative to round shot.
+/// - `spread(&self) -> f32`: Returns the angle in radians the projectiles scatter by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize, Default)]
+pub enum ShotType {
+    #[default]
+    RoundShot,
+    ChainShot,
+    GrapeShot,
+}
+
+impl ShotType {
+    /// Returns how many projectiles a single shot spawns.
+    pub fn projectile_count(&self) -> usize {
+        match self {
+            ShotType::RoundShot | ShotType::ChainShot => 1,
+            ShotType::GrapeShot => 9,
+        }
+    }
+
+    /// Returns the radius of each projectile in metres.
+    pub fn radius(&self) -> f32 {
+        match self {
+            ShotType::RoundShot => 0.08,
+            ShotType::ChainShot => 0.4,
+            ShotType::GrapeShot => 0.03,
+        }
+    }
+
+    /// Returns the mass of each projectile in kilograms.
+    pub fn mass(&self) -> f32 {
+        match self {
+            ShotType::RoundShot => 4.0,
+            ShotType::ChainShot => 4.5,
+            ShotType::GrapeShot => 0.4,
+        }
+    }
+
+    /// Returns the muzzle velocity of the shot relative to round shot.
+    pub fn velocity_factor(&self) -> f32 {
+        match self {
+            ShotType::RoundShot => 1.0,
+            ShotType::ChainShot => 0.7,
+            ShotType::GrapeShot => 0.8,
+        }
+    }
+
+    /// Returns the angle in radians the projectiles scatter by as they leave the barrel.
+    pub fn spread(&self) -> f32 {
+        match self {
+            ShotType::RoundShot => 0.5f32.to_radians(),
+            ShotType::ChainShot => 2.0f32.to_radians(),
+            ShotType::GrapeShot => 5.0f32.to_radians(),
+        }
+    }
+}
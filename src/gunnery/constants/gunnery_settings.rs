@@ -0,0 +1,23 @@
+/// The seconds a crew member needs to load a cannon.
+pub const DEFAULT_RELOAD_TIME: f32 = 12.0;
+
+/// The speed of round shot leaving the barrel, in metres per second.
+pub const DEFAULT_MUZZLE_VELOCITY: f32 = 120.0;
+
+/// How far from a cannon, in metres, a crew member can be and still work it.
+pub const CANNON_STATION_DISTANCE: f32 = 3.0;
+
+/// The lowest the barrel can be depressed, in radians.
+pub const CANNON_ELEVATION_MIN: f32 = -0.087;
+
+/// The highest the barrel can be raised, in radians.
+pub const CANNON_ELEVATION_MAX: f32 = 0.26;
+
+/// How quickly the barrels are raised or lowered while the keys are held, in radians per second.
+pub const CANNON_ELEVATION_RATE: f32 = 0.15;
+
+/// The distance from the cannon to the muzzle along the barrel, in metres.
+pub const MUZZLE_OFFSET: f32 = 1.5;
+
+/// The seconds a projectile flies for before it is removed.
+pub const PROJECTILE_LIFETIME: f32 = 10.0;
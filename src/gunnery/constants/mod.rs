@@ -0,0 +1,3 @@
+pub use gunnery_settings::*;
+
+mod gunnery_settings;
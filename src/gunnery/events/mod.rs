@@ -0,0 +1,3 @@
+pub use projectile_hit::*;
+
+mod projectile_hit;
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::gunnery::ShotType;
+
+/// Event sent when a projectile strikes something.
+///
+/// This event is sent by the `detect_projectile_hits` system when a projectile collides with
+/// an entity other than the ship that fired it. Anything that takes damage from gunfire reads
+/// these events.
+///
+/// # Fields
+/// - `target`: The top-level entity that was hit, such as a ship.
+/// - `source`: The ship that fired the projectile.
+/// - `position`: The world position of the hit.
+/// - `velocity`: The velocity of the projectile when it hit, in metres per second.
+/// - `shot`: The type of shot that hit.
+#[derive(Event, Debug, Clone)]
+pub struct ProjectileHit {
+    pub target: Entity,
+    pub source: Entity,
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub shot: ShotType,
+}
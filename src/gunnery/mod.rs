@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+pub use events::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod events;
+mod systems;
+mod utils;
+
+/// Plugin for the ships' guns and the shot they fire.
+///
+/// The `GunneryPlugin` arms ships with broadsides of cannon. Each `Cannon` has to be loaded by
+/// a crew member stationed at it, after which it can be laid and fired with round, chain or
+/// grape shot. Fired shot flies as physics projectiles along a ballistic arc, passing through
+/// the ship that fired it, and anything they strike is reported through `ProjectileHit` events
+/// for the damage systems to act on.
+///
+/// # Components
+/// - `Cannon`: A cannon mounted on a ship, with its loading, aiming and firing state.
+/// - `CannonMarker`: Marks a glTF node as a cannon.
+/// - `GunneryLayer`: The collision layers that keep shot from striking its own ship.
+/// - `Projectile`: A projectile in flight, fired from a cannon.
+///
+/// # Events
+/// - `ProjectileHit`: Reports a projectile striking something.
+///
+/// # Systems
+/// - `assign_collision_layers`: Places the colliders of ships on the collision layer of their side.
+/// - `control_cannons`: Selects the shot, lays the guns and fires broadsides based on user input.
+/// - `detect_projectile_hits`: Reports projectiles striking something and removes them.
+/// - `fire_cannons`: Spawns the projectiles of the cannons ordered to fire and applies their recoil.
+/// - `load_cannons`: Loads the cannons that have a crew member stationed at them.
+/// - `read_cannon_markers`: Mounts cannons on the nodes marked in glTF files.
+/// - `update_projectiles`: Removes projectiles that fall into the sea or run out of time.
+///
+/// This plugin is added to the app during the application setup and is configured to
+/// operate during the `AppStates::Running` state.
+pub struct GunneryPlugin;
+
+impl Plugin for GunneryPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Cannon>()
+            .register_type::<CannonMarker>()
+            .register_type::<Projectile>()
+            .add_event::<ProjectileHit>()
+            .add_systems(
+                Update,
+                assign_collision_layers.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, control_cannons.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                detect_projectile_hits.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                fire_cannons
+                    .after(control_cannons)
+                    .run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, load_cannons.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                read_cannon_markers.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                update_projectiles.run_if(in_state(AppStates::Running)),
+            );
+    }
+}
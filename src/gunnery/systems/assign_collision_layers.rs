@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use bevy_xpbd_3d::prelude::{Collider, CollisionLayers, LayerMask};
+
+use crate::gunnery::{GunneryLayer, Projectile};
+use crate::ship::{PlayerShip, Ship};
+use crate::utils::find_top_level_entity;
+
+/// System to place the colliders of ships on the collision layer of their side.
+///
+/// Colliders are added to a ship's hierarchy as its glTF scene and buoyancy are set up, so the
+/// system picks up every newly added collider, walks up the hierarchy to the ship carrying it and
+/// places the collider on the `GunneryLayer` of the ship's side. The collider still collides with
+/// everything else, but shot fired by the ship passes through it.
+///
+/// # Parameters
+/// - `commands`: Commands for inserting the collision layers.
+/// - `colliders`: Query to retrieve newly added colliders, other than projectiles.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each collider.
+/// - `ships`: Query to identify ships and whether they are sailed by the player.
+#[allow(clippy::type_complexity)]
+pub fn assign_collision_layers(
+    mut commands: Commands,
+    colliders: Query<Entity, (Added<Collider>, Without<Projectile>)>,
+    parent_query: Query<&Parent>,
+    ships: Query<Has<PlayerShip>, With<Ship>>,
+) {
+    for entity in colliders.iter() {
+        let ship = find_top_level_entity(entity, &parent_query);
+
+        let Ok(is_player) = ships.get(ship) else {
+            continue;
+        };
+
+        commands.entity(entity).insert(CollisionLayers::new(
+            [GunneryLayer::side(is_player)],
+            LayerMask::ALL,
+        ));
+    }
+}
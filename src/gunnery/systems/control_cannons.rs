@@ -0,0 +1,104 @@
+use bevy::input::ButtonInput;
+use bevy::prelude::{Entity, GlobalTransform, KeyCode, Parent, Query, Res, Time, With};
+
+use crate::gunnery::constants::{
+    CANNON_ELEVATION_MAX, CANNON_ELEVATION_MIN, CANNON_ELEVATION_RATE,
+};
+use crate::gunnery::utils::{calculate_barrel_direction, calculate_broadside};
+use crate::gunnery::{Broadside, Cannon, CannonState, ShotType};
+use crate::ship::PlayerShip;
+use crate::utils::find_top_level_entity;
+
+/// Selects the shot, lays the guns and fires broadsides of the player's ship based on user input.
+///
+/// - Press `1`, `2` or `3` to load round, chain or grape shot into the cannons not yet loaded.
+/// - Hold `T` to raise the barrels.
+/// - Hold `G` to lower the barrels.
+/// - Press `Q` to fire the loaded guns of the port broadside.
+/// - Press `E` to fire the loaded guns of the starboard broadside.
+///
+/// # Parameters
+/// - `input`: Resource that captures keyboard input.
+/// - `time`: Resource providing the delta time for the game.
+/// - `cannons`: Query to fetch and modify the `Cannon` components and their transforms.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each cannon.
+/// - `transforms`: Query to retrieve the transforms of ships.
+/// - `player_ships`: Query to identify the player's ship.
+pub fn control_cannons(
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut cannons: Query<(Entity, &mut Cannon)>,
+    parent_query: Query<&Parent>,
+    transforms: Query<&GlobalTransform>,
+    player_ships: Query<(), With<PlayerShip>>,
+) {
+    let shot = if input.just_pressed(KeyCode::Digit1) {
+        Some(ShotType::RoundShot)
+    } else if input.just_pressed(KeyCode::Digit2) {
+        Some(ShotType::ChainShot)
+    } else if input.just_pressed(KeyCode::Digit3) {
+        Some(ShotType::GrapeShot)
+    } else {
+        None
+    };
+
+    let mut elevation_delta = 0.0;
+    if input.pressed(KeyCode::KeyT) {
+        elevation_delta += CANNON_ELEVATION_RATE * time.delta_seconds();
+    }
+    if input.pressed(KeyCode::KeyG) {
+        elevation_delta -= CANNON_ELEVATION_RATE * time.delta_seconds();
+    }
+
+    let broadside = if input.just_pressed(KeyCode::KeyQ) {
+        Some(Broadside::Port)
+    } else if input.just_pressed(KeyCode::KeyE) {
+        Some(Broadside::Starboard)
+    } else {
+        None
+    };
+
+    if shot.is_none() && elevation_delta == 0.0 && broadside.is_none() {
+        return;
+    }
+
+    for (entity, mut cannon) in cannons.iter_mut() {
+        let ship = find_top_level_entity(entity, &parent_query);
+
+        if !player_ships.contains(ship) {
+            continue;
+        }
+
+        if let Some(shot) = shot {
+            if matches!(cannon.state, CannonState::Empty | CannonState::Loading) {
+                cannon.shot = shot;
+            }
+        }
+
+        cannon.elevation =
+            (cannon.elevation + elevation_delta).clamp(CANNON_ELEVATION_MIN, CANNON_ELEVATION_MAX);
+
+        if cannon.state != CannonState::Aiming || broadside.is_none() {
+            continue;
+        }
+
+        let (Ok(cannon_transform), Ok(ship_transform)) =
+            (transforms.get(entity), transforms.get(ship))
+        else {
+            continue;
+        };
+
+        let barrel_direction = calculate_barrel_direction(
+            cannon_transform.to_scale_rotation_translation().1,
+            cannon.elevation,
+        );
+        let cannon_broadside = calculate_broadside(
+            ship_transform.to_scale_rotation_translation().1,
+            barrel_direction,
+        );
+
+        if cannon_broadside == broadside {
+            cannon.state = CannonState::Firing;
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Commands, Entity, EventReader, EventWriter, Parent, Query, Transform};
+use bevy_xpbd_3d::components::LinearVelocity;
+use bevy_xpbd_3d::prelude::CollisionStarted;
+
+use crate::gunnery::{Projectile, ProjectileHit};
+use crate::utils::find_top_level_entity;
+
+/// Reports projectiles striking something and removes them.
+///
+/// For each collision between a projectile and an entity that is not another projectile,
+/// the top-level entity that was struck is found and a `ProjectileHit` event is sent. The
+/// projectile is then de-spawned. Projectiles pass harmlessly through the ship that fired them.
+///
+/// # Parameters
+/// - `commands`: Commands for de-spawning projectiles that hit.
+/// - `collision_events`: Event reader for the collisions that started this frame.
+/// - `projectiles`: Query to retrieve the projectiles, their transforms and velocities.
+/// - `parent_query`: Query to navigate up the hierarchy to find the entity that was struck.
+/// - `projectile_hit_events`: Event writer for `ProjectileHit` events.
+pub fn detect_projectile_hits(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionStarted>,
+    projectiles: Query<(&Projectile, &Transform, &LinearVelocity)>,
+    parent_query: Query<&Parent>,
+    mut projectile_hit_events: EventWriter<ProjectileHit>,
+) {
+    let mut spent_projectiles: Vec<Entity> = Vec::new();
+
+    for CollisionStarted(first, second) in collision_events.read() {
+        for (projectile_entity, other) in [(*first, *second), (*second, *first)] {
+            if spent_projectiles.contains(&projectile_entity) || projectiles.contains(other) {
+                continue;
+            }
+            let Ok((projectile, transform, velocity)) = projectiles.get(projectile_entity) else {
+                continue;
+            };
+
+            let target = find_top_level_entity(other, &parent_query);
+
+            if target == projectile.source {
+                continue;
+            }
+
+            projectile_hit_events.send(ProjectileHit {
+                target,
+                source: projectile.source,
+                position: transform.translation,
+                velocity: velocity.0,
+                shot: projectile.shot,
+            });
+
+            commands.entity(projectile_entity).despawn_recursive();
+            spent_projectiles.push(projectile_entity);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::asset::Assets;
+use bevy::core::Name;
+use bevy::math::{Mat3, Vec3};
+use bevy::pbr::{PbrBundle, StandardMaterial};
+use bevy::prelude::{
+    default, Color, Commands, Entity, GlobalTransform, Mesh, Meshable, Parent, Query, ResMut,
+    Sphere, Transform, With,
+};
+use bevy_xpbd_3d::components::{
+    CenterOfMass, ColliderDensity, ExternalImpulse, Inertia, LinearVelocity, Mass, RigidBody,
+};
+use bevy_xpbd_3d::prelude::{Collider, CollisionLayers};
+
+use crate::gunnery::constants::MUZZLE_OFFSET;
+use crate::gunnery::utils::{calculate_barrel_direction, scatter_direction};
+use crate::gunnery::{Cannon, CannonState, GunneryLayer, Projectile};
+use crate::ship::PlayerShip;
+use crate::utils::find_top_level_entity;
+
+/// Fires the cannons that have been ordered to fire.
+///
+/// Each firing cannon spawns the projectiles of its shot at the muzzle as dynamic rigid bodies,
+/// scattered around the line of the barrel. The projectiles leave at the cannon's muzzle velocity
+/// on top of the ship's own velocity, and the physics engine carries them along their ballistic
+/// flight. The projectiles only collide with the opposing side, so they never strike the ship
+/// that fired them. The recoil is applied to the ship as an impulse, and the cannon is left empty.
+///
+/// # Parameters
+/// - `commands`: Commands for spawning the projectiles.
+/// - `meshes`: A mutable reference to the Assets resource containing Mesh objects.
+/// - `materials`: A mutable reference to the Assets resource containing StandardMaterial objects.
+/// - `cannons`: Query to retrieve and modify the cannons and their transforms.
+/// - `parent_query`: Query to navigate up the hierarchy to find the ship carrying each cannon.
+/// - `ships`: Query to retrieve the transform, velocity, centre of mass and external impulse of ships.
+/// - `player_ships`: Query to identify the player's ship, deciding which side the shot is fired by.
+#[allow(clippy::type_complexity)]
+pub fn fire_cannons(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut cannons: Query<(Entity, &mut Cannon, &GlobalTransform)>,
+    parent_query: Query<&Parent>,
+    mut ships: Query<(
+        &Transform,
+        Option<&LinearVelocity>,
+        Option<&CenterOfMass>,
+        Option<&mut ExternalImpulse>,
+    )>,
+    player_ships: Query<(), With<PlayerShip>>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for (entity, mut cannon, cannon_transform) in cannons.iter_mut() {
+        if cannon.state != CannonState::Firing {
+            continue;
+        }
+
+        let ship = find_top_level_entity(entity, &parent_query);
+
+        let shot = cannon.shot;
+        let collision_layers = CollisionLayers::new(
+            [GunneryLayer::Shot],
+            [GunneryLayer::opposing_side(player_ships.contains(ship))],
+        );
+        let barrel_direction = calculate_barrel_direction(
+            cannon_transform.to_scale_rotation_translation().1,
+            cannon.elevation,
+        );
+        let muzzle_position = cannon_transform.translation() + barrel_direction * MUZZLE_OFFSET;
+        let ship_velocity = ships
+            .get(ship)
+            .ok()
+            .and_then(|(_, velocity, _, _)| velocity.map(|velocity| velocity.0))
+            .unwrap_or(Vec3::ZERO);
+
+        let radius = shot.radius();
+        let mass = shot.mass();
+        let mesh = meshes.add(Sphere::new(radius).mesh().ico(2).unwrap());
+        let material = materials.add(Color::BLACK);
+        let mut recoil = Vec3::ZERO;
+
+        for _ in 0..shot.projectile_count() {
+            let direction = scatter_direction(barrel_direction, shot.spread(), &mut rng);
+            let muzzle_velocity = direction * cannon.muzzle_velocity * shot.velocity_factor();
+            recoil -= muzzle_velocity * mass;
+
+            commands.spawn((
+                Name::new("Projectile"),
+                PbrBundle {
+                    mesh: mesh.clone(),
+                    material: material.clone(),
+                    transform: Transform::from_translation(muzzle_position),
+                    ..default()
+                },
+                Projectile {
+                    shot,
+                    source: ship,
+                    age: 0.0,
+                },
+                RigidBody::Dynamic,
+                Collider::sphere(radius),
+                collision_layers,
+                ColliderDensity(0.0),
+                Mass(mass),
+                Inertia(Mat3::from_diagonal(Vec3::splat(
+                    0.4 * mass * radius * radius,
+                ))),
+                LinearVelocity(ship_velocity + muzzle_velocity),
+            ));
+        }
+
+        if let Ok((ship_transform, _, Some(center_of_mass), Some(mut external_impulse))) =
+            ships.get_mut(ship)
+        {
+            let world_center_of_mass =
+                ship_transform.translation + ship_transform.rotation * center_of_mass.0;
+            external_impulse.apply_impulse_at_point(
+                recoil,
+                cannon_transform.translation(),
+                world_center_of_mass,
+            );
+        }
+
+        cannon.state = CannonState::Empty;
+        cannon.reload_progress = 0.0;
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::{GlobalTransform, Query, Res, Time, Without};
+
+use crate::crew_health::Health;
+use crate::crew_management::{CrewSkills, Skill, Station};
+use crate::crew_morale::Morale;
+use crate::damage::Integrity;
+use crate::gunnery::constants::CANNON_STATION_DISTANCE;
+use crate::gunnery::{Cannon, CannonState};
+
+/// Loads the cannons that have a crew member stationed at them.
+///
+/// A cannon only loads while its operator stands within reach of it, or while the gunner
+/// assigned to it as a station is manning it. Loading takes the cannon's reload time, after
+/// which the cannon is ready to be aimed and fired. Loading pauses if the crew leave, and picks
+/// up where it left off when someone returns. Damaged cannons load slower, in proportion to
+/// their integrity, and destroyed ones cannot be loaded. Crew load faster the more skilled at
+/// gunnery, the healthier and the more content they are, and a crew member loading a cannon
+/// they are not stationed at gains gunnery experience while they work.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `operators`: Query to retrieve the transforms of crew members operating cannons.
+/// - `crew`: Query to retrieve and modify the skills of crew members.
+/// - `conditions`: Query to retrieve the health and morale of crew members.
+/// - `cannons`: Query to retrieve and modify the cannons, their transforms, integrity and stations.
+pub fn load_cannons(
+    time: Res<Time>,
+    operators: Query<&GlobalTransform, Without<Cannon>>,
+    mut crew: Query<&mut CrewSkills>,
+    conditions: Query<(Option<&Health>, Option<&Morale>)>,
+    mut cannons: Query<(
+        &mut Cannon,
+        &GlobalTransform,
+        Option<&Integrity>,
+        Option<&Station>,
+    )>,
+) {
+    for (mut cannon, cannon_transform, integrity, station) in cannons.iter_mut() {
+        if !matches!(cannon.state, CannonState::Empty | CannonState::Loading) {
+            continue;
+        }
+
+        let is_manned = station.is_some_and(|station| station.manned);
+        let is_stationed = is_manned
+            || cannon
+                .operator
+                .and_then(|operator| operators.get(operator).ok())
+                .is_some_and(|operator_transform| {
+                    operator_transform
+                        .translation()
+                        .distance(cannon_transform.translation())
+                        <= CANNON_STATION_DISTANCE
+                });
+
+        let loader = if is_manned {
+            station.and_then(|station| station.crew)
+        } else {
+            cannon.operator
+        };
+        let mut skills = loader.and_then(|loader| crew.get_mut(loader).ok());
+
+        let effectiveness = integrity.map_or(1.0, |integrity| integrity.level)
+            * skills
+                .as_ref()
+                .map_or(1.0, |skills| skills.effectiveness(Skill::Gunnery))
+            * loader
+                .and_then(|loader| conditions.get(loader).ok())
+                .map_or(1.0, |(health, morale)| {
+                    health.map_or(1.0, |health| health.work_rate())
+                        * morale.map_or(1.0, |morale| morale.work_rate())
+                });
+        if !is_stationed || effectiveness <= 0.0 {
+            continue;
+        }
+
+        // Crew manning the cannon as their station are trained by `train_crew_skills`
+        if !is_manned {
+            if let Some(skills) = skills.as_mut() {
+                skills.gain_experience(Skill::Gunnery, time.delta_seconds());
+            }
+        }
+
+        cannon.state = CannonState::Loading;
+        cannon.reload_progress +=
+            effectiveness * time.delta_seconds() / cannon.reload_time.max(f32::EPSILON);
+
+        if cannon.reload_progress >= 1.0 {
+            cannon.reload_progress = 1.0;
+            cannon.state = CannonState::Aiming;
+        }
+    }
+}
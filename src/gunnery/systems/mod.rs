@@ -0,0 +1,12 @@
+pub use {
+    assign_collision_layers::*, control_cannons::*, detect_projectile_hits::*, fire_cannons::*,
+    load_cannons::*, read_cannon_markers::*, update_projectiles::*,
+};
+
+mod assign_collision_layers;
+mod control_cannons;
+mod detect_projectile_hits;
+mod fire_cannons;
+mod load_cannons;
+mod read_cannon_markers;
+mod update_projectiles;
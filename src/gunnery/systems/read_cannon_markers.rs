@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::claims::Claimable;
+use crate::gunnery::{Cannon, CannonMarker};
+
+/// System to process cannon markers authored in glTF files.
+///
+/// This system handles entities marked with the `CannonMarker` component, mounting a loaded
+/// `Cannon` with the default reload time and muzzle velocity on each of them, which one crew
+/// member at a time can claim.
+///
+/// # Parameters
+/// - `cannon_marker_query`: Query to retrieve entities with newly added `CannonMarker` components.
+/// - `commands`: Commands for modifying entities and their components.
+pub fn read_cannon_markers(
+    cannon_marker_query: Query<Entity, Added<CannonMarker>>,
+    mut commands: Commands,
+) {
+    for entity in cannon_marker_query.iter() {
+        commands
+            .entity(entity)
+            .insert((Cannon::default(), Claimable::new(1)));
+    }
+}
@@ -0,0 +1,33 @@
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Commands, Entity, Query, Res, Time, Transform};
+use bevy_water::WaterParam;
+
+use crate::gunnery::constants::PROJECTILE_LIFETIME;
+use crate::gunnery::Projectile;
+use crate::utils::get_water_height_at_position;
+
+/// Ages projectiles in flight and removes the ones that are spent.
+///
+/// Projectiles that fall below the water surface, or have been flying for longer than their
+/// lifetime, are de-spawned.
+///
+/// # Parameters
+/// - `commands`: Commands for de-spawning spent projectiles.
+/// - `time`: Resource providing the delta time for the game.
+/// - `water`: Parameter for sampling the height of the water surface.
+/// - `query`: Query to retrieve and modify the projectiles and their transforms.
+pub fn update_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    water: WaterParam,
+    mut query: Query<(Entity, &mut Projectile, &Transform)>,
+) {
+    for (entity, mut projectile, transform) in query.iter_mut() {
+        projectile.age += time.delta_seconds();
+
+        let water_height = get_water_height_at_position(transform.translation, &water);
+        if projectile.age > PROJECTILE_LIFETIME || transform.translation.y < water_height {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::gunnery::Broadside;
+use crate::ship::SHIP_STARBOARD;
+
+/// Calculates the direction a cannon's barrel points in.
+///
+/// # Arguments
+/// - `rotation`: The world rotation of the cannon.
+/// - `elevation`: The angle of the barrel above the cannon's horizontal, in radians.
+///
+/// # Returns
+/// The unit vector along the barrel in world space.
+pub fn calculate_barrel_direction(rotation: Quat, elevation: f32) -> Vec3 {
+    rotation * (Quat::from_rotation_z(elevation) * Vec3::X)
+}
+
+/// Determines which broadside a cannon belongs to.
+///
+/// # Arguments
+/// - `ship_rotation`: The world rotation of the ship carrying the cannon.
+/// - `barrel_direction`: The world direction of the cannon's barrel.
+///
+/// # Returns
+/// The side of the ship the cannon fires towards, or `None` for guns pointing fore or aft.
+pub fn calculate_broadside(ship_rotation: Quat, barrel_direction: Vec3) -> Option<Broadside> {
+    let side = (ship_rotation.inverse() * barrel_direction).dot(SHIP_STARBOARD);
+
+    if side > 0.5 {
+        Some(Broadside::Starboard)
+    } else if side < -0.5 {
+        Some(Broadside::Port)
+    } else {
+        None
+    }
+}
+
+/// Calculates how far a shot carries before it falls into the sea, ignoring air resistance.
+///
+/// # Arguments
+/// - `muzzle_velocity`: The speed of the shot leaving the barrel, in metres per second.
+/// - `elevation`: The angle of the barrel above the horizontal, in radians.
+/// - `height`: The height of the muzzle above the water, in metres.
+/// - `gravity`: The acceleration due to gravity, in metres per second squared.
+///
+/// # Returns
+/// The horizontal distance the shot travels, in metres.
+pub fn calculate_shot_range(
+    muzzle_velocity: f32,
+    elevation: f32,
+    height: f32,
+    gravity: f32,
+) -> f32 {
+    let vertical_speed = muzzle_velocity * elevation.sin();
+    let horizontal_speed = muzzle_velocity * elevation.cos();
+
+    let discriminant = vertical_speed.powi(2) + 2.0 * gravity * height.max(0.0);
+    let flight_time = (vertical_speed + discriminant.sqrt()) / gravity;
+
+    horizontal_speed * flight_time
+}
+
+/// Scatters a direction randomly within a cone, as shot leaves a barrel.
+///
+/// # Arguments
+/// - `direction`: The unit direction of the barrel.
+/// - `spread`: The half-angle of the cone, in radians.
+/// - `rng`: The random number generator to scatter with.
+///
+/// # Returns
+/// A unit direction at most `spread` radians away from `direction`.
+pub fn scatter_direction(direction: Vec3, spread: f32, rng: &mut impl Rng) -> Vec3 {
+    if spread <= 0.0 {
+        return direction;
+    }
+
+    let angle = rng.gen_range(0.0..=spread);
+    let roll = rng.gen_range(0.0..std::f32::consts::TAU);
+    let axis = Quat::from_axis_angle(direction, roll) * direction.any_orthonormal_vector();
+
+    Quat::from_axis_angle(axis, angle) * direction
+}
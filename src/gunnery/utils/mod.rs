@@ -0,0 +1,3 @@
+pub use gunnery_utils::*;
+
+mod gunnery_utils;
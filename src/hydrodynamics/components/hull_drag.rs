@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Tracks the state of each hull voxel needed for drag and slamming.
+///
+/// The `HullDrag` component is kept alongside a `Buoyancy` component, with one entry per
+/// voxel in the same order. Only voxels on the surface of the hull are wetted, so the
+/// exposed faces of each voxel are found once when the hull is voxelized. The water height
+/// and submerged fraction from the previous frame are kept to work out how fast the wave
+/// surface is moving and how quickly voxels are entering the water.
+///
+/// # Fields
+/// - `exposed_faces`: A bitmask of the hull-local faces of each voxel that are not covered
+///   by a neighbouring solid voxel, in the order +X, -X, +Y, -Y, +Z, -Z.
+/// - `water_heights`: The water height sampled at each voxel last frame, or `NaN` if not yet sampled.
+/// - `submerged_fractions`: The fraction of each voxel that was submerged last frame.
+///
+/// # Methods
+/// - `from_exposed_faces(exposed_faces: Vec<u8>) -> Self`:
+///   Creates a new `HullDrag` with no water samples taken yet.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct HullDrag {
+    pub exposed_faces: Vec<u8>,
+    pub water_heights: Vec<f32>,
+    pub submerged_fractions: Vec<f32>,
+}
+
+impl HullDrag {
+    /// Creates a new `HullDrag` from the exposed faces of each voxel.
+    pub fn from_exposed_faces(exposed_faces: Vec<u8>) -> Self {
+        let voxel_count = exposed_faces.len();
+
+        Self {
+            exposed_faces,
+            water_heights: vec![f32::NAN; voxel_count],
+            submerged_fractions: vec![0.0; voxel_count],
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the keel and underwater hull shape of a ship.
+///
+/// The `Keel` component gives a ship different resistance to moving forward and moving
+/// sideways through the water. A deep keel resists leeway strongly while letting the hull
+/// slip forward easily, which is what allows a ship to sail across the wind instead of
+/// being blown downwind.
+///
+/// # Fields
+/// - `forward_drag`: The drag in newtons per (metre per second)² when moving along the
+///   ship's centreline.
+/// - `lateral_drag`: The drag in newtons per (metre per second)² when moving sideways.
+/// - `center_of_lateral_resistance`: The point in ship-local space where the sideways
+///   resistance acts, usually below and slightly aft of the centre of mass.
+///
+/// # Methods
+/// - `new(forward_drag: f32, lateral_drag: f32, center_of_lateral_resistance: Vec3) -> Self`:
+///   Creates a new `Keel` with the given drag coefficients and centre of lateral resistance.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Keel {
+    pub forward_drag: f32,
+    pub lateral_drag: f32,
+    pub center_of_lateral_resistance: Vec3,
+}
+
+impl Keel {
+    /// Creates a new `Keel`.
+    ///
+    /// # Parameters
+    /// - `forward_drag`: The drag coefficient along the ship's centreline.
+    /// - `lateral_drag`: The drag coefficient across the ship's centreline.
+    /// - `center_of_lateral_resistance`: The point in ship-local space where the sideways
+    ///   resistance acts.
+    ///
+    /// # Returns
+    /// A new `Keel` instance.
+    pub fn new(forward_drag: f32, lateral_drag: f32, center_of_lateral_resistance: Vec3) -> Self {
+        Self {
+            forward_drag,
+            lateral_drag,
+            center_of_lateral_resistance,
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub use {hull_drag::*, keel::*, rudder::*};
+
+mod hull_drag;
+mod keel;
+mod rudder;
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents the rudder of a ship.
+///
+/// The `Rudder` component turns a ship by deflecting the water flowing past the stern.
+/// The force it produces grows with the square of the water speed at the rudder, so a
+/// ship that is barely moving can hardly be steered. The helm is set through
+/// `target_angle`, either by the player or by a helmsman, and the rudder swings towards
+/// it at a limited rate.
+///
+/// # Fields
+/// - `angle`: The current rudder angle in radians. Positive angles turn the ship to starboard.
+/// - `target_angle`: The rudder angle in radians the helm is asking for.
+/// - `max_angle`: The largest angle in radians the rudder can be put over to either side.
+/// - `turn_rate`: How quickly the rudder swings towards the target angle, in radians per second.
+/// - `lift`: The sideways force in newtons per (metre per second)² of water speed when the
+///   rudder is at its most effective angle.
+/// - `position`: The point in ship-local space where the rudder force acts.
+///
+/// # Methods
+/// - `new(max_angle: f32, turn_rate: f32, lift: f32, position: Vec3) -> Self`:
+///   Creates a new centred `Rudder`.
+/// - `set_target_angle(&mut self, angle: f32)`:
+///   Sets the angle the helm is asking for, clamped to the rudder's limits.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Rudder {
+    pub angle: f32,
+    pub target_angle: f32,
+    pub max_angle: f32,
+    pub turn_rate: f32,
+    pub lift: f32,
+    pub position: Vec3,
+}
+
+impl Rudder {
+    /// Creates a new centred `Rudder`.
+    ///
+    /// # Parameters
+    /// - `max_angle`: The largest angle in radians the rudder can be put over to either side.
+    /// - `turn_rate`: How quickly the rudder swings, in radians per second.
+    /// - `lift`: The sideways force coefficient of the rudder.
+    /// - `position`: The point in ship-local space where the rudder force acts.
+    ///
+    /// # Returns
+    /// A new `Rudder` instance with the helm amidships.
+    pub fn new(max_angle: f32, turn_rate: f32, lift: f32, position: Vec3) -> Self {
+        Self {
+            angle: 0.0,
+            target_angle: 0.0,
+            max_angle,
+            turn_rate,
+            lift,
+            position,
+        }
+    }
+
+    /// Sets the angle the helm is asking for.
+    ///
+    /// # Parameters
+    /// - `angle`: The requested rudder angle in radians. Positive angles turn the ship to
+    ///   starboard. The angle is clamped to `max_angle` on either side.
+    pub fn set_target_angle(&mut self, angle: f32) {
+        self.target_angle = angle.clamp(-self.max_angle, self.max_angle);
+    }
+}
@@ -0,0 +1,14 @@
+/// How quickly the helm is put over while the steering keys are held, in radians per second.
+pub const HELM_RATE: f32 = 0.6;
+
+/// Linear skin friction on the wetted faces of hull voxels, in newton-seconds per cubic metre.
+pub const VISCOUS_DRAG_COEFFICIENT: f32 = 2.0;
+
+/// Pressure drag coefficient of a voxel face pushing into the water.
+pub const PRESSURE_DRAG_COEFFICIENT: f32 = 1.0;
+
+/// Scales the impulse of water being slammed aside by a hull voxel entering the surface.
+pub const SLAMMING_COEFFICIENT: f32 = 2.0;
+
+/// The vertical speed in metres per second a voxel must enter the water at before it slams.
+pub const SLAMMING_SPEED_THRESHOLD: f32 = 1.5;
@@ -0,0 +1,3 @@
+pub use hydrodynamics_settings::*;
+
+mod hydrodynamics_settings;
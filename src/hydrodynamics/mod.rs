@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod systems;
+mod utils;
+
+/// Plugin for the hydrodynamic forces acting on a ship's hull.
+///
+/// The `HydrodynamicsPlugin` gives ships directional resistance and steering. The `Keel`
+/// resists leeway far more than forward motion, so sail force drives the ship ahead
+/// instead of sideways, and the `Rudder` turns the ship with a force that depends on how
+/// fast the water is flowing past it. The submerged voxels of every buoyant hull are
+/// resisted by skin friction and pressure drag as the hull heaves and rotates, and slam
+/// against the surface when they plunge into a wave, so hulls pitch, roll and settle in the
+/// waves without any uniform damping. Forward and sideways motion is left to the keel.
+/// Forces are applied through the hull's `ExternalForce` and slamming through its
+/// `ExternalImpulse`.
+///
+/// # Components
+/// - `HullDrag`: Tracks the exposed faces and water contact of each hull voxel.
+/// - `Keel`: Represents the keel and underwater hull shape of a ship.
+/// - `Rudder`: Represents the rudder of a ship and the angle the helm is set to.
+///
+/// # Systems
+/// - `apply_hull_drag`: Applies drag and slamming to the submerged voxels of buoyant hulls.
+/// - `apply_keel_forces`: Applies forward and sideways drag to ships moving through the water.
+/// - `apply_rudder_forces`: Applies the steering force of the rudder.
+/// - `control_rudder`: Sets the helm of the ship based on user input.
+/// - `initialize_hull_drag`: Finds the exposed faces of hull voxels once they are known.
+/// - `turn_rudder`: Swings each rudder towards the angle the helm is asking for.
+///
+/// This plugin is added to the app during the application setup and is configured to
+/// operate during the `AppStates::Running` state.
+pub struct HydrodynamicsPlugin;
+
+impl Plugin for HydrodynamicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<HullDrag>()
+            .register_type::<Keel>()
+            .register_type::<Rudder>()
+            .add_systems(Update, apply_hull_drag.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                apply_keel_forces.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                apply_rudder_forces.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, control_rudder.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                initialize_hull_drag.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                turn_rudder
+                    .after(control_rudder)
+                    .run_if(in_state(AppStates::Running)),
+            );
+    }
+}
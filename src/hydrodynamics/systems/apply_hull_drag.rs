@@ -0,0 +1,141 @@
+use bevy::math::Vec3;
+use bevy::prelude::{Query, Res, Time, Transform};
+use bevy_water::WaterParam;
+use bevy_xpbd_3d::components::{
+    AngularVelocity, CenterOfMass, ExternalForce, ExternalImpulse, LinearVelocity,
+};
+
+use crate::buoyancy_physics::{calculate_submersion, Buoyancy, WATER_DENSITY};
+use crate::hydrodynamics::utils::{
+    calculate_hull_drag_velocity, calculate_slamming_impulse, calculate_voxel_drag,
+};
+use crate::hydrodynamics::HullDrag;
+use crate::utils::get_water_height_at_position;
+
+/// Applies drag and slamming forces to the submerged voxels of buoyant hulls.
+///
+/// For every exposed hull voxel, the system samples the water height and works out how much
+/// of the voxel is submerged. The vertical speed of the wave surface is found from the change
+/// in water height since the last frame, and the voxel's velocity relative to the water, including
+/// the contribution of the hull's rotation, is used to apply skin friction and pressure drag
+/// at the voxel. Voxels that enter the water quickly also receive a slamming impulse.
+///
+/// Together these damp heave, pitch, roll and yaw so hulls settle naturally in the waves, and
+/// replace the uniform `LinearDamping` and `AngularDamping` buoyant bodies used to carry. The
+/// hull sliding forward and sideways is left to the `Keel`, as described in
+/// `calculate_hull_drag_velocity`, so that resistance is not applied twice.
+///
+/// # Parameters
+/// - `time`: Resource providing the time elapsed since the last frame.
+/// - `water`: A parameter used to sample the height of the waves.
+/// - `query`: Query to retrieve buoyant hulls with their drag state, velocities, centre of mass,
+///   external force and external impulse.
+#[allow(clippy::type_complexity)]
+pub fn apply_hull_drag(
+    time: Res<Time>,
+    water: WaterParam,
+    mut query: Query<(
+        &Buoyancy,
+        &mut HullDrag,
+        &Transform,
+        &LinearVelocity,
+        &AngularVelocity,
+        &CenterOfMass,
+        &mut ExternalForce,
+        &mut ExternalImpulse,
+    )>,
+) {
+    let delta_seconds = time.delta_seconds();
+    if delta_seconds <= 0.0 {
+        return;
+    }
+
+    for (
+        buoyancy,
+        mut hull_drag,
+        transform,
+        linear_velocity,
+        angular_velocity,
+        center_of_mass,
+        mut external_force,
+        mut external_impulse,
+    ) in query.iter_mut()
+    {
+        if hull_drag.exposed_faces.len() != buoyancy.voxels.len() {
+            continue;
+        }
+
+        let voxel_size = buoyancy.voxel_size;
+        let voxel_volume = voxel_size.powi(3);
+        let world_center_of_mass = transform.translation + transform.rotation * center_of_mass.0;
+
+        for (index, voxel) in buoyancy.voxels.iter().enumerate() {
+            let exposed_faces = hull_drag.exposed_faces[index];
+            if exposed_faces == 0 {
+                continue;
+            }
+
+            let world_position = transform.translation + transform.rotation * voxel.position;
+            let water_height = get_water_height_at_position(world_position, &water);
+            let submersion =
+                calculate_submersion(world_position, transform.rotation, voxel_size, 1, |_| {
+                    water_height
+                });
+            let submerged_fraction = submersion.volume / voxel_volume;
+
+            let previous_water_height = hull_drag.water_heights[index];
+            let previous_submerged_fraction = hull_drag.submerged_fractions[index];
+            hull_drag.water_heights[index] = water_height;
+            hull_drag.submerged_fractions[index] = submerged_fraction;
+
+            if submerged_fraction <= 0.0 {
+                continue;
+            }
+
+            let surface_speed = if previous_water_height.is_nan() {
+                0.0
+            } else {
+                (water_height - previous_water_height) / delta_seconds
+            };
+
+            let relative_velocity = calculate_hull_drag_velocity(
+                transform.rotation,
+                linear_velocity.0,
+                angular_velocity.0,
+                world_position - world_center_of_mass,
+                surface_speed,
+            );
+
+            let local_velocity = transform.rotation.inverse() * relative_velocity;
+            let drag = transform.rotation
+                * calculate_voxel_drag(
+                    exposed_faces,
+                    local_velocity,
+                    submerged_fraction,
+                    voxel_size,
+                    WATER_DENSITY,
+                );
+            external_force.apply_force_at_point(
+                drag,
+                submersion.center_of_buoyancy,
+                world_center_of_mass,
+            );
+
+            let slamming_impulse = calculate_slamming_impulse(
+                exposed_faces,
+                transform.rotation,
+                relative_velocity.y,
+                (submerged_fraction - previous_submerged_fraction) * voxel_size,
+                voxel_size,
+                WATER_DENSITY,
+            );
+            if slamming_impulse != Vec3::ZERO {
+                external_impulse.apply_impulse_at_point(
+                    slamming_impulse,
+                    world_position,
+                    world_center_of_mass,
+                );
+            }
+        }
+    }
+}
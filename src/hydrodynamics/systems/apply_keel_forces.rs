@@ -0,0 +1,41 @@
+use bevy::prelude::{Query, Transform};
+use bevy_xpbd_3d::components::{AngularVelocity, CenterOfMass, ExternalForce, LinearVelocity};
+
+use crate::hydrodynamics::utils::calculate_keel_force;
+use crate::hydrodynamics::Keel;
+
+/// Applies the drag of the keel and underwater hull to ships moving through the water.
+///
+/// For every entity with a `Keel`, the system works out the velocity of the hull at the
+/// centre of lateral resistance, including the contribution of the ship's rotation,
+/// converts it into ship-local space and applies the resulting drag through the ship's
+/// `ExternalForce`. Forward motion is lightly damped while leeway is strongly resisted.
+///
+/// # Parameters
+/// - `query`: Query to retrieve the keel, transform, velocities, centre of mass and external
+///   force of ships.
+pub fn apply_keel_forces(
+    mut query: Query<(
+        &Keel,
+        &Transform,
+        &LinearVelocity,
+        &AngularVelocity,
+        &CenterOfMass,
+        &mut ExternalForce,
+    )>,
+) {
+    for (keel, transform, linear_velocity, angular_velocity, center_of_mass, mut external_force) in
+        query.iter_mut()
+    {
+        let world_center_of_mass = transform.translation + transform.rotation * center_of_mass.0;
+        let world_point =
+            transform.translation + transform.rotation * keel.center_of_lateral_resistance;
+
+        let point_velocity =
+            linear_velocity.0 + angular_velocity.0.cross(world_point - world_center_of_mass);
+        let local_velocity = transform.rotation.inverse() * point_velocity;
+
+        let force = transform.rotation * calculate_keel_force(keel, local_velocity);
+        external_force.apply_force_at_point(force, world_point, world_center_of_mass);
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::{Query, Transform};
+use bevy_xpbd_3d::components::{AngularVelocity, CenterOfMass, ExternalForce, LinearVelocity};
+
+use crate::damage::Integrity;
+use crate::hydrodynamics::utils::calculate_rudder_force;
+use crate::hydrodynamics::Rudder;
+
+/// Applies the steering force of the rudder to ships moving through the water.
+///
+/// For every entity with a `Rudder`, the system works out the velocity of the water past
+/// the rudder, including the contribution of the ship's rotation, and applies the
+/// resulting sideways force at the rudder's position. Because the rudder sits far aft of
+/// the centre of mass, the force produces the yawing torque that turns the ship. A damaged
+/// rudder steers less, in proportion to its integrity.
+///
+/// # Parameters
+/// - `query`: Query to retrieve the rudder, transform, velocities, centre of mass, external
+///   force and rudder integrity of ships.
+#[allow(clippy::type_complexity)]
+pub fn apply_rudder_forces(
+    mut query: Query<(
+        &Rudder,
+        &Transform,
+        &LinearVelocity,
+        &AngularVelocity,
+        &CenterOfMass,
+        &mut ExternalForce,
+        Option<&Integrity>,
+    )>,
+) {
+    for (
+        rudder,
+        transform,
+        linear_velocity,
+        angular_velocity,
+        center_of_mass,
+        mut external_force,
+        integrity,
+    ) in query.iter_mut()
+    {
+        let world_center_of_mass = transform.translation + transform.rotation * center_of_mass.0;
+        let world_point = transform.translation + transform.rotation * rudder.position;
+
+        let point_velocity =
+            linear_velocity.0 + angular_velocity.0.cross(world_point - world_center_of_mass);
+        let local_velocity = transform.rotation.inverse() * point_velocity;
+
+        let force = transform.rotation
+            * calculate_rudder_force(rudder, local_velocity)
+            * integrity.map_or(1.0, |integrity| integrity.level);
+        external_force.apply_force_at_point(force, world_point, world_center_of_mass);
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::input::ButtonInput;
+use bevy::prelude::{KeyCode, Query, Res, Time, With};
+
+use crate::hydrodynamics::constants::HELM_RATE;
+use crate::hydrodynamics::Rudder;
+use crate::ship::PlayerShip;
+
+/// Sets the helm of the player's ship based on user input.
+///
+/// - Hold `,` to put the helm over to port.
+/// - Hold `.` to put the helm over to starboard.
+/// - Press `/` to centre the helm.
+///
+/// # Parameters
+/// - `input`: Resource that captures keyboard input.
+/// - `time`: Resource providing the delta time for the game.
+/// - `rudders`: Query to fetch and modify the `Rudder` components of the player's ship.
+pub fn control_rudder(
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut rudders: Query<&mut Rudder, With<PlayerShip>>,
+) {
+    let mut helm_delta = 0.0;
+
+    if input.pressed(KeyCode::Comma) {
+        helm_delta -= HELM_RATE * time.delta_seconds();
+    }
+    if input.pressed(KeyCode::Period) {
+        helm_delta += HELM_RATE * time.delta_seconds();
+    }
+
+    for mut rudder in rudders.iter_mut() {
+        if input.just_pressed(KeyCode::Slash) {
+            rudder.set_target_angle(0.0);
+        } else if helm_delta != 0.0 {
+            let target_angle = rudder.target_angle + helm_delta;
+            rudder.set_target_angle(target_angle);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::{Changed, Commands, Entity, Query};
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::hydrodynamics::utils::calculate_exposed_faces;
+use crate::hydrodynamics::HullDrag;
+
+/// Prepares the drag state of buoyant hulls once their voxels are known.
+///
+/// Whenever the voxels of a `Buoyancy` component change and their solidity is up to date,
+/// this system finds the exposed faces of the hull voxels and inserts a fresh `HullDrag`
+/// component on the body.
+///
+/// # Parameters
+/// - `commands`: Commands for inserting the `HullDrag` component.
+/// - `query`: Query to retrieve bodies whose `Buoyancy` has changed.
+pub fn initialize_hull_drag(
+    mut commands: Commands,
+    query: Query<(Entity, &Buoyancy), Changed<Buoyancy>>,
+) {
+    for (entity, buoyancy) in query.iter() {
+        if buoyancy.needs_update {
+            continue;
+        }
+
+        let exposed_faces = calculate_exposed_faces(&buoyancy.voxels, buoyancy.voxel_size);
+        commands
+            .entity(entity)
+            .insert(HullDrag::from_exposed_faces(exposed_faces));
+    }
+}
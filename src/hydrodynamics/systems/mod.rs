@@ -0,0 +1,11 @@
+pub use {
+    apply_hull_drag::*, apply_keel_forces::*, apply_rudder_forces::*, control_rudder::*,
+    initialize_hull_drag::*, turn_rudder::*,
+};
+
+mod apply_hull_drag;
+mod apply_keel_forces;
+mod apply_rudder_forces;
+mod control_rudder;
+mod initialize_hull_drag;
+mod turn_rudder;
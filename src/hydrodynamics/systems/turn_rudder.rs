@@ -0,0 +1,27 @@
+use bevy::prelude::{Query, Res, Time};
+
+use crate::crew_management::Manning;
+use crate::hydrodynamics::Rudder;
+
+/// Swings each rudder towards the angle the helm is asking for.
+///
+/// The rudder cannot move instantly; it turns towards its `target_angle` at no more than
+/// its `turn_rate`. This applies regardless of whether the helm was set by the player or
+/// by a helmsman. A ship's rudder only answers the helm while its helm station is manned,
+/// and stays where it is while the helm is left unmanned.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `rudders`: Query to fetch and modify the `Rudder` components, along with the manning of
+///   the ship's stations.
+pub fn turn_rudder(time: Res<Time>, mut rudders: Query<(&mut Rudder, Option<&Manning>)>) {
+    for (mut rudder, manning) in rudders.iter_mut() {
+        let helm = manning.map_or(1.0, |manning| manning.helm);
+        let max_step = rudder.turn_rate * helm * time.delta_seconds();
+        let step = (rudder.target_angle - rudder.angle).clamp(-max_step, max_step);
+
+        if step != 0.0 {
+            rudder.angle += step;
+        }
+    }
+}
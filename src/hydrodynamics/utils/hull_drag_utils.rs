@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use bevy::math::{IVec3, Quat, Vec3};
+
+use crate::buoyancy_physics::Voxel;
+use crate::hydrodynamics::constants::{
+    PRESSURE_DRAG_COEFFICIENT, SLAMMING_COEFFICIENT, SLAMMING_SPEED_THRESHOLD,
+    VISCOUS_DRAG_COEFFICIENT,
+};
+
+/// The outward normals of the six faces of a voxel, in the order used by exposed face bitmasks.
+const FACE_NORMALS: [Vec3; 6] = [
+    Vec3::X,
+    Vec3::NEG_X,
+    Vec3::Y,
+    Vec3::NEG_Y,
+    Vec3::Z,
+    Vec3::NEG_Z,
+];
+
+/// Finds which faces of each solid voxel lie on the surface of the hull.
+///
+/// A face is exposed when the neighbouring voxel in that direction is missing or not solid.
+/// Non-solid voxels have no exposed faces.
+///
+/// # Arguments
+///
+/// * `voxels` - The voxels of the hull, laid out on a regular grid.
+/// * `voxel_size` - The spacing of the voxel grid.
+///
+/// # Returns
+///
+/// A bitmask of exposed faces for each voxel, in the same order as `voxels`.
+pub fn calculate_exposed_faces(voxels: &[Voxel], voxel_size: f32) -> Vec<u8> {
+    let Some(origin) = voxels.first().map(|voxel| voxel.position) else {
+        return Vec::new();
+    };
+    let grid_cell = |position: Vec3| ((position - origin) / voxel_size).round().as_ivec3();
+
+    let solid_cells: HashSet<IVec3> = voxels
+        .iter()
+        .filter(|voxel| voxel.is_solid)
+        .map(|voxel| grid_cell(voxel.position))
+        .collect();
+
+    voxels
+        .iter()
+        .map(|voxel| {
+            if !voxel.is_solid {
+                return 0;
+            }
+
+            let cell = grid_cell(voxel.position);
+            FACE_NORMALS
+                .iter()
+                .enumerate()
+                .filter(|(_, normal)| !solid_cells.contains(&(cell + normal.as_ivec3())))
+                .fold(0, |faces, (index, _)| faces | 1 << index)
+        })
+        .collect()
+}
+
+/// Calculates the velocity of a hull voxel relative to the water that hull drag resists.
+///
+/// The `Keel` already resists the hull sliding forward and sideways through the water, so
+/// the parts of the hull's linear velocity along its centreline and across it are left out
+/// here to avoid counting that resistance twice. What remains is the hull's heave along its
+/// own vertical axis and the motion of the voxel due to the hull's rotation, less the vertical
+/// motion of the wave surface. Hull drag therefore damps heave, pitch, roll and yaw, taking
+/// the place of the uniform linear and angular damping bodies would otherwise need.
+///
+/// # Arguments
+///
+/// * `rotation` - The rotation of the hull.
+/// * `linear_velocity` - The linear velocity of the hull's centre of mass.
+/// * `angular_velocity` - The angular velocity of the hull.
+/// * `offset` - The world-space offset of the voxel from the hull's centre of mass.
+/// * `surface_speed` - The vertical speed of the wave surface at the voxel.
+///
+/// # Returns
+///
+/// A `Vec3` representing the relative velocity of the voxel in world space.
+pub fn calculate_hull_drag_velocity(
+    rotation: Quat,
+    linear_velocity: Vec3,
+    angular_velocity: Vec3,
+    offset: Vec3,
+    surface_speed: f32,
+) -> Vec3 {
+    let hull_up = rotation.mul_vec3(Vec3::Y);
+    let heave_velocity = hull_up * linear_velocity.dot(hull_up);
+
+    heave_velocity + angular_velocity.cross(offset) - Vec3::Y * surface_speed
+}
+
+/// Calculates the drag on a hull voxel moving through the water.
+///
+/// Every exposed face contributes linear skin friction, and each exposed face leading into
+/// the flow contributes quadratic pressure drag along its normal. Both are scaled by how
+/// much of the voxel is submerged.
+///
+/// # Arguments
+///
+/// * `exposed_faces` - The exposed face bitmask of the voxel.
+/// * `velocity` - The velocity of the voxel relative to the water, in hull-local space.
+/// * `submerged_fraction` - The fraction of the voxel below the water surface.
+/// * `voxel_size` - The size of the voxel.
+/// * `water_density` - The density of the water.
+///
+/// # Returns
+///
+/// A `Vec3` representing the drag force in hull-local space.
+pub fn calculate_voxel_drag(
+    exposed_faces: u8,
+    velocity: Vec3,
+    submerged_fraction: f32,
+    voxel_size: f32,
+    water_density: f32,
+) -> Vec3 {
+    let face_area = voxel_size * voxel_size;
+    let wetted_area = exposed_faces.count_ones() as f32 * face_area * submerged_fraction;
+
+    let viscous_drag = -velocity * VISCOUS_DRAG_COEFFICIENT * wetted_area;
+
+    let pressure_drag = FACE_NORMALS
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| exposed_faces & (1 << index) != 0)
+        .map(|(_, normal)| {
+            let normal_speed = velocity.dot(*normal);
+            if normal_speed <= 0.0 {
+                return Vec3::ZERO;
+            }
+
+            -*normal
+                * 0.5
+                * water_density
+                * PRESSURE_DRAG_COEFFICIENT
+                * face_area
+                * submerged_fraction
+                * normal_speed
+                * normal_speed
+        })
+        .sum::<Vec3>();
+
+    viscous_drag + pressure_drag
+}
+
+/// Calculates the impulse of a hull voxel slamming into the water surface.
+///
+/// When a voxel enters the water faster than `SLAMMING_SPEED_THRESHOLD`, the water under its
+/// downward-facing exposed faces is pushed aside abruptly. The impulse is the momentum given
+/// to that water: its mass over the depth entered this frame, moving at the entry speed.
+///
+/// # Arguments
+///
+/// * `exposed_faces` - The exposed face bitmask of the voxel.
+/// * `rotation` - The rotation of the hull.
+/// * `vertical_velocity` - The vertical velocity of the voxel relative to the water surface.
+/// * `depth_entered` - How much deeper the voxel is submerged than on the previous frame.
+/// * `voxel_size` - The size of the voxel.
+/// * `water_density` - The density of the water.
+///
+/// # Returns
+///
+/// A `Vec3` representing the upward slamming impulse in world space, or zero if the voxel
+/// is not slamming.
+pub fn calculate_slamming_impulse(
+    exposed_faces: u8,
+    rotation: Quat,
+    vertical_velocity: f32,
+    depth_entered: f32,
+    voxel_size: f32,
+    water_density: f32,
+) -> Vec3 {
+    if vertical_velocity > -SLAMMING_SPEED_THRESHOLD || depth_entered <= 0.0 {
+        return Vec3::ZERO;
+    }
+
+    let face_area = voxel_size * voxel_size;
+    let impact_area: f32 = FACE_NORMALS
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| exposed_faces & (1 << index) != 0)
+        .map(|(_, normal)| face_area * (-rotation.mul_vec3(*normal).y).max(0.0))
+        .sum();
+
+    Vec3::Y
+        * SLAMMING_COEFFICIENT
+        * water_density
+        * impact_area
+        * depth_entered
+        * -vertical_velocity
+}
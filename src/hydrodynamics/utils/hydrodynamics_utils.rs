@@ -0,0 +1,50 @@
+use bevy::math::Vec3;
+
+use crate::hydrodynamics::{Keel, Rudder};
+use crate::ship::{SHIP_FORWARD, SHIP_STARBOARD};
+
+/// Calculates the drag a keel produces as the hull moves through the water.
+///
+/// The velocity is split into a component along the ship's centreline and a component
+/// across it. Each component is resisted by quadratic drag using the matching coefficient
+/// of the keel, so sideways motion is damped far more strongly than forward motion.
+///
+/// # Arguments
+///
+/// * `keel` - The keel resisting the motion.
+/// * `velocity` - The velocity of the hull through the water, in ship-local space.
+///
+/// # Returns
+///
+/// A `Vec3` representing the drag force in ship-local space.
+pub fn calculate_keel_force(keel: &Keel, velocity: Vec3) -> Vec3 {
+    let forward_speed = velocity.dot(SHIP_FORWARD);
+    let lateral_speed = velocity.dot(SHIP_STARBOARD);
+
+    let forward_force = -forward_speed * forward_speed.abs() * keel.forward_drag;
+    let lateral_force = -lateral_speed * lateral_speed.abs() * keel.lateral_drag;
+
+    SHIP_FORWARD * forward_force + SHIP_STARBOARD * lateral_force
+}
+
+/// Calculates the sideways force a rudder produces from the water flowing past it.
+///
+/// The rudder acts like a small wing. Its lift peaks at 45 degrees of deflection and
+/// scales with the square of the water speed along the ship's centreline, so the rudder
+/// has no effect when the ship is stopped and reverses its effect when going astern.
+///
+/// # Arguments
+///
+/// * `rudder` - The rudder producing the force.
+/// * `velocity` - The velocity of the rudder through the water, in ship-local space.
+///
+/// # Returns
+///
+/// A `Vec3` representing the rudder force in ship-local space. With a positive rudder
+/// angle and headway, the force pushes the stern to port so the bow swings to starboard.
+pub fn calculate_rudder_force(rudder: &Rudder, velocity: Vec3) -> Vec3 {
+    let water_speed = velocity.dot(SHIP_FORWARD);
+    let lift = rudder.lift * water_speed * water_speed.abs() * (2.0 * rudder.angle).sin();
+
+    -SHIP_STARBOARD * lift
+}
@@ -0,0 +1,4 @@
+pub use {hull_drag_utils::*, hydrodynamics_utils::*};
+
+mod hull_drag_utils;
+mod hydrodynamics_utils;
@@ -5,44 +5,84 @@ use bevy_tnua::prelude::TnuaControllerPlugin;
 use bevy_tnua_xpbd3d::TnuaXpbd3dPlugin;
 use big_brain::BigBrainPlugin;
 
-use ai_eating_behavior::AiEatingBehaviorPlugin;
+use ai_captain_behavior::AiCaptainBehaviorPlugin;
+use ai_gunnery_behavior::AiGunneryBehaviorPlugin;
+use ai_healing_behavior::AiHealingBehaviorPlugin;
+use ai_morale_behavior::AiMoraleBehaviorPlugin;
 use ai_navigation::AiNavigationPlugin;
-use ai_sleeping_behavior::AiSleepingBehaviorPlugin;
+use ai_need_behavior::AiNeedBehaviorPlugin;
+use ai_order_behavior::AiOrderBehaviorPlugin;
+use ai_production_behavior::AiProductionBehaviorPlugin;
+use ai_pumping_behavior::AiPumpingBehaviorPlugin;
+use ai_repair_behavior::AiRepairBehaviorPlugin;
+use ai_station_behavior::AiStationBehaviorPlugin;
 use area_visibility::AreaVisibilityPlugin;
 use asset_management::AssetManagementPlugin;
 use atmospheric_lighting::AtmosphericLightingPlugin;
 use buoyancy_physics::BuoyancyPhysicsPlugin;
 use camera_control::CameraControlPlugin;
+use claims::ClaimsPlugin;
 use collider_management::ColliderManagementPlugin;
+use crew_health::CrewHealthPlugin;
 use crew_management::CrewManagementPlugin;
+use crew_morale::CrewMoralePlugin;
+use crew_orders::CrewOrdersPlugin;
+use crew_watches::CrewWatchesPlugin;
+use damage::DamagePlugin;
+use flooding::FloodingPlugin;
 use food::FoodPlugin;
+use gunnery::GunneryPlugin;
+use hydrodynamics::HydrodynamicsPlugin;
 use navmesh::NavMeshPlugin;
 use ocean::OceanPlugin;
 use player::PlayerPlugin;
 use player_input::PlayerInputPlugin;
+use production::ProductionPlugin;
+use sailing::SailingPlugin;
 use ship::ShipPlugin;
 use ship_items::ShipItemsPlugin;
 use sun::SunCyclePlugin;
+use wind::WindPlugin;
 
-mod ai_eating_behavior;
+pub mod ai_captain_behavior;
+mod ai_gunnery_behavior;
+mod ai_healing_behavior;
+mod ai_morale_behavior;
 mod ai_navigation;
-mod ai_sleeping_behavior;
+pub mod ai_need_behavior;
+mod ai_order_behavior;
+mod ai_production_behavior;
+mod ai_pumping_behavior;
+mod ai_repair_behavior;
+mod ai_station_behavior;
 mod area_visibility;
 mod asset_management;
 mod atmospheric_lighting;
-mod buoyancy_physics;
+pub mod buoyancy_physics;
 mod camera_control;
+pub mod claims;
 mod collider_management;
-mod crew_management;
-mod food;
+pub mod crew_health;
+pub mod crew_management;
+pub mod crew_morale;
+pub mod crew_orders;
+pub mod crew_watches;
+pub mod damage;
+pub mod flooding;
+pub mod food;
+pub mod gunnery;
+pub mod hydrodynamics;
 mod navmesh;
 mod ocean;
 mod player;
 mod player_input;
-mod ship;
-mod ship_items;
+pub mod production;
+pub mod sailing;
+pub mod ship;
+pub mod ship_items;
 mod sun;
-mod utils;
+pub mod utils;
+mod wind;
 
 /// PluginGroup for the Empire of Wind game.
 ///
@@ -55,27 +95,47 @@ pub struct EmpireOfWindPlugins;
 impl PluginGroup for EmpireOfWindPlugins {
     fn build(self) -> PluginGroupBuilder {
         PluginGroupBuilder::start::<Self>()
-            .add(AiEatingBehaviorPlugin)
+            .add(AiCaptainBehaviorPlugin)
+            .add(AiGunneryBehaviorPlugin)
+            .add(AiHealingBehaviorPlugin)
+            .add(AiMoraleBehaviorPlugin)
             .add(AiNavigationPlugin)
-            .add(AiSleepingBehaviorPlugin)
+            .add(AiNeedBehaviorPlugin)
+            .add(AiOrderBehaviorPlugin)
+            .add(AiProductionBehaviorPlugin)
+            .add(AiPumpingBehaviorPlugin)
+            .add(AiRepairBehaviorPlugin)
+            .add(AiStationBehaviorPlugin)
             .add(AreaVisibilityPlugin)
             .add(AssetManagementPlugin)
             .add(AtmosphericLightingPlugin)
             .add(BigBrainPlugin::new(PreUpdate))
             .add(BuoyancyPhysicsPlugin)
             .add(CameraControlPlugin)
+            .add(ClaimsPlugin)
             .add(ColliderManagementPlugin)
             .add(ComponentsFromGltfPlugin::default())
+            .add(CrewHealthPlugin)
             .add(CrewManagementPlugin)
+            .add(CrewMoralePlugin)
+            .add(CrewOrdersPlugin)
+            .add(CrewWatchesPlugin)
+            .add(DamagePlugin)
+            .add(FloodingPlugin)
             .add(FoodPlugin)
+            .add(GunneryPlugin)
+            .add(HydrodynamicsPlugin)
             .add(NavMeshPlugin)
             .add(OceanPlugin)
             .add(PlayerPlugin)
             .add(PlayerInputPlugin)
+            .add(ProductionPlugin)
+            .add(SailingPlugin)
             .add(ShipPlugin)
             .add(ShipItemsPlugin)
             .add(SunCyclePlugin)
             .add(TnuaControllerPlugin)
             .add(TnuaXpbd3dPlugin)
+            .add(WindPlugin)
     }
 }
@@ -7,6 +7,8 @@ use bevy::{
 use bevy_editor_pls::prelude::*;
 use bevy_xpbd_3d::prelude::*;
 
+use empire_of_wind::buoyancy_physics::VoxelCache;
+use empire_of_wind::crew_management::CrewRecords;
 use empire_of_wind::EmpireOfWindPlugins;
 
 fn main() {
@@ -40,6 +42,8 @@ fn main() {
         .add_plugins(PhysicsPlugins::default())
         .add_plugins(EditorPlugin::default())
         .add_plugins(EmpireOfWindPlugins)
-        .add_plugins(LogDiagnosticsPlugin::default());
+        .add_plugins(LogDiagnosticsPlugin::default())
+        .insert_resource(VoxelCache::with_directory("cache/voxels"))
+        .insert_resource(CrewRecords::with_directory("saves/crew"));
     app.run();
 }
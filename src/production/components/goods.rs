@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The kinds of goods stowed aboard a ship.
+///
+/// - `SaltPork`: Salted pork in casks, eaten as a raw ration or cooked.
+/// - `Biscuit`: Ship's biscuit, eaten as a raw ration or cooked.
+/// - `Water`: Fresh water in casks, which goes into the cooking.
+/// - `Meal`: A hot meal cooked in the galley.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect, Serialize, Deserialize, Default,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub enum Goods {
+    #[default]
+    SaltPork,
+    Biscuit,
+    Water,
+    Meal,
+}
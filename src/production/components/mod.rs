@@ -0,0 +1,6 @@
+pub use {goods::*, recipe::*, stockpile::*, workshop::*};
+
+mod goods;
+mod recipe;
+mod stockpile;
+mod workshop;
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::crew_management::Skill;
+use crate::production::Goods;
+
+/// What a workshop makes and what it takes to make it.
+///
+/// # Fields
+/// - `inputs`: The goods used up by each job, and how much of each.
+/// - `output`: The goods each job produces.
+/// - `output_quantity`: How much of the output each job produces.
+/// - `work_time`: The seconds of work a fully effective crew member takes to finish a job.
+/// - `skill`: The skill the work calls for.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Serialize, Deserialize)]
+pub struct Recipe {
+    pub inputs: Vec<(Goods, f32)>,
+    pub output: Goods,
+    pub output_quantity: f32,
+    pub work_time: f32,
+    pub skill: Skill,
+}
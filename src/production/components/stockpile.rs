@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::production::Goods;
+
+/// A store of goods kept aboard a ship, such as the casks of provisions in the hold.
+///
+/// The `Stockpile` component is added to entities inside a ship's hierarchy. Workshops draw
+/// the goods their recipes call for from the stockpiles aboard their ship and put what they
+/// produce into them, and the crew eat from the stockpiles of food. Some goods slowly spoil.
+///
+/// # Fields
+/// - `goods`: The kind of goods kept in the stockpile.
+/// - `quantity`: How much of the goods is left.
+/// - `capacity`: How much of the goods the stockpile can hold.
+/// - `spoil_rate`: The fraction of the goods left that spoils each second.
+///
+/// # Methods
+/// - `take(&mut self, quantity: f32) -> f32`: Takes up to the given quantity from the stockpile.
+/// - `put(&mut self, quantity: f32) -> f32`: Puts up to the given quantity into the stockpile.
+/// - `spoil(&mut self, seconds: f32)`: Spoils the goods over the given time.
+/// - `is_empty(&self) -> bool`: Returns whether the stockpile has run out.
+/// - `is_full(&self) -> bool`: Returns whether the stockpile has no room left.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Stockpile {
+    pub goods: Goods,
+    pub quantity: f32,
+    pub capacity: f32,
+    pub spoil_rate: f32,
+}
+
+impl Stockpile {
+    /// Takes up to the given quantity from the stockpile.
+    ///
+    /// # Returns
+    /// The quantity taken, which is less than asked for if the stockpile runs out.
+    pub fn take(&mut self, quantity: f32) -> f32 {
+        let taken = quantity.clamp(0.0, self.quantity);
+        self.quantity -= taken;
+        taken
+    }
+
+    /// Puts up to the given quantity into the stockpile.
+    ///
+    /// # Returns
+    /// The quantity put in, which is less than offered if the stockpile fills up.
+    pub fn put(&mut self, quantity: f32) -> f32 {
+        let put = quantity.clamp(0.0, (self.capacity - self.quantity).max(0.0));
+        self.quantity += put;
+        put
+    }
+
+    /// Spoils the goods over the given number of seconds.
+    pub fn spoil(&mut self, seconds: f32) {
+        self.quantity *= (1.0 - self.spoil_rate * seconds).max(0.0);
+        if self.quantity < f32::EPSILON {
+            self.quantity = 0.0;
+        }
+    }
+
+    /// Returns whether the stockpile has run out.
+    pub fn is_empty(&self) -> bool {
+        self.quantity <= 0.0
+    }
+
+    /// Returns whether the stockpile has no room left.
+    pub fn is_full(&self) -> bool {
+        self.quantity >= self.capacity
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::production::Recipe;
+
+/// A workshop aboard a ship, such as the galley, where goods are made from other goods.
+///
+/// The `Workshop` component is added to a `Station`, and the crew member the duty roster
+/// assigns to the station works it. A job is started by drawing the inputs of the recipe from
+/// the stockpiles aboard the ship, and once enough work has gone into it the output is put
+/// into a stockpile with room for it. A job that is interrupted is picked up where it was left.
+///
+/// # Fields
+/// - `recipe`: What the workshop makes and what it takes to make it.
+/// - `progress`: How far along the current job is, from `0.0` to `1.0`, or `None` if there is
+///   no job under way.
+///
+/// # Methods
+/// - `new(recipe: Recipe) -> Self`: Creates an idle workshop for the given recipe.
+/// - `start(&mut self)`: Starts a new job.
+/// - `work(&mut self, amount: f32) -> bool`: Puts work into the current job.
+/// - `finish(&mut self)`: Finishes the current job.
+/// - `is_working(&self) -> bool`: Returns whether a job is under way.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Workshop {
+    pub recipe: Recipe,
+    pub progress: Option<f32>,
+}
+
+impl Workshop {
+    /// Creates an idle workshop for the given recipe.
+    pub fn new(recipe: Recipe) -> Self {
+        Self {
+            recipe,
+            progress: None,
+        }
+    }
+
+    /// Starts a new job.
+    pub fn start(&mut self) {
+        self.progress = Some(0.0);
+    }
+
+    /// Puts the given seconds of fully effective work into the current job.
+    ///
+    /// # Returns
+    /// Whether the job is done.
+    pub fn work(&mut self, amount: f32) -> bool {
+        let Some(progress) = self.progress.as_mut() else {
+            return false;
+        };
+
+        *progress = (*progress + amount / self.recipe.work_time.max(f32::EPSILON)).min(1.0);
+        *progress >= 1.0
+    }
+
+    /// Finishes the current job, leaving the workshop idle.
+    pub fn finish(&mut self) {
+        self.progress = None;
+    }
+
+    /// Returns whether a job is under way.
+    pub fn is_working(&self) -> bool {
+        self.progress.is_some()
+    }
+}
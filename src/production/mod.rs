@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+pub use components::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod systems;
+mod utils;
+
+/// Plugin for the production of goods aboard ships.
+///
+/// The `ProductionPlugin` keeps the stockpiles of goods a ship carries and the workshops that
+/// make goods from other goods, such as the galley that cooks provisions into meals. Each
+/// workshop follows a `Recipe` and is worked by the crew member assigned to its station.
+///
+/// # Components
+/// - `Goods`: The kinds of goods stowed aboard a ship.
+/// - `Stockpile`: A store of goods kept aboard a ship.
+/// - `Workshop`: A workshop aboard a ship where goods are made from other goods.
+///
+/// # Systems
+/// - `spoil_stockpiles`: Spoils the goods in each stockpile over time.
+///
+/// All systems are run during the `Update` stage and only when the application is in the `AppStates::Running` state.
+pub struct ProductionPlugin;
+
+impl Plugin for ProductionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Goods>()
+            .register_type::<Recipe>()
+            .register_type::<Stockpile>()
+            .register_type::<Workshop>()
+            .add_systems(
+                Update,
+                spoil_stockpiles.run_if(in_state(AppStates::Running)),
+            );
+    }
+}
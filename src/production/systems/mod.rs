@@ -0,0 +1,3 @@
+pub use spoil_stockpiles::*;
+
+mod spoil_stockpiles;
@@ -0,0 +1,14 @@
+use bevy::prelude::{Query, Res, Time};
+
+use crate::production::Stockpile;
+
+/// Spoils the goods in each stockpile over time, at the stockpile's spoil rate.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `stockpiles`: Query to retrieve and modify the stockpiles.
+pub fn spoil_stockpiles(time: Res<Time>, mut stockpiles: Query<&mut Stockpile>) {
+    for mut stockpile in stockpiles.iter_mut() {
+        stockpile.spoil(time.delta_seconds());
+    }
+}
@@ -0,0 +1,3 @@
+pub use production_utils::*;
+
+mod production_utils;
@@ -0,0 +1,30 @@
+use crate::production::{Goods, Recipe, Stockpile};
+
+/// Calculates whether a job of a recipe can be started from the given stockpiles.
+///
+/// # Arguments
+/// - `recipe`: The recipe to make.
+/// - `stockpiles`: The stockpiles the inputs are drawn from and the output is put into.
+///
+/// # Returns
+/// `true` if the stockpiles hold enough of every input and have room for the output.
+pub fn can_start_job(recipe: &Recipe, stockpiles: &[&Stockpile]) -> bool {
+    let available = |goods: Goods| {
+        stockpiles
+            .iter()
+            .filter(|stockpile| stockpile.goods == goods)
+            .map(|stockpile| stockpile.quantity)
+            .sum::<f32>()
+    };
+    let room = stockpiles
+        .iter()
+        .filter(|stockpile| stockpile.goods == recipe.output)
+        .map(|stockpile| (stockpile.capacity - stockpile.quantity).max(0.0))
+        .sum::<f32>();
+
+    recipe
+        .inputs
+        .iter()
+        .all(|(goods, quantity)| available(*goods) >= *quantity)
+        && room >= recipe.output_quantity
+}
@@ -0,0 +1,3 @@
+pub use sail::*;
+
+mod sail;
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Represents a sail rigged on a ship.
+///
+/// The `Sail` component is added to child entities of a `Ship`. The entity's `Transform`
+/// marks the sail's centre of effort relative to the ship, which is where the sail force
+/// is applied. Because the centre of effort sits high above the hull, the sideways part of
+/// the force heels the ship over as well as pushing it through the water.
+///
+/// # Fields
+/// - `area`: The area of canvas in square metres when the sail is fully set.
+/// - `trim`: The sheet angle in radians between the sail and the ship's centreline. Small
+///   angles suit sailing close to the wind, large angles suit running before it.
+/// - `furl`: How much of the sail is furled, from `0.0` (fully set) to `1.0` (fully furled).
+///
+/// # Methods
+/// - `new(area: f32) -> Self`:
+///   Creates a new fully set `Sail` with the given area and a default trim.
+/// - `effective_area(&self) -> f32`:
+///   Returns the area of canvas currently exposed to the wind.
+#[derive(Debug, Clone, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Sail {
+    pub area: f32,
+    pub trim: f32,
+    pub furl: f32,
+}
+
+impl Sail {
+    /// Creates a new fully set `Sail`.
+    ///
+    /// # Parameters
+    /// - `area`: The area of canvas in square metres.
+    ///
+    /// # Returns
+    /// A new `Sail` trimmed at 45 degrees to the centreline.
+    pub fn new(area: f32) -> Self {
+        Self {
+            area,
+            trim: 45.0f32.to_radians(),
+            furl: 0.0,
+        }
+    }
+
+    /// Returns the area of canvas currently exposed to the wind.
+    ///
+    /// # Returns
+    /// The sail area in square metres, reduced by how much of the sail is furled.
+    pub fn effective_area(&self) -> f32 {
+        self.area * (1.0 - self.furl.clamp(0.0, 1.0))
+    }
+}
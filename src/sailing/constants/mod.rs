@@ -0,0 +1,3 @@
+pub use sail_settings::*;
+
+mod sail_settings;
@@ -0,0 +1,22 @@
+use std::f32::consts::FRAC_PI_2;
+
+/// The density of air in kilograms per cubic metre, used to turn wind speed into pressure.
+pub const AIR_DENSITY: f32 = 1.225;
+
+/// The force coefficient of a sail when it stands square to the wind.
+///
+/// A sail is treated as a flat plate, so the force it produces scales with the sine of
+/// the angle between the sail and the apparent wind, up to this coefficient.
+pub const SAIL_FORCE_COEFFICIENT: f32 = 1.2;
+
+/// The smallest sheet angle in radians a sail can be trimmed to.
+pub const SAIL_TRIM_MIN: f32 = 0.1745; // 10 degrees
+
+/// The largest sheet angle in radians a sail can be trimmed to.
+pub const SAIL_TRIM_MAX: f32 = FRAC_PI_2;
+
+/// How quickly the sheets are hauled in or eased out, in radians per second.
+pub const SAIL_TRIM_RATE: f32 = 0.5;
+
+/// How quickly sails are furled or set, as a fraction of the sail per second.
+pub const SAIL_FURL_RATE: f32 = 0.25;
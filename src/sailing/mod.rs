@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+pub use components::*;
+pub use constants::*;
+use systems::*;
+pub use utils::*;
+
+use crate::asset_management::states::app_states::AppStates;
+
+mod components;
+mod constants;
+mod systems;
+mod utils;
+
+/// Plugin for driving ships under sail.
+///
+/// The `SailingPlugin` turns the wind into propulsion. Each `Sail` rigged on a ship converts
+/// the apparent wind into a force that is applied through the ship's `ExternalForce`, the
+/// same path used by buoyancy, so ships are pushed forward and heeled over by the wind.
+///
+/// # Components
+/// - `Sail`: Represents a sail rigged on a ship, with its area, trim and furl state.
+///
+/// # Systems
+/// - `apply_sail_forces`: Applies the force of the wind on each sail to the ship carrying it.
+/// - `control_sails`: Adjusts the trim and furl state of the sails based on user input.
+///
+/// This plugin is added to the app during the application setup and is configured to
+/// operate during the `AppStates::Running` state.
+pub struct SailingPlugin;
+
+impl Plugin for SailingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Sail>()
+            .add_systems(
+                Update,
+                apply_sail_forces.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(Update, control_sails.run_if(in_state(AppStates::Running)));
+    }
+}
@@ -0,0 +1,59 @@
+use bevy::hierarchy::Parent;
+use bevy::prelude::{Query, Res, Transform, With};
+use bevy_xpbd_3d::components::{CenterOfMass, ExternalForce, LinearVelocity};
+
+use crate::damage::Integrity;
+use crate::sailing::utils::calculate_sail_force;
+use crate::sailing::Sail;
+use crate::ship::Ship;
+use crate::wind::Wind;
+
+/// Applies the force of the wind on each sail to the ship carrying it.
+///
+/// For every `Sail`, the system works out the apparent wind felt by its ship (the true
+/// wind minus the ship's own velocity), calculates the resulting sail force, and applies
+/// it to the ship's `ExternalForce` at the sail's centre of effort. Applying the force at
+/// the centre of effort, high above the centre of mass, produces the heeling torque as
+/// well as the forward thrust. Torn sails catch less wind, in proportion to their integrity.
+///
+/// # Parameters
+/// - `wind`: Resource describing the wind blowing across the game world.
+/// - `sail_query`: Query to retrieve sails, their parent ship, their local transform and integrity.
+/// - `ship_query`: Query to retrieve the transform, velocity, centre of mass and external
+///   force of ships.
+///
+/// # Details
+/// Sails are only driven once their ship has been configured as a dynamic body by
+/// `read_buoyancy_objects`; until then the ship has no `ExternalForce` and is skipped.
+pub fn apply_sail_forces(
+    wind: Res<Wind>,
+    sail_query: Query<(&Sail, &Parent, &Transform, Option<&Integrity>)>,
+    mut ship_query: Query<
+        (
+            &Transform,
+            &LinearVelocity,
+            &CenterOfMass,
+            &mut ExternalForce,
+        ),
+        With<Ship>,
+    >,
+) {
+    for (sail, parent, sail_transform, integrity) in sail_query.iter() {
+        if let Ok((ship_transform, velocity, center_of_mass, mut external_force)) =
+            ship_query.get_mut(parent.get())
+        {
+            let apparent_wind = wind.velocity() - velocity.0;
+            let local_wind = ship_transform.rotation.inverse() * apparent_wind;
+            let local_force = calculate_sail_force(sail, local_wind)
+                * integrity.map_or(1.0, |integrity| integrity.level);
+
+            let force = ship_transform.rotation * local_force;
+            let center_of_effort =
+                ship_transform.translation + ship_transform.rotation * sail_transform.translation;
+            let world_center_of_mass =
+                ship_transform.translation + ship_transform.rotation * center_of_mass.0;
+
+            external_force.apply_force_at_point(force, center_of_effort, world_center_of_mass);
+        }
+    }
+}
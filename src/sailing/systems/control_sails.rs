@@ -0,0 +1,59 @@
+use bevy::input::ButtonInput;
+use bevy::prelude::{KeyCode, Parent, Query, Res, Time, With};
+
+use crate::crew_management::Manning;
+use crate::sailing::constants::{SAIL_FURL_RATE, SAIL_TRIM_MAX, SAIL_TRIM_MIN, SAIL_TRIM_RATE};
+use crate::sailing::Sail;
+use crate::ship::PlayerShip;
+
+/// Adjusts the trim and furl state of the sails of the player's ship based on user input.
+///
+/// - Hold `[` to haul the sheets in, trimming the sails closer to the centreline.
+/// - Hold `]` to ease the sheets out, letting the sails swing further outboard.
+/// - Hold `R` to set more sail.
+/// - Hold `F` to furl the sails.
+///
+/// The sheets are worked as quickly as the ship's sail handling stations are manned, and not at
+/// all while they are left unmanned.
+///
+/// # Parameters
+/// - `input`: Resource that captures keyboard input.
+/// - `time`: Resource providing the delta time for the game.
+/// - `sails`: Query to fetch and modify the `Sail` components and the ships carrying them.
+/// - `player_ships`: Query to identify the player's ship and retrieve the manning of its stations.
+pub fn control_sails(
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut sails: Query<(&mut Sail, &Parent)>,
+    player_ships: Query<Option<&Manning>, With<PlayerShip>>,
+) {
+    let mut trim_delta = 0.0;
+    let mut furl_delta = 0.0;
+
+    if input.pressed(KeyCode::BracketLeft) {
+        trim_delta -= SAIL_TRIM_RATE * time.delta_seconds();
+    }
+    if input.pressed(KeyCode::BracketRight) {
+        trim_delta += SAIL_TRIM_RATE * time.delta_seconds();
+    }
+    if input.pressed(KeyCode::KeyR) {
+        furl_delta -= SAIL_FURL_RATE * time.delta_seconds();
+    }
+    if input.pressed(KeyCode::KeyF) {
+        furl_delta += SAIL_FURL_RATE * time.delta_seconds();
+    }
+
+    if trim_delta == 0.0 && furl_delta == 0.0 {
+        return;
+    }
+
+    for (mut sail, parent) in sails.iter_mut() {
+        let Ok(manning) = player_ships.get(parent.get()) else {
+            continue;
+        };
+
+        let sail_handling = manning.map_or(1.0, |manning| manning.sails);
+        sail.trim = (sail.trim + trim_delta * sail_handling).clamp(SAIL_TRIM_MIN, SAIL_TRIM_MAX);
+        sail.furl = (sail.furl + furl_delta * sail_handling).clamp(0.0, 1.0);
+    }
+}
@@ -0,0 +1,4 @@
+pub use {apply_sail_forces::*, control_sails::*};
+
+mod apply_sail_forces;
+mod control_sails;
@@ -0,0 +1,3 @@
+pub use sail_utils::*;
+
+mod sail_utils;
@@ -0,0 +1,53 @@
+use bevy::math::Vec3;
+
+use crate::sailing::constants::{AIR_DENSITY, SAIL_FORCE_COEFFICIENT};
+use crate::sailing::Sail;
+use crate::ship::{SHIP_FORWARD, SHIP_STARBOARD};
+
+/// Calculates the force a sail produces from the apparent wind.
+///
+/// The sail is treated as a flat plate swung out to leeward at its trim angle. The force
+/// acts along the sail's normal, on the side the wind is blowing towards, and scales with
+/// the dynamic pressure of the wind and the sine of the angle between the sail and the wind.
+/// With the sheets eased and the wind from astern the force drives the ship forward; with
+/// the wind on the beam most of the force pushes the ship sideways, which is what heels it.
+///
+/// # Arguments
+///
+/// * `sail` - The sail producing the force.
+/// * `apparent_wind` - The velocity of the air relative to the ship, in ship-local space.
+///
+/// # Returns
+///
+/// A `Vec3` representing the sail force in ship-local space. Only the horizontal component
+/// of the apparent wind is considered, so the force always lies in the ship's deck plane.
+pub fn calculate_sail_force(sail: &Sail, apparent_wind: Vec3) -> Vec3 {
+    let wind = Vec3::new(apparent_wind.x, 0.0, apparent_wind.z);
+    let wind_speed = wind.length();
+    let area = sail.effective_area();
+
+    if wind_speed <= f32::EPSILON || area <= 0.0 {
+        return Vec3::ZERO;
+    }
+
+    let wind_direction = wind / wind_speed;
+
+    // The sail swings out on the side the wind is blowing towards.
+    let leeward = if wind_direction.dot(SHIP_STARBOARD) >= 0.0 {
+        SHIP_STARBOARD
+    } else {
+        -SHIP_STARBOARD
+    };
+
+    let (sin_trim, cos_trim) = sail.trim.sin_cos();
+    let chord = -SHIP_FORWARD * cos_trim + leeward * sin_trim;
+    let mut normal = SHIP_FORWARD * sin_trim + leeward * cos_trim;
+    if normal.dot(wind_direction) < 0.0 {
+        normal = -normal;
+    }
+
+    let sin_angle_of_attack = chord.cross(wind_direction).length();
+    let dynamic_pressure = 0.5 * AIR_DENSITY * wind_speed * wind_speed;
+
+    normal * dynamic_pressure * area * SAIL_FORCE_COEFFICIENT * sin_angle_of_attack
+}
@@ -1,3 +1,4 @@
-pub use ship::*;
+pub use {player_ship::*, ship::*};
 
+mod player_ship;
 mod ship;
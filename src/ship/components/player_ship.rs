@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A marker component indicating that a ship is sailed by the player.
+///
+/// Keyboard controls for the helm, sails and guns only act on ships carrying this component,
+/// leaving every other ship to its captain.
+#[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct PlayerShip;
@@ -0,0 +1,3 @@
+pub use ship_axes::*;
+
+mod ship_axes;
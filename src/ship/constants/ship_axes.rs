@@ -0,0 +1,14 @@
+use bevy::math::Vec3;
+
+/// The local axis pointing from the stern towards the bow of a ship model.
+///
+/// Ship models are authored with the aft cabins on the negative X side, so the bow
+/// points along positive X. Systems that need to know which way a ship is heading,
+/// such as sail and hydrodynamic force calculations, use this axis in ship-local space.
+pub const SHIP_FORWARD: Vec3 = Vec3::X;
+
+/// The local axis pointing to the starboard (right hand) side of a ship model.
+///
+/// This is the axis perpendicular to `SHIP_FORWARD` in the horizontal plane, used to
+/// split forces and velocities into forward and sideways components.
+pub const SHIP_STARBOARD: Vec3 = Vec3::Z;
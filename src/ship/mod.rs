@@ -2,28 +2,33 @@ use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
 
 pub use components::*;
+pub use constants::*;
 use resources::*;
 use systems::*;
 
 use crate::asset_management::states::app_states::AppStates;
 
 mod components;
+mod constants;
 mod resources;
 mod systems;
+mod utils;
 
-/// Plugin for managing the ship entity within the game.
+/// Plugin for managing the ship entities within the game.
 ///
-/// The `ShipPlugin` is responsible for loading and setting up the main ship entity in the game world.
+/// The `ShipPlugin` is responsible for loading and setting up the player's ship and the enemy ship in the game world.
 /// It registers the necessary components, initializes resources, and sets up systems to handle ship spawning and asset loading.
 ///
 /// # Components
+/// - `PlayerShip`: A marker component indicating that a ship is sailed by the player.
 /// - `Ship`: A marker component indicating that an entity is a ship.
 ///
 /// # Resources
 /// - `ShipAssets`: Contains handles to the ship assets used in the game.
 ///
 /// # Systems
-/// - `spawn_ship`: Spawns the ship entity with the necessary components and assets.
+/// - `spawn_enemy_ship`: Spawns an enemy ship commanded by its own captain.
+/// - `spawn_ship`: Spawns the player's ship entity with the necessary components and assets.
 pub struct ShipPlugin;
 
 impl Plugin for ShipPlugin {
@@ -31,7 +36,9 @@ impl Plugin for ShipPlugin {
         app.configure_loading_state(
             LoadingStateConfig::new(AppStates::AssetLoading).load_collection::<ShipAssets>(),
         )
+        .register_type::<PlayerShip>()
         .register_type::<Ship>()
+        .add_systems(OnEnter(AppStates::Running), spawn_enemy_ship)
         .add_systems(OnEnter(AppStates::Running), spawn_ship);
     }
 }
@@ -10,9 +10,12 @@ use bevy_asset_loader::asset_collection::AssetCollection;
 ///
 /// # Fields
 ///
-/// * `ship` - A handle to the ship scene asset.
+/// * `ship` - A handle to the player's ship scene asset.
+/// * `enemy_ship` - A handle to the enemy ship scene asset.
 #[derive(AssetCollection, Resource)]
 pub struct ShipAssets {
     #[asset(path = "models/export/ship/carrack_2.glb#Scene0")]
     pub ship: Handle<Scene>,
+    #[asset(path = "models/export/ship/carrack_b.glb#Scene0")]
+    pub enemy_ship: Handle<Scene>,
 }
@@ -1,3 +1,4 @@
-pub use spawn_ship::*;
+pub use {spawn_enemy_ship::*, spawn_ship::*};
 
+mod spawn_enemy_ship;
 mod spawn_ship;
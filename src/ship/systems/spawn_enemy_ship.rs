@@ -0,0 +1,57 @@
+use bevy::math::{Quat, Vec3};
+use bevy::prelude::{Commands, Res, Transform};
+use big_brain::pickers::Highest;
+use big_brain::prelude::Thinker;
+
+use crate::ai_captain_behavior::{
+    Approach, ApproachScorer, Board, BoardScorer, BroadsideScorer, Captain, Flee, FleeScorer,
+    HoldBroadside,
+};
+use crate::crew_management::ShipCrew;
+use crate::crew_morale::ShipMorale;
+use crate::ship::resources::ShipAssets;
+use crate::ship::utils::spawn_rigged_ship;
+
+/// Spawns an enemy ship in the game world, commanded by its own captain.
+///
+/// The enemy ship is rigged by `spawn_rigged_ship` from its own scene asset and placed some
+/// distance off the player's ship, heading towards it. It is sailed and fought by a `Captain`
+/// whose AI is managed using the `big_brain` crate, the same way the crew's is.
+///
+/// # Parameters
+///
+/// * `commands`: The Commands resource is used to spawn and configure entities.
+/// * `ship_assets`: A reference to the ShipAssets resource, which contains preloaded
+/// assets for the ship entity.
+///
+/// # Components
+///
+/// * `ShipCrew`: Ships a crew of twelve aboard.
+/// * `ShipMorale`: Tracks the morale of the crew as a whole.
+/// * `Captain`: Holds the captain's assessment of the engagement and their orders.
+/// * `Thinker`: Chooses between approaching, holding broadside position, fleeing and boarding.
+pub fn spawn_enemy_ship(mut commands: Commands, ship_assets: Res<ShipAssets>) {
+    spawn_rigged_ship(
+        &mut commands,
+        ship_assets.enemy_ship.clone(),
+        "Enemy Ship",
+        Transform {
+            translation: Vec3::new(150.0, 0.0, 150.0),
+            rotation: Quat::from_rotation_y(135.0f32.to_radians()),
+            ..Transform::default()
+        },
+    )
+    .insert((
+        ShipCrew::new(12),
+        ShipMorale::default(),
+        Captain::default(),
+        Thinker::build()
+            .label("Captain Thinker")
+            // Selects the action with the highest score
+            .picker(Highest)
+            .when(FleeScorer, Flee)
+            .when(BoardScorer, Board)
+            .when(BroadsideScorer, HoldBroadside)
+            .when(ApproachScorer, Approach),
+    ));
+}
@@ -1,14 +1,16 @@
-use bevy::core::Name;
-use bevy::prelude::{default, Commands, Res, SceneBundle};
+use bevy::prelude::{Commands, Res, Transform};
 
+use crate::crew_management::ShipCrew;
+use crate::crew_morale::ShipMorale;
 use crate::ship::resources::ShipAssets;
-use crate::ship::Ship;
+use crate::ship::utils::spawn_rigged_ship;
+use crate::ship::PlayerShip;
 
-/// Spawns the main ship entity in the game world.
+/// Spawns the player's ship in the game world.
 ///
 /// This function utilizes preloaded ship assets to create and configure the ship entity.
-/// The ship entity is assigned various components to integrate it into the game's
-/// entity-component system.
+/// The ship is rigged by `spawn_rigged_ship` and then assigned the components that make it
+/// the player's own.
 ///
 /// # Parameters
 ///
@@ -18,16 +20,15 @@ use crate::ship::Ship;
 ///
 /// # Components
 ///
-/// * `Ship`: A custom component that identifies the entity as the main ship.
-/// * `Name`: Assigns a name to the entity ("Ship").
-/// * `SceneBundle`: Combines the scene asset and other necessary data for the ship entity.
+/// * `PlayerShip`: Hands the helm, sails and guns of the ship to the player's controls.
+/// * `ShipCrew`: Ships a crew of nine aboard.
+/// * `ShipMorale`: Tracks the morale of the crew as a whole.
 pub fn spawn_ship(mut commands: Commands, ship_assets: Res<ShipAssets>) {
-    commands.spawn((
-        Ship,
-        Name::new("Ship"),
-        SceneBundle {
-            scene: ship_assets.ship.clone(),
-            ..default()
-        },
-    ));
+    spawn_rigged_ship(
+        &mut commands,
+        ship_assets.ship.clone(),
+        "Ship",
+        Transform::default(),
+    )
+    .insert((PlayerShip, ShipCrew::new(9), ShipMorale::default()));
 }
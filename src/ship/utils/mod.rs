@@ -0,0 +1,3 @@
+pub use ship_utils::*;
+
+mod ship_utils;
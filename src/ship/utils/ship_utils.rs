@@ -0,0 +1,168 @@
+use bevy::asset::Handle;
+use bevy::core::Name;
+use bevy::ecs::system::EntityCommands;
+use bevy::hierarchy::BuildChildren;
+use bevy::math::{Quat, Vec3};
+use bevy::prelude::{default, Commands, Scene, SceneBundle, Transform, TransformBundle};
+
+use crate::claims::Claimable;
+use crate::crew_management::{CrewRole, Manning, Station};
+use crate::food::create_meal_recipe;
+use crate::gunnery::Cannon;
+use crate::hydrodynamics::{Keel, Rudder};
+use crate::production::Workshop;
+use crate::sailing::Sail;
+use crate::ship::Ship;
+use crate::ship_items::{BilgePump, Sickbay, BILGE_PUMP_RATE};
+
+/// Spawns a ship from a scene, fitted out with its keel, rudder, sails, guns and stations.
+///
+/// Every ship in the game is built the same way, whoever sails it, so the player's ship and
+/// enemy ships share this function and only add what sets them apart.
+///
+/// # Arguments
+/// - `commands`: The commands used to spawn the ship and its rigging.
+/// - `scene`: The scene asset holding the ship's model.
+/// - `name`: The name given to the ship entity.
+/// - `transform`: Where the ship is placed in the world and which way it faces.
+///
+/// # Returns
+/// The commands of the spawned ship, for adding further components to it.
+///
+/// # Components
+///
+/// * `Ship`: Identifies the entity as a ship.
+/// * `SceneBundle`: Combines the scene asset and the transform of the ship.
+/// * `Keel`: Gives the hull low forward drag and high resistance to leeway.
+/// * `Rudder`: Lets the helm steer the ship, mounted at the stern below the waterline.
+/// * `Manning`: Tracks how well the ship's stations are manned.
+///
+/// # Children
+///
+/// * `Sail`: The fore, main and mizzen sails, positioned at their centres of effort.
+/// * `Cannon`: Two guns on each broadside, turned so their barrels point out over the side.
+///   Each gun is also a `Gunner` station, and can be claimed by one crew member at a time.
+/// * `Station`: The helm, the fore and main braces, the carpenter's bench and the galley, on deck.
+/// * `Workshop`: The galley, where the cook turns the ship's provisions into meals.
+/// * `Sickbay`: Where the injured are treated, which is also the surgeon's station.
+/// * `BilgePump`: The pump crew members work, one at a time, to remove water from the hull
+///   when it floods.
+pub fn spawn_rigged_ship<'a>(
+    commands: &'a mut Commands,
+    scene: Handle<Scene>,
+    name: &str,
+    transform: Transform,
+) -> EntityCommands<'a> {
+    let mut ship = commands.spawn((
+        Ship,
+        Name::new(name.to_string()),
+        SceneBundle {
+            scene,
+            transform,
+            ..default()
+        },
+        Keel::new(150.0, 3000.0, Vec3::new(-1.0, -2.0, 0.0)),
+        Rudder::new(
+            35.0f32.to_radians(),
+            0.5,
+            250.0,
+            Vec3::new(-16.0, -1.0, 0.0),
+        ),
+        Manning::default(),
+    ));
+
+    ship.with_children(|parent| {
+        parent.spawn((
+            Name::new("Fore Sail"),
+            Sail::new(60.0),
+            TransformBundle::from_transform(Transform::from_xyz(9.0, 20.0, 0.0)),
+        ));
+        parent.spawn((
+            Name::new("Main Sail"),
+            Sail::new(90.0),
+            TransformBundle::from_transform(Transform::from_xyz(0.0, 24.0, 0.0)),
+        ));
+        parent.spawn((
+            Name::new("Mizzen Sail"),
+            Sail::new(40.0),
+            TransformBundle::from_transform(Transform::from_xyz(-10.0, 18.0, 0.0)),
+        ));
+
+        for (name, role, translation) in [
+            ("Helm", CrewRole::Helmsman, Vec3::new(-12.0, 9.0, 0.0)),
+            (
+                "Fore Braces",
+                CrewRole::SailHandler,
+                Vec3::new(8.0, 9.0, 1.5),
+            ),
+            (
+                "Main Braces",
+                CrewRole::SailHandler,
+                Vec3::new(1.0, 9.0, 1.5),
+            ),
+            (
+                "Carpenter's Bench",
+                CrewRole::Carpenter,
+                Vec3::new(-6.0, 9.0, -1.5),
+            ),
+        ] {
+            parent.spawn((
+                Name::new(name),
+                Station::new(role),
+                TransformBundle::from_transform(Transform::from_translation(translation)),
+            ));
+        }
+
+        parent.spawn((
+            Name::new("Galley"),
+            Station::new(CrewRole::Cook),
+            Workshop::new(create_meal_recipe()),
+            TransformBundle::from_transform(Transform::from_xyz(3.0, 9.0, -1.5)),
+        ));
+
+        parent.spawn((
+            Name::new("Sickbay"),
+            Sickbay,
+            Station::new(CrewRole::Surgeon),
+            TransformBundle::from_transform(Transform::from_xyz(-3.0, 9.0, 1.5)),
+        ));
+
+        parent.spawn((
+            Name::new("Bilge Pump"),
+            BilgePump {
+                compartment: None,
+                rate: BILGE_PUMP_RATE,
+                operator: None,
+            },
+            Claimable::new(1),
+            TransformBundle::from_transform(Transform::from_xyz(-8.0, 9.0, 1.5)),
+        ));
+
+        for (name, x) in [("Fore", 4.0), ("Aft", -4.0)] {
+            parent.spawn((
+                Name::new(format!("Port {} Cannon", name)),
+                Cannon::default(),
+                Claimable::new(1),
+                Station::new(CrewRole::Gunner),
+                TransformBundle::from_transform(Transform {
+                    translation: Vec3::new(x, 9.0, -3.5),
+                    rotation: Quat::from_rotation_y(90.0f32.to_radians()),
+                    ..default()
+                }),
+            ));
+            parent.spawn((
+                Name::new(format!("Starboard {} Cannon", name)),
+                Cannon::default(),
+                Claimable::new(1),
+                Station::new(CrewRole::Gunner),
+                TransformBundle::from_transform(Transform {
+                    translation: Vec3::new(x, 9.0, 3.5),
+                    rotation: Quat::from_rotation_y(-90.0f32.to_radians()),
+                    ..default()
+                }),
+            ));
+        }
+    });
+
+    ship
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A crew-operated pump that removes water from a flooded hull.
+///
+/// The `BilgePump` component is added to pump entities fitted to a ship as children of its
+/// hull. While a crew member operates the pump, it draws water out of its compartment of
+/// that hull at a steady rate. The compartment is the one directly below the pump, found once
+/// the hull's compartments are known, and a pump over no compartment draws from the whole hull.
+///
+/// # Fields
+/// - `compartment`: The name of the compartment the pump draws from, or `None` for the whole hull.
+/// - `rate`: The volume of water the pump removes while operated, in cubic metres per second.
+/// - `operator`: The crew member currently operating the pump, if any.
+#[derive(Debug, Clone, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct BilgePump {
+    pub compartment: Option<String>,
+    pub rate: f32,
+    pub operator: Option<Entity>,
+}
@@ -1,3 +1,5 @@
-pub use sleep_area::*;
+pub use {bilge_pump::*, sickbay::*, sleep_area::*};
 
+mod bilge_pump;
+mod sickbay;
 mod sleep_area;
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Marker component for the place aboard a ship where injured crew are treated.
+///
+/// The `Sickbay` component is added to an entity in a ship's hierarchy. Injured crew recover
+/// there slowly on their own, and much faster while the surgeon is at work, so the sickbay is
+/// usually also a `Surgeon` station.
+#[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct Sickbay;
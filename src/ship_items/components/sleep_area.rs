@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
-/// Marker component for a place where a character can sleep.
+/// Marker component for a place where characters can sleep, such as a bunk or a row of hammocks.
 ///
 /// The `SleepArea` component is used to designate areas or objects within the game world
-/// where characters are allowed to sleep. It is typically added to entities like beds or
-/// sleeping quarters.
+/// where characters are allowed to sleep. Its berths are given by a `Claimable` component
+/// on the same entity, and a character claims one of them when it sets off to sleep there,
+/// so that no more characters turn in at an area than it has room for.
 #[derive(Debug, Clone, Eq, PartialEq, Component, Reflect, Serialize, Deserialize, Default)]
 #[reflect(Component, Serialize, Deserialize)]
 pub struct SleepArea;
@@ -0,0 +1,5 @@
+/// The distance within which a character is close enough to a bilge pump to work its handle.
+pub const BILGE_PUMP_DISTANCE: f32 = 2.0;
+
+/// The volume of water a bilge pump removes while operated, in cubic metres per second.
+pub const BILGE_PUMP_RATE: f32 = 0.25;
@@ -0,0 +1,4 @@
+pub use {bilge_pump_settings::*, sleep_area_settings::*};
+
+mod bilge_pump_settings;
+mod sleep_area_settings;
@@ -0,0 +1,2 @@
+/// The distance within which a character is close enough to a sleep area to turn in there.
+pub const SLEEP_AREA_DISTANCE: f32 = 3.0;
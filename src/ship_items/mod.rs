@@ -1,29 +1,48 @@
 use bevy::prelude::*;
 
 pub use components::*;
+pub use constants::*;
 use systems::*;
+pub use utils::*;
 
 use crate::asset_management::states::app_states::AppStates;
 
 mod components;
+mod constants;
 mod systems;
+mod utils;
 
 /// Plugin for managing ship items within the game.
 ///
 /// The `ShipItemsPlugin` is responsible for setting up various interactive items within the ship,
-/// such as furniture. It registers the necessary components and sets up systems to handle the
-/// spawning of these items.
+/// such as furniture, bilge pumps and the sickbay. It registers the necessary components and sets up systems
+/// to handle the spawning and operation of these items.
 ///
 /// # Components
+/// - `BilgePump`: A crew-operated pump that removes water from a flooded hull.
+/// - `Sickbay`: A marker component for the place aboard a ship where injured crew are treated.
 /// - `SleepArea`: A marker component for designating areas where characters can sleep.
 ///
 /// # Systems
-/// - `spawn_furniture`: Spawns furniture items such as beds in the game world.
+/// - `assign_pump_compartments`: Links each bilge pump to the compartment it draws water from.
+/// - `operate_bilge_pumps`: Removes water from flooded hulls through the pumps being operated.
+/// - `spawn_furniture`: Spawns furniture items such as bunks and hammocks aboard each ship that has
+///   just been given a crew.
 pub struct ShipItemsPlugin;
 
 impl Plugin for ShipItemsPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<SleepArea>()
-            .add_systems(OnEnter(AppStates::Running), spawn_furniture);
+        app.register_type::<BilgePump>()
+            .register_type::<Sickbay>()
+            .register_type::<SleepArea>()
+            .add_systems(Update, spawn_furniture.run_if(in_state(AppStates::Running)))
+            .add_systems(
+                Update,
+                assign_pump_compartments.run_if(in_state(AppStates::Running)),
+            )
+            .add_systems(
+                Update,
+                operate_bilge_pumps.run_if(in_state(AppStates::Running)),
+            );
     }
 }
@@ -0,0 +1,32 @@
+use bevy::prelude::{Changed, Parent, Query, Transform};
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::flooding::Flooding;
+use crate::ship_items::{find_pump_compartment, BilgePump};
+
+/// Links each bilge pump to the compartment of its hull that it draws water from.
+///
+/// Pumps are fitted to their hull as children, but the hull's compartments are only known
+/// once its `Flooding` has been set up from the voxels and the compartment markers. Whenever
+/// a hull's flooding state changes, the pumps aboard it are pointed at the compartment
+/// directly below them, or at the whole hull if it has no compartment there.
+///
+/// # Parameters
+/// - `pumps`: Query to retrieve and modify the bilge pumps, along with their hulls and
+///   hull-local transforms.
+/// - `hulls`: Query to retrieve the flooding state and voxels of hulls whose flooding has changed.
+pub fn assign_pump_compartments(
+    mut pumps: Query<(&mut BilgePump, &Parent, &Transform)>,
+    hulls: Query<(&Flooding, &Buoyancy), Changed<Flooding>>,
+) {
+    for (mut pump, parent, transform) in pumps.iter_mut() {
+        let Ok((flooding, buoyancy)) = hulls.get(parent.get()) else {
+            continue;
+        };
+
+        let compartment = find_pump_compartment(flooding, &buoyancy.voxels, transform.translation);
+        if pump.compartment != compartment {
+            pump.compartment = compartment;
+        }
+    }
+}
@@ -1,3 +1,5 @@
-pub use spawn_furniture::*;
+pub use {assign_pump_compartments::*, operate_bilge_pumps::*, spawn_furniture::*};
 
+mod assign_pump_compartments;
+mod operate_bilge_pumps;
 mod spawn_furniture;
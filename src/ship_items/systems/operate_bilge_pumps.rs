@@ -0,0 +1,38 @@
+use bevy::prelude::{Parent, Query, Res, Time};
+
+use crate::buoyancy_physics::Buoyancy;
+use crate::damage::Integrity;
+use crate::flooding::Flooding;
+use crate::ship_items::BilgePump;
+
+/// Removes water from flooded hulls through the bilge pumps being operated.
+///
+/// Each pump with an operator draws water out of its compartment of the hull it is fitted to
+/// at the pump's rate, reduced in proportion to the pump's integrity. Pumps without an
+/// operator do nothing.
+///
+/// # Parameters
+/// - `time`: Resource providing the delta time for the game.
+/// - `pumps`: Query to retrieve the bilge pumps, their hulls and integrity.
+/// - `hulls`: Query to retrieve the flooding state and voxels of hulls.
+pub fn operate_bilge_pumps(
+    time: Res<Time>,
+    pumps: Query<(&BilgePump, &Parent, Option<&Integrity>)>,
+    mut hulls: Query<(&mut Flooding, &Buoyancy)>,
+) {
+    for (pump, parent, integrity) in pumps.iter() {
+        if pump.operator.is_none() {
+            continue;
+        }
+
+        if let Ok((mut flooding, buoyancy)) = hulls.get_mut(parent.get()) {
+            flooding.pump_out(
+                pump.compartment.as_deref(),
+                pump.rate
+                    * integrity.map_or(1.0, |integrity| integrity.level)
+                    * time.delta_seconds(),
+                buoyancy.voxel_size,
+            );
+        }
+    }
+}
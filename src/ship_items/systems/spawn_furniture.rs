@@ -1,62 +1,101 @@
 use bevy::asset::Assets;
 use bevy::core::Name;
+use bevy::hierarchy::BuildChildren;
 use bevy::math::{Quat, Vec3};
 use bevy::pbr::{PbrBundle, StandardMaterial};
 /// This module provides a system for spawning furniture items on the ship.
-/// Currently, it includes the creation of a bed object.
+/// Currently, it includes the creation of the bunks and the hammocks.
 ///
 /// Note: This system is expected to be deprecated as the game evolves and more
 /// sophisticated systems for spawning and managing furniture are developed.
-use bevy::prelude::{default, Color, Commands, Cuboid, Mesh, ResMut, Transform};
-use bevy_xpbd_3d::components::{Friction, RigidBody};
+use bevy::prelude::{
+    default, Added, Color, Commands, Cuboid, Entity, Mesh, Query, ResMut, Transform,
+};
+use bevy_xpbd_3d::components::Friction;
 use bevy_xpbd_3d::prelude::Collider;
 
+use crate::claims::Claimable;
+use crate::crew_management::ShipCrew;
 use crate::ship_items::SleepArea;
 
-/// Spawns a bed entity in the game world.
+/// Spawns the bunk and hammock entities aboard each ship that has just been given a `ShipCrew`.
 ///
-/// This function creates a simple bed entity using Bevy's PBR (Physically Based Rendering)
-/// components and some physics properties. The bed is positioned at a specific location on the ship
-/// and is marked as a `SleepArea` where characters can rest.
+/// This function creates simple bunk and hammock entities using Bevy's PBR (Physically Based
+/// Rendering) components and some physics properties. Both are positioned at specific locations
+/// relative to the ship and are marked as a `SleepArea` where characters can rest, the bunks
+/// with two berths and the hammocks with four. They are children of the ship, so they sail
+/// with it, and their colliders become part of the ship's.
 ///
 /// # Parameters
 ///
 /// * `commands`: The Commands resource is used to spawn and configure entities.
 /// * `meshes`: A mutable reference to the Assets resource containing Mesh objects.
 /// * `materials`: A mutable reference to the Assets resource containing StandardMaterial objects.
+/// * `ships`: Query to retrieve the ships that have just been given a crew.
 ///
 /// # Components
 ///
-/// * `Name`: Assigns a name to the entity ("Bed").
+/// * `Name`: Assigns a name to the entity ("Bunks" or "Hammocks").
 /// * `PbrBundle`: Combines mesh, material, and transform data for the entity.
 /// * `SleepArea`: A custom component marking the entity as a sleeping area.
-/// * `RigidBody::Dynamic`: Marks the entity as a dynamic rigid body for physics simulation.
+/// * `Claimable`: Lets crew members claim a berth in a sleeping area.
 /// * `Friction`: Sets the friction coefficient for the entity's collider.
 /// * `Collider::cuboid`: Defines the entity's collision shape as a cuboid.
 pub fn spawn_furniture(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    ships: Query<Entity, Added<ShipCrew>>,
 ) {
-    // Create a bed
-    commands.spawn((
-        Name::new("Bed"),
-        PbrBundle {
-            mesh: meshes.add(Mesh::from(Cuboid::new(4.0, 1.0, 2.0))),
-            material: materials.add(StandardMaterial {
-                base_color: Color::BLUE,
-                ..default()
-            }),
-            transform: Transform {
-                translation: Vec3::new(-14.155, 8.4, -0.147),
-                rotation: Quat::from_rotation_z(-9.8367f32.to_radians()),
-                scale: Vec3::ONE,
-            },
-            ..default()
-        },
-        SleepArea,
-        RigidBody::Dynamic,
-        Friction::new(1.0),
-        Collider::cuboid(5.0, 1.0, 5.0),
-    ));
+    for ship in ships.iter() {
+        // Create the bunks
+        let bunks = commands
+            .spawn((
+                Name::new("Bunks"),
+                PbrBundle {
+                    mesh: meshes.add(Mesh::from(Cuboid::new(4.0, 1.0, 2.0))),
+                    material: materials.add(StandardMaterial {
+                        base_color: Color::BLUE,
+                        ..default()
+                    }),
+                    transform: Transform {
+                        translation: Vec3::new(-14.155, 8.4, -0.147),
+                        rotation: Quat::from_rotation_z(-9.8367f32.to_radians()),
+                        scale: Vec3::ONE,
+                    },
+                    ..default()
+                },
+                SleepArea,
+                Claimable::new(2),
+                Friction::new(1.0),
+                Collider::cuboid(5.0, 1.0, 5.0),
+            ))
+            .id();
+
+        // Create the hammocks
+        let hammocks = commands
+            .spawn((
+                Name::new("Hammocks"),
+                PbrBundle {
+                    mesh: meshes.add(Mesh::from(Cuboid::new(3.0, 0.5, 2.0))),
+                    material: materials.add(StandardMaterial {
+                        base_color: Color::BEIGE,
+                        ..default()
+                    }),
+                    transform: Transform {
+                        translation: Vec3::new(-9.5, 8.4, -1.5),
+                        rotation: Quat::from_rotation_z(-9.8367f32.to_radians()),
+                        scale: Vec3::ONE,
+                    },
+                    ..default()
+                },
+                SleepArea,
+                Claimable::new(4),
+                Friction::new(1.0),
+                Collider::cuboid(3.0, 0.5, 2.0),
+            ))
+            .id();
+
+        commands.entity(ship).push_children(&[bunks, hammocks]);
+    }
 }
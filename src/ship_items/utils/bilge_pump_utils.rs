@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use crate::buoyancy_physics::Voxel;
+use crate::flooding::Flooding;
+
+/// Finds the compartment a bilge pump draws water from.
+///
+/// A pump stands on deck with its suction running straight down into the bilge, so it draws
+/// from the compartment at the bottom of the hull directly below it.
+///
+/// # Arguments
+/// - `flooding`: The flooding state of the hull the pump is fitted to.
+/// - `voxels`: The voxels of the hull.
+/// - `pump_position`: The position of the pump in the hull's local space.
+///
+/// # Returns
+/// The name of the compartment below the pump, or `None` if the hull is not divided into
+/// compartments there, in which case the pump draws from the whole hull.
+pub fn find_pump_compartment(
+    flooding: &Flooding,
+    voxels: &[Voxel],
+    pump_position: Vec3,
+) -> Option<String> {
+    let bilge_height = voxels
+        .iter()
+        .filter(|voxel| voxel.is_solid)
+        .map(|voxel| voxel.position.y)
+        .reduce(f32::min)?;
+
+    let compartment = flooding.compartment_at(
+        voxels,
+        Vec3::new(pump_position.x, bilge_height, pump_position.z),
+    )?;
+    flooding.compartments.get(compartment).cloned()
+}
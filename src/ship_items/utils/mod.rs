@@ -0,0 +1,3 @@
+pub use bilge_pump_utils::*;
+
+mod bilge_pump_utils;
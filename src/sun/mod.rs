@@ -3,7 +3,7 @@ use std::time::Duration;
 use bevy::app::{App, Plugin};
 use bevy::prelude::*;
 
-use resources::*;
+pub use resources::*;
 use systems::*;
 
 use crate::asset_management::states::app_states::AppStates;
@@ -1,3 +1,4 @@
+use std::f32::consts::TAU;
 use std::time::Duration;
 
 use bevy::prelude::{Resource, Timer, TimerMode};
@@ -28,6 +29,10 @@ use crate::sun::consts::{SUN_CYCLE_SPEED_MAX, SUN_CYCLE_SPEED_MIN};
 ///   Toggles the pause state of the stopwatch.
 /// - `time(&self) -> f32`:
 ///   Returns the elapsed time in seconds, scaled by the speed factor.
+/// - `sun_angle(&self) -> f32`:
+///   Returns the angle of the sun above the eastern horizon, in radians.
+/// - `hour_of_day(&self) -> f32`:
+///   Returns the hour of the in-game day, with the sun rising at six.
 /// - `update(&self) -> bool`:
 ///   Returns whether the timer has finished its current cycle.
 /// - `update_speed(&mut self, delta: f32)`:
@@ -94,6 +99,28 @@ impl SunCycleTimer {
         self.time.elapsed().as_millis() as f32 / 2000.0
     }
 
+    /// Returns the angle of the sun above the eastern horizon, in radians.
+    ///
+    /// The sun rises at an angle of `0.0`, stands highest at a quarter turn and sets at half a
+    /// turn, before passing below the horizon for the night.
+    ///
+    /// # Returns
+    /// The angle of the sun in radians.
+    pub fn sun_angle(&self) -> f32 {
+        (self.time() + 3.0) * 0.1
+    }
+
+    /// Returns the hour of the in-game day.
+    ///
+    /// The sun rises at six in the morning, stands highest at noon and sets at six in the
+    /// evening.
+    ///
+    /// # Returns
+    /// The hour of the day, from `0.0` up to `24.0`.
+    pub fn hour_of_day(&self) -> f32 {
+        (6.0 + self.sun_angle() / TAU * 24.0).rem_euclid(24.0)
+    }
+
     /// Checks if the timer has finished its current cycle.
     ///
     /// # Returns
@@ -33,7 +33,7 @@ pub fn update_sun_cycle(
 
     if timer.update() {
         let mut pos = atmosphere.sun_position;
-        let t = (timer.time() + 3.0) * 0.1;
+        let t = timer.sun_angle();
         pos.y = t.sin();
         pos.z = t.cos();
         atmosphere.sun_position = pos;
@@ -0,0 +1,17 @@
+use bevy::prelude::{Entity, Parent, Query};
+
+/// Finds the top-level entity an entity is part of, such as the ship carrying a cannon.
+///
+/// # Arguments
+/// - `entity`: The entity to start from.
+/// - `parent_query`: Query to navigate up the hierarchy.
+///
+/// # Returns
+/// The top-level entity, which is the entity itself if it has no parent.
+pub fn find_top_level_entity(entity: Entity, parent_query: &Query<&Parent>) -> Entity {
+    let mut top_level = entity;
+    while let Ok(parent) = parent_query.get(top_level) {
+        top_level = parent.get();
+    }
+    top_level
+}
@@ -96,3 +96,33 @@ pub fn calculate_mesh_bounds(mesh: &Mesh) -> (Vec3, Vec3) {
 
     (min, max)
 }
+
+/// Calculates the axis-aligned bounding box (AABB) of a mesh after applying a transform.
+///
+/// Every vertex is moved by the full affine transform, including rotation and scale, before
+/// the bounds are taken, so the box encloses the mesh as it sits in the transform's parent space.
+///
+/// # Parameters
+/// - `mesh`: Reference to the `Mesh` object whose bounds are to be calculated.
+/// - `transform`: The transform applied to the vertices of the mesh.
+///
+/// # Returns
+/// A tuple containing the minimum and maximum coordinates of the transformed bounding box.
+///
+/// # Panics
+/// This function will panic if the mesh does not contain position attributes.
+pub fn calculate_transformed_mesh_bounds(mesh: &Mesh, transform: &Transform) -> (Vec3, Vec3) {
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        panic!("Mesh does not contain position attribute.");
+    };
+
+    let first = transform.transform_point(Vec3::from(positions[0]));
+    positions
+        .iter()
+        .map(|&vertex| transform.transform_point(Vec3::from(vertex)))
+        .fold((first, first), |(min, max), vertex| {
+            (min.min(vertex), max.max(vertex))
+        })
+}
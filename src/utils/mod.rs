@@ -1,7 +1,11 @@
+pub use hierarchy_utils::*;
 pub use mesh_utils::*;
+pub use score_hysteresis::*;
 pub use vec3i::*;
 pub use water_utils::*;
 
+mod hierarchy_utils;
 mod mesh_utils;
+mod score_hysteresis;
 mod vec3i;
 mod water_utils;
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Hysteresis for the score of a scorer, so that an action is not dropped as soon as it
+/// starts to pay off.
+///
+/// The `ScoreHysteresis` is kept on each scorer, and since every actor has its own scorers,
+/// each actor keeps its own state. Once the raw score reaches the `enter` threshold, the score
+/// is held at the score it entered with, until the raw score falls to the `exit` threshold.
+///
+/// # Fields
+/// - `enter`: The raw score at or above which the score starts being held.
+/// - `exit`: The raw score at or below which the score stops being held.
+/// - `held`: The score being held, or `None` if the score follows the raw score.
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize, Default)]
+#[reflect(Serialize, Deserialize)]
+pub struct ScoreHysteresis {
+    pub enter: f32,
+    pub exit: f32,
+    pub held: Option<f32>,
+}
+
+impl ScoreHysteresis {
+    /// Creates a new `ScoreHysteresis` with the specified thresholds.
+    ///
+    /// # Parameters
+    /// - `enter`: The raw score at or above which the score starts being held.
+    /// - `exit`: The raw score at or below which the score stops being held.
+    ///
+    /// # Returns
+    /// A new `ScoreHysteresis` that is not holding a score.
+    pub fn new(enter: f32, exit: f32) -> Self {
+        ScoreHysteresis {
+            enter,
+            exit,
+            held: None,
+        }
+    }
+
+    /// Applies the hysteresis to a newly calculated raw score.
+    ///
+    /// # Parameters
+    /// - `score`: The raw score.
+    ///
+    /// # Returns
+    /// The score to set: the higher of the held score and the raw score while a score is held,
+    /// and the raw score otherwise.
+    pub fn apply(&mut self, score: f32) -> f32 {
+        match self.held {
+            None if score >= self.enter => self.held = Some(score),
+            Some(_) if score <= self.exit => self.held = None,
+            _ => {}
+        }
+
+        self.held.map_or(score, |held| held.max(score))
+    }
+
+    /// Returns whether a score is being held.
+    pub fn is_holding(&self) -> bool {
+        self.held.is_some()
+    }
+}
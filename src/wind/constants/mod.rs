@@ -0,0 +1,3 @@
+pub use wind_settings::*;
+
+mod wind_settings;
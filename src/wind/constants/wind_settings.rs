@@ -0,0 +1,16 @@
+use bevy::math::Vec3;
+
+/// The initial direction the wind blows towards, in world space.
+///
+/// The direction is horizontal and normalized. It is used to seed the `Wind` resource
+/// when the game starts.
+pub const WIND_DIRECTION: Vec3 = Vec3::new(0.6, 0.0, 0.8);
+
+/// The initial mean wind speed in metres per second.
+pub const WIND_SPEED: f32 = 8.0;
+
+/// The maximum speed in metres per second that gusts add to or remove from the mean wind speed.
+pub const WIND_GUST_STRENGTH: f32 = 2.5;
+
+/// How often gusts build and fade, in cycles per second.
+pub const WIND_GUST_FREQUENCY: f32 = 0.05;
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+pub use resources::*;
+use systems::*;
+
+use crate::asset_management::states::app_states::AppStates;
+use crate::wind::constants::{WIND_DIRECTION, WIND_GUST_FREQUENCY, WIND_GUST_STRENGTH, WIND_SPEED};
+
+mod constants;
+mod resources;
+mod systems;
+
+/// Plugin for managing the wind blowing across the game world.
+///
+/// The `WindPlugin` provides a global wind model with a prevailing direction, a mean speed
+/// and gusts that vary over time. Other plugins, such as the `SailingPlugin`, read the wind
+/// to drive ships under sail.
+///
+/// # Resources
+/// - `Wind`: Describes the direction, speed and gusts of the wind.
+///
+/// # Systems
+/// - `update_wind`: Updates the gust currently blowing on top of the mean wind speed.
+///
+/// This plugin is added to the app during the application setup and is configured to
+/// operate during the `AppStates::Running` state.
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Wind>()
+            .insert_resource(Wind::new(
+                WIND_DIRECTION,
+                WIND_SPEED,
+                WIND_GUST_STRENGTH,
+                WIND_GUST_FREQUENCY,
+            ))
+            .add_systems(Update, update_wind.run_if(in_state(AppStates::Running)));
+    }
+}
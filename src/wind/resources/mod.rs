@@ -0,0 +1,3 @@
+pub use wind::*;
+
+mod wind;
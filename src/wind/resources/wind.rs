@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+/// A resource describing the wind blowing across the game world.
+///
+/// The `Wind` resource holds the prevailing wind direction and speed, along with the
+/// parameters used to vary it with gusts over time. Sails use the resulting wind velocity
+/// to calculate the thrust and heeling forces applied to ships.
+///
+/// # Fields
+/// - `direction`: The horizontal, normalized direction the wind blows towards.
+/// - `speed`: The mean wind speed in metres per second.
+/// - `gust_strength`: The maximum speed gusts add to or remove from the mean speed.
+/// - `gust_frequency`: How often gusts build and fade, in cycles per second.
+/// - `gust`: The gust currently blowing, in metres per second. Updated by `update_wind`.
+///
+/// # Methods
+/// - `new(direction: Vec3, speed: f32, gust_strength: f32, gust_frequency: f32) -> Self`:
+///   Creates a new `Wind` resource with no gust currently blowing.
+/// - `current_speed(&self) -> f32`:
+///   Returns the wind speed including the current gust.
+/// - `velocity(&self) -> Vec3`:
+///   Returns the wind velocity in world space including the current gust.
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct Wind {
+    pub direction: Vec3,
+    pub speed: f32,
+    pub gust_strength: f32,
+    pub gust_frequency: f32,
+    pub gust: f32,
+}
+
+impl Wind {
+    /// Creates a new `Wind` resource.
+    ///
+    /// # Parameters
+    /// - `direction`: The direction the wind blows towards. It is flattened onto the
+    ///   horizontal plane and normalized.
+    /// - `speed`: The mean wind speed in metres per second.
+    /// - `gust_strength`: The maximum speed gusts add to or remove from the mean speed.
+    /// - `gust_frequency`: How often gusts build and fade, in cycles per second.
+    ///
+    /// # Returns
+    /// A new `Wind` instance.
+    pub fn new(direction: Vec3, speed: f32, gust_strength: f32, gust_frequency: f32) -> Self {
+        Self {
+            direction: Vec3::new(direction.x, 0.0, direction.z).normalize_or_zero(),
+            speed,
+            gust_strength,
+            gust_frequency,
+            gust: 0.0,
+        }
+    }
+
+    /// Returns the wind speed including the current gust.
+    ///
+    /// # Returns
+    /// The wind speed in metres per second, never negative.
+    pub fn current_speed(&self) -> f32 {
+        (self.speed + self.gust).max(0.0)
+    }
+
+    /// Returns the wind velocity in world space including the current gust.
+    ///
+    /// # Returns
+    /// A `Vec3` pointing in the direction the wind blows, scaled by the current speed.
+    pub fn velocity(&self) -> Vec3 {
+        self.direction * self.current_speed()
+    }
+}
+
+impl Default for Wind {
+    /// Creates a calm `Wind` blowing along the positive X axis.
+    fn default() -> Self {
+        Self::new(Vec3::X, 0.0, 0.0, 0.0)
+    }
+}
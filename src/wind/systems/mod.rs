@@ -0,0 +1,3 @@
+pub use update_wind::*;
+
+mod update_wind;
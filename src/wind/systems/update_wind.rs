@@ -0,0 +1,21 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::{Res, ResMut, Time};
+
+use crate::wind::Wind;
+
+/// Updates the gust currently blowing on top of the mean wind speed.
+///
+/// Gusts are produced by summing two sine waves that are out of phase with each other,
+/// which gives an irregular rise and fall of the wind without needing a noise source.
+/// The result is scaled by the `gust_strength` of the `Wind` resource.
+///
+/// # Parameters
+/// - `time`: Resource providing the elapsed time for the game.
+/// - `wind`: Mutable resource describing the wind blowing across the game world.
+pub fn update_wind(time: Res<Time>, mut wind: ResMut<Wind>) {
+    let phase = time.elapsed_seconds() * wind.gust_frequency * TAU;
+    let gust = phase.sin() * 0.6 + (phase * 2.7 + 1.3).sin() * 0.4;
+
+    wind.gust = gust * wind.gust_strength;
+}
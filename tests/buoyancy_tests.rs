@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use empire_of_wind::buoyancy_physics::calculate_submersion;
+
+const VOXEL_SIZE: f32 = 2.0;
+const VOXEL_VOLUME: f32 = VOXEL_SIZE * VOXEL_SIZE * VOXEL_SIZE;
+
+fn calm_water(height: f32) -> impl Fn(Vec3) -> f32 {
+    move |_| height
+}
+
+#[test]
+fn test_fully_submerged_voxel_displaces_its_whole_volume() {
+    for samples_per_axis in [1, 2, 4] {
+        let submersion = calculate_submersion(
+            Vec3::new(3.0, -5.0, 1.0),
+            Quat::IDENTITY,
+            VOXEL_SIZE,
+            samples_per_axis,
+            calm_water(0.0),
+        );
+
+        assert!((submersion.volume - VOXEL_VOLUME).abs() < 1e-4);
+        assert!((submersion.center_of_buoyancy - Vec3::new(3.0, -5.0, 1.0)).length() < 1e-4);
+    }
+}
+
+#[test]
+fn test_dry_voxel_displaces_nothing() {
+    let position = Vec3::new(0.0, 5.0, 0.0);
+    let submersion = calculate_submersion(position, Quat::IDENTITY, VOXEL_SIZE, 2, calm_water(0.0));
+
+    assert_eq!(submersion.volume, 0.0);
+    assert_eq!(submersion.center_of_buoyancy, position);
+}
+
+#[test]
+fn test_half_submerged_voxel_buoys_from_its_lower_half() {
+    let submersion =
+        calculate_submersion(Vec3::ZERO, Quat::IDENTITY, VOXEL_SIZE, 2, calm_water(0.0));
+
+    assert!((submersion.volume - VOXEL_VOLUME / 2.0).abs() < 1e-4);
+    assert!((submersion.center_of_buoyancy - Vec3::new(0.0, -0.5, 0.0)).length() < 1e-4);
+}
+
+#[test]
+fn test_submerged_volume_follows_the_water_level() {
+    let volume_at = |height: f32| {
+        calculate_submersion(
+            Vec3::ZERO,
+            Quat::IDENTITY,
+            VOXEL_SIZE,
+            4,
+            calm_water(height),
+        )
+        .volume
+    };
+
+    assert!((volume_at(-0.5) - VOXEL_VOLUME / 4.0).abs() < 1e-4);
+    assert!((volume_at(0.5) - VOXEL_VOLUME * 3.0 / 4.0).abs() < 1e-4);
+    assert!(volume_at(-0.5) < volume_at(0.0));
+    assert!(volume_at(0.0) < volume_at(0.5));
+}
+
+#[test]
+fn test_heeled_voxel_is_partially_submerged() {
+    let heeled = Quat::from_rotation_x(0.4);
+
+    let dry = calculate_submersion(Vec3::Y * 3.0, heeled, VOXEL_SIZE, 4, calm_water(0.0));
+    let wet = calculate_submersion(Vec3::Y * -3.0, heeled, VOXEL_SIZE, 4, calm_water(0.0));
+    let awash = calculate_submersion(Vec3::ZERO, heeled, VOXEL_SIZE, 4, calm_water(0.0));
+
+    assert_eq!(dry.volume, 0.0);
+    assert!((wet.volume - VOXEL_VOLUME).abs() < 1e-3);
+    assert!(awash.volume > 0.0 && awash.volume < VOXEL_VOLUME);
+    assert!(awash.center_of_buoyancy.y < 0.0);
+}
+
+#[test]
+fn test_sloping_water_shifts_buoyancy_to_the_deeper_side() {
+    let slope = |position: Vec3| position.x * 0.5;
+    let submersion = calculate_submersion(Vec3::ZERO, Quat::IDENTITY, VOXEL_SIZE, 4, slope);
+
+    assert!(submersion.volume > 0.0 && submersion.volume < VOXEL_VOLUME);
+    assert!(submersion.center_of_buoyancy.x > 0.0);
+}
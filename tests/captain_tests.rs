@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use empire_of_wind::ai_captain_behavior::{
+    calculate_helm_angle, calculate_sailable_heading, calculate_weather_gage, evaluate_approach,
+    evaluate_board, evaluate_broadside, evaluate_flee, Captain, BROADSIDE_RANGE, NO_GO_ANGLE,
+};
+
+fn engaged(distance: f32) -> Captain {
+    Captain {
+        target: Some(Entity::from_raw(1)),
+        distance,
+        bearing: Vec3::X,
+        health: 1.0,
+        target_health: 1.0,
+        crew_ratio: 1.0,
+        ..default()
+    }
+}
+
+#[test]
+fn test_headings_into_the_wind_are_turned_onto_a_tack() {
+    // The wind blows towards -X, so it comes from +X
+    let wind_direction = Vec3::NEG_X;
+
+    let reach = calculate_sailable_heading(Vec3::Z, wind_direction);
+    assert!(reach.abs_diff_eq(Vec3::Z, 1e-5));
+
+    let dead_to_windward = Vec3::new(1.0, 0.0, 0.1).normalize();
+    let tack = calculate_sailable_heading(dead_to_windward, wind_direction);
+    assert!((tack.angle_between(Vec3::X) - NO_GO_ANGLE).abs() < 1e-4);
+    assert!(tack.z > 0.0, "the nearer tack should be chosen");
+}
+
+#[test]
+fn test_helm_turns_towards_the_ordered_heading() {
+    // A positive helm angle turns the ship to starboard, which lies along +Z
+    assert!(calculate_helm_angle(Vec3::X, Vec3::Z) > 0.0);
+    assert!(calculate_helm_angle(Vec3::X, Vec3::NEG_Z) < 0.0);
+    assert!(calculate_helm_angle(Vec3::X, Vec3::X).abs() < 1e-6);
+}
+
+#[test]
+fn test_weather_gage_belongs_to_the_upwind_ship() {
+    let wind_direction = Vec3::X;
+
+    assert!(calculate_weather_gage(Vec3::ZERO, Vec3::new(100.0, 0.0, 0.0), wind_direction) > 0.9);
+    assert!(calculate_weather_gage(Vec3::new(100.0, 0.0, 0.0), Vec3::ZERO, wind_direction) < -0.9);
+}
+
+#[test]
+fn test_captain_chooses_by_range_damage_and_crew() {
+    let distant = engaged(BROADSIDE_RANGE * 3.0);
+    assert!(evaluate_approach(&distant) > 0.0);
+    assert_eq!(evaluate_broadside(&distant), 0.0);
+
+    let in_range = engaged(BROADSIDE_RANGE / 2.0);
+    assert_eq!(evaluate_approach(&in_range), 0.0);
+    assert!(evaluate_broadside(&in_range) > evaluate_board(&in_range));
+
+    let battered = Captain {
+        health: 0.2,
+        ..engaged(BROADSIDE_RANGE / 2.0)
+    };
+    assert!(evaluate_flee(&battered) > evaluate_broadside(&battered));
+
+    let outnumbered = Captain {
+        crew_ratio: 0.25,
+        ..engaged(BROADSIDE_RANGE * 3.0)
+    };
+    assert!(evaluate_flee(&outnumbered) > 0.0);
+    assert_eq!(evaluate_approach(&outnumbered), 0.0);
+
+    let boarding = Captain {
+        crew_ratio: 2.0,
+        target_health: 0.1,
+        ..engaged(BROADSIDE_RANGE / 2.0)
+    };
+    assert!(evaluate_board(&boarding) > evaluate_broadside(&boarding));
+
+    let no_target = Captain::default();
+    assert_eq!(evaluate_approach(&no_target), 0.0);
+    assert_eq!(evaluate_flee(&no_target), 0.0);
+}
@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use big_brain::prelude::{ActionSpan, ActionState, Actor};
+use empire_of_wind::ai_need_behavior::{satisfy_need_action, Fatigue, Need, Sleep};
+use empire_of_wind::claims::{hold_claims, Claimable, CLAIM_TIMEOUT};
+
+#[test]
+fn test_claimable_turns_claimants_away_once_full() {
+    let mut hammocks = Claimable::new(2);
+    let crew: Vec<Entity> = (0..3).map(Entity::from_raw).collect();
+
+    assert!(hammocks.claim(crew[0], 10.0));
+    assert!(hammocks.claim(crew[1], 10.0));
+    assert!(!hammocks.has_room(crew[2]));
+    assert!(!hammocks.claim(crew[2], 10.0));
+
+    // A claimant keeps their claim when claiming again
+    assert!(hammocks.claim(crew[1], 10.0));
+    assert_eq!(hammocks.claims.len(), 2);
+
+    hammocks.release(crew[0]);
+    assert!(!hammocks.is_claimed_by(crew[0]));
+    assert!(hammocks.claim(crew[2], 10.0));
+}
+
+#[test]
+fn test_claims_lapse_unless_renewed() {
+    let mut pump = Claimable::new(1);
+    let first = Entity::from_raw(0);
+    let second = Entity::from_raw(1);
+
+    pump.claim(first, 10.0);
+    pump.expire(6.0);
+    pump.renew(first, 10.0);
+    pump.expire(6.0);
+    assert!(pump.is_claimed_by(first));
+    assert!(!pump.has_room(second));
+
+    pump.expire(6.0);
+    assert!(!pump.is_claimed_by(first));
+    assert!(pump.claim(second, 10.0));
+}
+
+#[test]
+fn test_claims_are_released_as_soon_as_the_action_ends() {
+    let mut app = App::new();
+    app.insert_resource(Time::<()>::default());
+    app.add_systems(
+        Update,
+        (satisfy_need_action::<Fatigue>, hold_claims::<Sleep>).chain(),
+    );
+
+    // Each sleeper has claimed a hammock and turned in
+    let mut turn_in = |fatigue: f32| {
+        let mut need = Need::<Fatigue>::new(4.0);
+        need.level = fatigue;
+        let sleeper = app.world.spawn(need).id();
+        let mut hammock = Claimable::new(1);
+        hammock.claim(sleeper, CLAIM_TIMEOUT);
+        let hammock = app.world.spawn(hammock).id();
+        let action = app.world.spawn_empty().id();
+        app.world.entity_mut(action).insert((
+            Actor(sleeper),
+            ActionState::Requested,
+            Sleep::new(10.0, 10.0),
+            ActionSpan::new(action, None),
+        ));
+        (hammock, action)
+    };
+    let (finished_hammock, finished) = turn_in(15.0);
+    let (cancelled_hammock, cancelled) = turn_in(50.0);
+    let is_claimed = |app: &App, hammock: Entity| {
+        !app.world
+            .get::<Claimable>(hammock)
+            .unwrap()
+            .claims
+            .is_empty()
+    };
+
+    app.update();
+    assert!(is_claimed(&app, finished_hammock));
+    assert!(is_claimed(&app, cancelled_hammock));
+
+    // One sleeper is rested while the other is woken, well before their claims would lapse
+    *app.world.get_mut::<ActionState>(cancelled).unwrap() = ActionState::Cancelled;
+    app.world
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1));
+    app.update();
+
+    assert_eq!(
+        *app.world.get::<ActionState>(finished).unwrap(),
+        ActionState::Success
+    );
+    assert_eq!(
+        *app.world.get::<ActionState>(cancelled).unwrap(),
+        ActionState::Failure
+    );
+    assert!(!is_claimed(&app, finished_hammock));
+    assert!(!is_claimed(&app, cancelled_hammock));
+}
@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+use empire_of_wind::crew_management::{
+    apply_station_orders, calculate_manning_level, calculate_skill_effectiveness,
+    draw_up_duty_roster, load_crew_records, save_crew_records, sign_on_crew_skills, CrewRecords,
+    CrewRole, CrewSkills, Skill, Station, NOVICE_EFFECTIVENESS,
+};
+use empire_of_wind::crew_orders::{assign_nearest_targets, find_next_crew_member};
+
+#[test]
+fn test_duty_roster_assigns_crew_to_stations_of_their_role() {
+    let helm = Entity::from_raw(1);
+    let fore_gun = Entity::from_raw(2);
+    let aft_gun = Entity::from_raw(3);
+    let helmsman = Entity::from_raw(10);
+    let gunner = Entity::from_raw(11);
+    let cook = Entity::from_raw(12);
+
+    let stations = [
+        (helm, CrewRole::Helmsman, None),
+        (fore_gun, CrewRole::Gunner, None),
+        (aft_gun, CrewRole::Gunner, None),
+    ];
+    let crew = [
+        (cook, CrewRole::Cook, true),
+        (gunner, CrewRole::Gunner, true),
+        (helmsman, CrewRole::Helmsman, true),
+    ];
+
+    let roster = draw_up_duty_roster(&stations, &crew);
+
+    assert_eq!(roster, vec![(helm, helmsman), (fore_gun, gunner)]);
+}
+
+#[test]
+fn test_duty_roster_keeps_existing_assignments() {
+    let fore_gun = Entity::from_raw(1);
+    let aft_gun = Entity::from_raw(2);
+    let first_gunner = Entity::from_raw(10);
+    let second_gunner = Entity::from_raw(11);
+
+    let stations = [
+        (fore_gun, CrewRole::Gunner, Some(first_gunner)),
+        (aft_gun, CrewRole::Gunner, None),
+    ];
+    let crew = [
+        (first_gunner, CrewRole::Gunner, true),
+        (second_gunner, CrewRole::Gunner, true),
+    ];
+
+    let roster = draw_up_duty_roster(&stations, &crew);
+
+    assert_eq!(roster, vec![(aft_gun, second_gunner)]);
+}
+
+#[test]
+fn test_station_orders_relieve_the_crew_through_the_roster() {
+    let fore_gun = Entity::from_raw(1);
+    let aft_gun = Entity::from_raw(2);
+    let first_gunner = Entity::from_raw(10);
+    let second_gunner = Entity::from_raw(11);
+
+    let stations = [
+        (fore_gun, CrewRole::Gunner, Some(first_gunner)),
+        (aft_gun, CrewRole::Gunner, Some(second_gunner)),
+    ];
+
+    let changes = apply_station_orders(&stations, &[(second_gunner, fore_gun)]);
+    assert_eq!(
+        changes,
+        vec![(fore_gun, Some(second_gunner)), (aft_gun, None)]
+    );
+
+    // The relieved gunner is drawn up for the station left vacant
+    let stations = [
+        (fore_gun, CrewRole::Gunner, Some(second_gunner)),
+        (aft_gun, CrewRole::Gunner, None),
+    ];
+    let crew = [
+        (first_gunner, CrewRole::Gunner, true),
+        (second_gunner, CrewRole::Gunner, true),
+    ];
+    assert_eq!(
+        draw_up_duty_roster(&stations, &crew),
+        vec![(aft_gun, first_gunner)]
+    );
+
+    // An order that has already been carried out changes nothing
+    assert!(apply_station_orders(&stations, &[(second_gunner, fore_gun)]).is_empty());
+}
+
+#[test]
+fn test_manning_level_counts_manned_stations_of_a_role() {
+    let manned = Station {
+        manned: true,
+        ..Station::new(CrewRole::SailHandler)
+    };
+    let unmanned = Station::new(CrewRole::SailHandler);
+    let helm = Station::new(CrewRole::Helmsman);
+    let stations = [manned, unmanned, helm];
+
+    assert_eq!(
+        calculate_manning_level(&stations, CrewRole::SailHandler),
+        0.5
+    );
+    assert_eq!(calculate_manning_level(&stations, CrewRole::Helmsman), 0.0);
+    assert_eq!(calculate_manning_level(&stations, CrewRole::Cook), 1.0);
+}
+
+#[test]
+fn test_selection_cycles_through_the_crew() {
+    let crew = [
+        Entity::from_raw(10),
+        Entity::from_raw(11),
+        Entity::from_raw(12),
+    ];
+
+    assert_eq!(find_next_crew_member(&crew, &[]), Some(crew[0]));
+    assert_eq!(find_next_crew_member(&crew, &[crew[0]]), Some(crew[1]));
+    assert_eq!(find_next_crew_member(&crew, &[crew[2]]), Some(crew[0]));
+    assert_eq!(
+        find_next_crew_member(&crew, &[crew[1], crew[2]]),
+        Some(crew[0])
+    );
+    assert_eq!(find_next_crew_member(&crew, &crew), None);
+}
+
+#[test]
+fn test_orders_send_each_crew_member_to_a_different_nearby_target() {
+    let crew = [
+        Entity::from_raw(10),
+        Entity::from_raw(11),
+        Entity::from_raw(12),
+    ];
+    let near_pump = Entity::from_raw(1);
+    let far_cannon = Entity::from_raw(2);
+    let targets = [
+        (far_cannon, Vec3::new(10.0, 0.0, 0.0)),
+        (near_pump, Vec3::new(1.0, 0.0, 0.0)),
+    ];
+
+    let orders = assign_nearest_targets(&crew, Vec3::ZERO, &targets);
+
+    assert_eq!(orders, vec![(crew[0], near_pump), (crew[1], far_cannon)]);
+}
+
+#[test]
+fn test_experience_makes_crew_more_effective() {
+    assert_eq!(calculate_skill_effectiveness(0.0), NOVICE_EFFECTIVENESS);
+    assert!(calculate_skill_effectiveness(600.0) > calculate_skill_effectiveness(60.0));
+    assert!(calculate_skill_effectiveness(1.0e6) <= 1.0);
+
+    let mut skills = CrewSkills::rated(CrewRole::Gunner.skill());
+    assert!(skills.effectiveness(Skill::Gunnery) > skills.effectiveness(Skill::Seamanship));
+
+    let seamanship = skills.effectiveness(Skill::Seamanship);
+    skills.gain_experience(Skill::Seamanship, 300.0);
+    assert!(skills.effectiveness(Skill::Seamanship) > seamanship);
+    assert_eq!(skills.experience(Skill::Cooking), 0.0);
+}
+
+#[test]
+fn test_crew_skills_are_saved_and_reloaded_across_voyages() {
+    let directory = std::env::temp_dir().join(format!("crew_records_{}", std::process::id()));
+    let mut veteran = CrewSkills::rated(CrewRole::Gunner.skill());
+    veteran.gain_experience(Skill::Carpentry, 600.0);
+    let crew = vec![
+        (CrewRole::Gunner, veteran.clone()),
+        (CrewRole::Cook, CrewSkills::rated(CrewRole::Cook.skill())),
+    ];
+
+    save_crew_records(&directory, "Enemy Ship", &crew).unwrap();
+    assert_eq!(
+        load_crew_records(&directory, "Enemy Ship"),
+        Some(crew.clone())
+    );
+    assert_eq!(load_crew_records(&directory, "Ship"), None);
+
+    // The veterans sign on again in their own roles, and fresh hands fill the rest
+    let records = CrewRecords::with_directory(&directory);
+    let skills = sign_on_crew_skills(
+        &[CrewRole::Helmsman, CrewRole::Gunner, CrewRole::Gunner],
+        records.load("Enemy Ship"),
+    );
+    assert_eq!(
+        skills,
+        vec![
+            CrewSkills::rated(CrewRole::Helmsman.skill()),
+            veteran,
+            CrewSkills::rated(CrewRole::Gunner.skill()),
+        ]
+    );
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
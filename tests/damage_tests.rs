@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use empire_of_wind::buoyancy_physics::{calculate_voxel_solidity, generate_voxel_grid};
+use empire_of_wind::damage::{
+    apply_projectile_damage, calculate_hit_effect, calculate_system_damage, needs_repair,
+    repair_damage, HullIntegrity, Integrity,
+};
+use empire_of_wind::flooding::{find_nearest_solid_voxel, Flooding, HullBreached};
+use empire_of_wind::gunnery::{ProjectileHit, ShotType};
+
+#[test]
+fn test_round_shot_holes_the_hull_and_chain_shot_does_not() {
+    let round_shot = calculate_hit_effect(ShotType::RoundShot, 120.0);
+    let chain_shot = calculate_hit_effect(ShotType::ChainShot, 120.0);
+
+    assert!(round_shot.breach_area > 0.0);
+    assert_eq!(chain_shot.breach_area, 0.0);
+    assert!(chain_shot.system_radius > round_shot.system_radius);
+
+    let spent_shot = calculate_hit_effect(ShotType::RoundShot, 60.0);
+    assert!((spent_shot.breach_area - round_shot.breach_area / 4.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_system_damage_falls_off_with_distance() {
+    let effect = calculate_hit_effect(ShotType::RoundShot, 120.0);
+
+    let direct_hit = calculate_system_damage(&effect, 0.0);
+    let near_miss = calculate_system_damage(&effect, effect.system_radius / 2.0);
+    assert_eq!(direct_hit, effect.system_damage);
+    assert!((near_miss - direct_hit / 2.0).abs() < 1e-6);
+    assert_eq!(
+        calculate_system_damage(&effect, effect.system_radius * 2.0),
+        0.0
+    );
+
+    let mut integrity = Integrity::default();
+    integrity.damage(direct_hit * 3.0);
+    assert_eq!(integrity.level, 0.0);
+    integrity.repair(2.0);
+    assert!(!integrity.is_damaged());
+}
+
+#[test]
+fn test_hull_sections_follow_compartments() {
+    let mut hull_integrity = HullIntegrity::new(2);
+    assert_eq!(hull_integrity.sections.len(), 3);
+    assert_eq!(hull_integrity.section(Some(1)), 1);
+    assert_eq!(hull_integrity.section(None), 2);
+    assert_eq!(hull_integrity.compartment(2), None);
+    assert_eq!(hull_integrity.most_damaged_section(), None);
+
+    hull_integrity.damage(0, 0.2);
+    hull_integrity.damage(2, 0.5);
+    assert_eq!(hull_integrity.most_damaged_section(), Some(2));
+
+    hull_integrity.repair(2, 1.0);
+    assert_eq!(hull_integrity.most_damaged_section(), Some(0));
+}
+
+#[test]
+fn test_holed_hull_is_repaired_before_the_rudder_on_the_same_ship() {
+    let mesh = Mesh::from(Cuboid::new(12.0, 6.0, 6.0));
+    let mut voxels = generate_voxel_grid(&mesh, &Transform::default(), 2.0);
+    calculate_voxel_solidity(&mesh, &Transform::default(), &mut voxels);
+    let mut flooding = Flooding::from_voxels(&voxels, 2.0);
+    let keel_voxel = find_nearest_solid_voxel(&voxels, Vec3::new(0.0, -3.0, 0.0)).unwrap();
+    flooding.breach(keel_voxel, 0.5);
+
+    // The ship entity carries both the rudder's integrity and the hull's.
+    let mut rudder_integrity = Integrity::default();
+    let mut hull_integrity = HullIntegrity::new(0);
+    hull_integrity.damage(0, 0.5);
+    assert!(needs_repair(Some(&rudder_integrity), Some(&hull_integrity)));
+
+    let progress = repair_damage(
+        Some(&mut rudder_integrity),
+        Some((&mut hull_integrity, &mut flooding)),
+        0.25,
+    );
+    assert!(!progress.is_repaired);
+    assert_eq!(progress.repaired, 0.25);
+    assert_eq!(hull_integrity.sections[0], 0.75);
+    assert!(flooding.breach_areas[keel_voxel] < 0.5);
+
+    rudder_integrity.damage(0.5);
+    repair_damage(
+        Some(&mut rudder_integrity),
+        Some((&mut hull_integrity, &mut flooding)),
+        0.25,
+    );
+    assert_eq!(hull_integrity.sections[0], 1.0);
+    assert_eq!(flooding.breach_areas[keel_voxel], 0.0);
+    assert_eq!(rudder_integrity.level, 0.5);
+    assert!(needs_repair(Some(&rudder_integrity), Some(&hull_integrity)));
+
+    let progress = repair_damage(
+        Some(&mut rudder_integrity),
+        Some((&mut hull_integrity, &mut flooding)),
+        1.0,
+    );
+    assert!(progress.is_repaired);
+    assert_eq!(
+        progress.repaired, 0.5,
+        "only the damage left counts as repaired"
+    );
+    assert!(!needs_repair(
+        Some(&rudder_integrity),
+        Some(&hull_integrity)
+    ));
+}
+
+#[test]
+fn test_only_the_struck_ship_loses_system_integrity() {
+    let mut app = App::new();
+    app.add_event::<ProjectileHit>()
+        .add_event::<HullBreached>()
+        .add_systems(Update, apply_projectile_damage);
+
+    // Two ships lie side by side, each with a cannon run out towards the other
+    let mut spawn_ship = |x: f32| {
+        let ship = app
+            .world
+            .spawn((Transform::from_xyz(x, 0.0, 0.0), GlobalTransform::default()))
+            .id();
+        let cannon = app
+            .world
+            .spawn((
+                Integrity::default(),
+                GlobalTransform::from_translation(Vec3::new(x.signum() * -0.5, 0.0, 0.0)),
+            ))
+            .id();
+        app.world.entity_mut(ship).add_child(cannon);
+        (ship, cannon)
+    };
+    let (struck_ship, struck_cannon) = spawn_ship(-2.0);
+    let (_, other_cannon) = spawn_ship(2.0);
+
+    app.world.send_event(ProjectileHit {
+        target: struck_ship,
+        source: Entity::PLACEHOLDER,
+        position: Vec3::ZERO,
+        velocity: Vec3::new(120.0, 0.0, 0.0),
+        shot: ShotType::RoundShot,
+    });
+    app.update();
+
+    assert!(app
+        .world
+        .get::<Integrity>(struck_cannon)
+        .unwrap()
+        .is_damaged());
+    assert!(!app
+        .world
+        .get::<Integrity>(other_cannon)
+        .unwrap()
+        .is_damaged());
+}
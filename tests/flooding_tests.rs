@@ -0,0 +1,276 @@
+use bevy::prelude::*;
+use empire_of_wind::buoyancy_physics::{
+    calculate_mass_properties, calculate_submersion, calculate_voxel_solidity, generate_voxel_grid,
+    Voxel, GRAVITY, WATER_DENSITY,
+};
+use empire_of_wind::flooding::{find_nearest_solid_voxel, simulate_flooding, Flooding};
+use empire_of_wind::ship_items::find_pump_compartment;
+
+const VOXEL_SIZE: f32 = 2.0;
+const HULL_HEIGHT: f32 = 6.0;
+const HULL_DENSITY: f32 = 0.45;
+const TICK: f32 = 0.1;
+
+fn hull_voxels() -> Vec<Voxel> {
+    let mesh = Mesh::from(Cuboid::new(12.0, HULL_HEIGHT, 6.0));
+    let mut voxels = generate_voxel_grid(&mesh, &Transform::default(), VOXEL_SIZE);
+    calculate_voxel_solidity(&mesh, &Transform::default(), &mut voxels);
+    voxels
+}
+
+/// Finds the transform at which a hull floats level in calm water at height zero.
+fn settle(voxels: &[Voxel], flooding: &Flooding) -> Transform {
+    let hull_mass = calculate_mass_properties(voxels, VOXEL_SIZE, HULL_DENSITY * WATER_DENSITY)
+        .unwrap()
+        .mass;
+    let weight = GRAVITY * (hull_mass + flooding.flooded_volume(VOXEL_SIZE) * WATER_DENSITY);
+
+    let buoyancy_at = |height: f32| -> f32 {
+        voxels
+            .iter()
+            .filter(|voxel| voxel.is_solid)
+            .map(|voxel| {
+                calculate_submersion(
+                    voxel.position + Vec3::Y * height,
+                    Quat::IDENTITY,
+                    VOXEL_SIZE,
+                    1,
+                    |_| 0.0,
+                )
+                .volume
+            })
+            .sum::<f32>()
+            * GRAVITY
+            * WATER_DENSITY
+    };
+
+    let (mut low, mut high) = (-20.0, 20.0);
+    for _ in 0..30 {
+        let middle = (low + high) / 2.0;
+        if buoyancy_at(middle) > weight {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    Transform::from_xyz(0.0, (low + high) / 2.0, 0.0)
+}
+
+fn freeboard(transform: &Transform) -> f32 {
+    transform.translation.y + HULL_HEIGHT / 2.0
+}
+
+/// Runs the flooding for a number of ticks, letting the hull settle after each one.
+fn run_ticks(voxels: &[Voxel], flooding: &mut Flooding, ticks: usize) -> Transform {
+    let mut transform = settle(voxels, flooding);
+    for _ in 0..ticks {
+        simulate_flooding(flooding, voxels, VOXEL_SIZE, &transform, |_| 0.0, TICK);
+        transform = settle(voxels, flooding);
+    }
+    transform
+}
+
+#[test]
+fn test_intact_hull_keeps_its_freeboard() {
+    let voxels = hull_voxels();
+    let mut flooding = Flooding::from_voxels(&voxels, VOXEL_SIZE);
+
+    let transform = run_ticks(&voxels, &mut flooding, 100);
+
+    assert_eq!(flooding.flooded_volume(VOXEL_SIZE), 0.0);
+    assert!((freeboard(&transform) - HULL_HEIGHT * (1.0 - HULL_DENSITY)).abs() < 0.01);
+}
+
+#[test]
+fn test_breach_below_waterline_floods_and_sinks_hull() {
+    let voxels = hull_voxels();
+    let mut flooding = Flooding::from_voxels(&voxels, VOXEL_SIZE);
+    let initial_freeboard = freeboard(&settle(&voxels, &flooding));
+
+    let keel_voxel = find_nearest_solid_voxel(&voxels, Vec3::new(4.0, -3.0, 2.0)).unwrap();
+    flooding.breach(keel_voxel, 1.0);
+
+    let transform = run_ticks(&voxels, &mut flooding, 50);
+    let wet_voxels = flooding
+        .fill_levels
+        .iter()
+        .filter(|fill| **fill > 0.0)
+        .count();
+    assert!(
+        wet_voxels > 1,
+        "water should spread beyond the breached voxel"
+    );
+    assert!(freeboard(&transform) < initial_freeboard);
+
+    let transform = run_ticks(&voxels, &mut flooding, 600);
+    assert!(
+        freeboard(&transform) <= 0.0,
+        "a fully flooded hull should sink"
+    );
+}
+
+#[test]
+fn test_water_collects_in_the_lowest_voxels() {
+    let voxels = hull_voxels();
+    let mut flooding = Flooding::from_voxels(&voxels, VOXEL_SIZE);
+
+    let keel_voxel = find_nearest_solid_voxel(&voxels, Vec3::new(-4.0, -3.0, 0.0)).unwrap();
+    flooding.breach(keel_voxel, 0.2);
+    run_ticks(&voxels, &mut flooding, 100);
+
+    let fill_at_height = |y: f32| -> f32 {
+        voxels
+            .iter()
+            .zip(flooding.fill_levels.iter())
+            .filter(|(voxel, _)| voxel.position.y == y)
+            .map(|(_, fill)| *fill)
+            .sum()
+    };
+    assert!(fill_at_height(-2.0) > fill_at_height(2.0));
+}
+
+#[test]
+fn test_breach_above_waterline_stays_dry() {
+    let voxels = hull_voxels();
+    let mut flooding = Flooding::from_voxels(&voxels, VOXEL_SIZE);
+
+    let deck_voxel = find_nearest_solid_voxel(&voxels, Vec3::new(0.0, 3.0, 0.0)).unwrap();
+    flooding.breach(deck_voxel, 1.0);
+    let transform = run_ticks(&voxels, &mut flooding, 100);
+
+    assert_eq!(flooding.flooded_volume(VOXEL_SIZE), 0.0);
+    assert!(freeboard(&transform) > 3.0);
+}
+
+#[test]
+fn test_bulkhead_keeps_water_in_its_compartment_until_door_opens() {
+    let voxels = hull_voxels();
+    let mut flooding = Flooding::from_voxels(&voxels, VOXEL_SIZE);
+    let bulkhead = Entity::from_raw(1);
+
+    flooding.add_compartment(
+        "Bow",
+        &voxels,
+        Vec3::new(0.0, -3.0, -3.0),
+        Vec3::new(6.0, 3.0, 3.0),
+    );
+    flooding.add_compartment(
+        "Stern",
+        &voxels,
+        Vec3::new(-6.0, -3.0, -3.0),
+        Vec3::new(0.0, 3.0, 3.0),
+    );
+    flooding.add_bulkhead(
+        &voxels,
+        Vec3::new(-0.05, -3.0, -3.0),
+        Vec3::new(0.05, 3.0, 3.0),
+        Some((bulkhead, false)),
+    );
+
+    let bow_voxel = find_nearest_solid_voxel(&voxels, Vec3::new(5.0, -3.0, 0.0)).unwrap();
+    flooding.breach(bow_voxel, 0.5);
+    run_ticks(&voxels, &mut flooding, 100);
+
+    assert!(flooding.compartment_fill_level("Bow").unwrap() > 0.0);
+    assert_eq!(flooding.compartment_fill_level("Stern"), Some(0.0));
+
+    flooding.set_door_open(bulkhead, true);
+    run_ticks(&voxels, &mut flooding, 100);
+
+    assert!(flooding.compartment_fill_level("Stern").unwrap() > 0.0);
+}
+
+#[test]
+fn test_pumping_drains_only_its_compartment() {
+    let voxels = hull_voxels();
+    let mut flooding = Flooding::from_voxels(&voxels, VOXEL_SIZE);
+
+    flooding.add_compartment(
+        "Bow",
+        &voxels,
+        Vec3::new(0.0, -3.0, -3.0),
+        Vec3::new(6.0, 3.0, 3.0),
+    );
+    flooding.add_compartment(
+        "Stern",
+        &voxels,
+        Vec3::new(-6.0, -3.0, -3.0),
+        Vec3::new(0.0, 3.0, 3.0),
+    );
+
+    let keel_voxel = find_nearest_solid_voxel(&voxels, Vec3::new(0.0, -3.0, 0.0)).unwrap();
+    flooding.breach(keel_voxel, 1.0);
+    run_ticks(&voxels, &mut flooding, 50);
+    flooding.breach_areas.fill(0.0);
+
+    let bow_fill = flooding.compartment_fill_level("Bow").unwrap();
+    let stern_fill = flooding.compartment_fill_level("Stern").unwrap();
+    assert!(bow_fill > 0.0 && stern_fill > 0.0);
+
+    let removed = flooding.pump_out(Some("Bow"), 1.0, VOXEL_SIZE);
+    assert!((removed - 1.0).abs() < 1e-4);
+    assert!(flooding.compartment_fill_level("Bow").unwrap() < bow_fill);
+    assert_eq!(flooding.compartment_fill_level("Stern"), Some(stern_fill));
+
+    let total = flooding.flooded_volume(VOXEL_SIZE);
+    let removed = flooding.pump_out(None, total + 10.0, VOXEL_SIZE);
+    assert!((removed - total).abs() < 1e-3);
+    assert_eq!(flooding.fill_level(None, &voxels), Some(0.0));
+}
+
+#[test]
+fn test_compartment_is_found_from_a_position_in_the_hull() {
+    let voxels = hull_voxels();
+    let mut flooding = Flooding::from_voxels(&voxels, VOXEL_SIZE);
+
+    flooding.add_compartment(
+        "Bow",
+        &voxels,
+        Vec3::new(0.0, -3.0, -3.0),
+        Vec3::new(6.0, 3.0, 3.0),
+    );
+
+    let bow = flooding.compartment_at(&voxels, Vec3::new(4.0, 0.0, 0.0));
+    assert_eq!(bow, Some(0));
+    assert_eq!(
+        flooding.compartment_at(&voxels, Vec3::new(-4.0, 0.0, 0.0)),
+        None
+    );
+
+    let centre = flooding.compartment_centre(0, &voxels).unwrap();
+    assert!(centre.x > 0.0 && centre.x < 6.0);
+    assert_eq!(flooding.compartment_centre(1, &voxels), None);
+}
+
+#[test]
+fn test_pump_draws_from_the_bilge_compartment_below_it() {
+    let voxels = hull_voxels();
+    let mut flooding = Flooding::from_voxels(&voxels, VOXEL_SIZE);
+    assert_eq!(
+        find_pump_compartment(&flooding, &voxels, Vec3::new(4.0, 9.0, 0.0)),
+        None
+    );
+
+    flooding.add_compartment(
+        "Bow",
+        &voxels,
+        Vec3::new(0.0, -3.0, -3.0),
+        Vec3::new(6.0, 3.0, 3.0),
+    );
+    flooding.add_compartment(
+        "Stern",
+        &voxels,
+        Vec3::new(-6.0, -3.0, -3.0),
+        Vec3::new(0.0, 3.0, 3.0),
+    );
+
+    assert_eq!(
+        find_pump_compartment(&flooding, &voxels, Vec3::new(4.0, 9.0, 0.0)).as_deref(),
+        Some("Bow")
+    );
+    assert_eq!(
+        find_pump_compartment(&flooding, &voxels, Vec3::new(-4.0, 9.0, 1.0)).as_deref(),
+        Some("Stern")
+    );
+}
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+use big_brain::prelude::{Actor, Score, ScorerSpan};
+use empire_of_wind::ai_need_behavior::{
+    calculate_need_score, suppress_hunger_without_food, Hunger, HungerScorer, Need,
+};
+use empire_of_wind::crew_management::ShipCrew;
+use empire_of_wind::crew_watches::{WatchSchedule, WATCH_HOURS};
+use empire_of_wind::food::{
+    calculate_nourishment, create_meal_recipe, eat_from_stockpile, Food, MEAL_NOURISHMENT,
+    SALT_PORK_NOURISHMENT,
+};
+use empire_of_wind::production::{can_start_job, Goods, Stockpile, Workshop};
+
+fn stockpile(goods: Goods, quantity: f32, capacity: f32) -> Stockpile {
+    Stockpile {
+        goods,
+        quantity,
+        capacity,
+        spoil_rate: 0.0,
+    }
+}
+
+#[test]
+fn test_food_store_runs_out() {
+    let mut store = stockpile(Goods::SaltPork, 1.5, 1.5);
+
+    assert_eq!(store.take(1.0), 1.0);
+    assert!(!store.is_empty());
+    assert_eq!(store.take(1.0), 0.5);
+    assert!(store.is_empty());
+    assert_eq!(store.take(1.0), 0.0);
+}
+
+#[test]
+fn test_provisions_spoil_over_the_voyage() {
+    let mut store = Stockpile {
+        spoil_rate: 0.01,
+        ..stockpile(Goods::Biscuit, 100.0, 100.0)
+    };
+
+    store.spoil(10.0);
+    assert_eq!(store.quantity, 90.0);
+
+    store.spoil(1000.0);
+    assert!(store.is_empty());
+}
+
+#[test]
+fn test_meals_are_more_nourishing_than_raw_rations() {
+    let meal = calculate_nourishment(Goods::Meal).unwrap();
+    assert!(meal > calculate_nourishment(Goods::SaltPork).unwrap());
+    assert!(meal > calculate_nourishment(Goods::Biscuit).unwrap());
+    assert_eq!(calculate_nourishment(Goods::Water), None);
+}
+
+#[test]
+fn test_galley_needs_every_ingredient_and_room_for_the_meals() {
+    let recipe = create_meal_recipe();
+    let salt_pork = stockpile(Goods::SaltPork, 10.0, 10.0);
+    let biscuit = stockpile(Goods::Biscuit, 10.0, 10.0);
+    let water = stockpile(Goods::Water, 10.0, 10.0);
+    let meals = stockpile(Goods::Meal, 0.0, 40.0);
+    let full_meals = stockpile(Goods::Meal, 40.0, 40.0);
+
+    assert!(can_start_job(
+        &recipe,
+        &[&salt_pork, &biscuit, &water, &meals]
+    ));
+    assert!(!can_start_job(&recipe, &[&salt_pork, &biscuit, &meals]));
+    assert!(!can_start_job(
+        &recipe,
+        &[&salt_pork, &biscuit, &water, &full_meals]
+    ));
+}
+
+#[test]
+fn test_cooking_takes_time() {
+    let recipe = create_meal_recipe();
+    let mut galley = Workshop::new(recipe.clone());
+    assert!(!galley.work(recipe.work_time));
+
+    galley.start();
+    assert!(!galley.work(recipe.work_time / 2.0));
+    assert!(galley.is_working());
+    assert!(galley.work(recipe.work_time / 2.0));
+
+    galley.finish();
+    assert!(!galley.is_working());
+}
+
+#[test]
+fn test_crew_set_hunger_aside_once_their_stores_are_empty() {
+    let mut app = App::new();
+    app.insert_resource(WatchSchedule::new(WATCH_HOURS));
+    app.add_systems(
+        Update,
+        (calculate_need_score::<Hunger>, suppress_hunger_without_food).chain(),
+    );
+
+    let mut hunger = Need::<Hunger>::new(4.0);
+    hunger.level = 90.0;
+    let crew_member = app.world.spawn(hunger).id();
+    let scorer = app.world.spawn_empty().id();
+    app.world.entity_mut(scorer).insert((
+        Actor(crew_member),
+        Score::default(),
+        HungerScorer::new(0.6, 0.1),
+        ScorerSpan::new(scorer, None),
+    ));
+
+    let ship = app
+        .world
+        .spawn(ShipCrew {
+            complement: 1,
+            members: vec![crew_member],
+        })
+        .id();
+    let store = app.world.spawn(stockpile(Goods::SaltPork, 0.0, 10.0)).id();
+    app.world.entity_mut(ship).add_child(store);
+
+    // Food aboard another ship is out of reach
+    let other_ship = app.world.spawn(ShipCrew::new(1)).id();
+    let other_store = app
+        .world
+        .spawn((stockpile(Goods::SaltPork, 10.0, 10.0), Food))
+        .id();
+    app.world.entity_mut(other_ship).add_child(other_store);
+
+    app.update();
+    assert_eq!(app.world.get::<Score>(scorer).unwrap().get(), 0.0);
+
+    // The urge to eat returns once the stores are restocked
+    app.world.entity_mut(store).insert(Food);
+    app.update();
+    assert_eq!(app.world.get::<Score>(scorer).unwrap().get(), 0.9);
+}
+
+#[test]
+fn test_a_meal_relieves_more_hunger_than_rations_in_the_same_time() {
+    let mut meals = stockpile(Goods::Meal, 10.0, 10.0);
+    let mut salt_pork = stockpile(Goods::SaltPork, 10.0, 10.0);
+
+    let meal_relief = eat_from_stockpile(&mut meals, 10.0);
+    let ration_relief = eat_from_stockpile(&mut salt_pork, 10.0);
+    assert!(meal_relief > ration_relief);
+    assert!((meal_relief / ration_relief - MEAL_NOURISHMENT / SALT_PORK_NOURISHMENT).abs() < 1e-6);
+
+    // Both are eaten at the same pace
+    assert_eq!(meals.quantity, salt_pork.quantity);
+
+    // An empty store relieves nothing
+    let mut empty = stockpile(Goods::Meal, 0.0, 10.0);
+    assert_eq!(eat_from_stockpile(&mut empty, 10.0), 0.0);
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use empire_of_wind::gunnery::{
+    calculate_barrel_direction, calculate_broadside, calculate_shot_range, scatter_direction,
+    Broadside,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn test_broadside_guns_point_over_their_side() {
+    let port = calculate_barrel_direction(Quat::from_rotation_y(90.0f32.to_radians()), 0.0);
+    let starboard = calculate_barrel_direction(Quat::from_rotation_y(-90.0f32.to_radians()), 0.0);
+
+    assert_eq!(
+        calculate_broadside(Quat::IDENTITY, port),
+        Some(Broadside::Port)
+    );
+    assert_eq!(
+        calculate_broadside(Quat::IDENTITY, starboard),
+        Some(Broadside::Starboard)
+    );
+    assert_eq!(calculate_broadside(Quat::IDENTITY, Vec3::X), None);
+
+    // A ship turned about keeps its broadsides on the same sides
+    let turned = Quat::from_rotation_y(180.0f32.to_radians());
+    assert_eq!(
+        calculate_broadside(turned, turned * port),
+        Some(Broadside::Port)
+    );
+}
+
+#[test]
+fn test_elevation_raises_the_barrel_and_range() {
+    let level = calculate_barrel_direction(Quat::IDENTITY, 0.0);
+    let raised = calculate_barrel_direction(Quat::IDENTITY, 10.0f32.to_radians());
+    assert!(raised.y > level.y);
+    assert!((raised.length() - 1.0).abs() < 1e-5);
+
+    let point_blank = calculate_shot_range(120.0, 0.0, 4.905, 9.81);
+    assert!((point_blank - 120.0).abs() < 1e-2);
+    assert!(calculate_shot_range(120.0, 10.0f32.to_radians(), 4.905, 9.81) > point_blank);
+}
+
+#[test]
+fn test_scattered_shot_stays_within_its_spread() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let spread = 5.0f32.to_radians();
+
+    for _ in 0..100 {
+        let direction = scatter_direction(Vec3::Z, spread, &mut rng);
+        assert!((direction.length() - 1.0).abs() < 1e-5);
+        assert!(direction.angle_between(Vec3::Z) <= spread + 1e-4);
+    }
+}
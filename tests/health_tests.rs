@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use empire_of_wind::crew_health::{
+    calculate_fall_injury, calculate_hit_injury, remove_dead_crew, CrewDied, Health, InjuryKind,
+    MIN_WORK_RATE, SHOT_INJURY_SEVERITY, SPLINTER_RADIUS,
+};
+use empire_of_wind::crew_management::ShipCrew;
+use empire_of_wind::gunnery::ShotType;
+
+#[test]
+fn test_round_shot_hurts_crew_with_shot_and_splinters() {
+    assert_eq!(
+        calculate_hit_injury(ShotType::RoundShot, 0.5),
+        Some((InjuryKind::Shot, SHOT_INJURY_SEVERITY))
+    );
+
+    let (kind, near) = calculate_hit_injury(ShotType::RoundShot, 2.0).unwrap();
+    let (_, far) = calculate_hit_injury(ShotType::RoundShot, 4.0).unwrap();
+    assert_eq!(kind, InjuryKind::Splinter);
+    assert!(near > far);
+
+    assert_eq!(
+        calculate_hit_injury(ShotType::RoundShot, SPLINTER_RADIUS + 1.0),
+        None
+    );
+    assert_eq!(calculate_hit_injury(ShotType::ChainShot, 2.0), None);
+}
+
+#[test]
+fn test_only_hard_landings_hurt() {
+    assert_eq!(calculate_fall_injury(3.0), None);
+    assert!(calculate_fall_injury(20.0).unwrap() > calculate_fall_injury(12.0).unwrap());
+}
+
+#[test]
+fn test_injuries_slow_crew_until_treated() {
+    let mut health = Health::default();
+    assert_eq!(health.work_rate(), 1.0);
+
+    health.injure(InjuryKind::Splinter, 0.4);
+    assert!(health.is_injured());
+    assert!(health.work_rate() < 1.0 && health.work_rate() > MIN_WORK_RATE);
+
+    health.treat(0.2);
+    assert!(health.is_injured());
+    health.treat(1.0);
+    assert!(!health.is_injured());
+    assert_eq!(health.level, 1.0);
+
+    health.injure(InjuryKind::Shot, 2.0);
+    assert!(health.is_dead());
+    assert_eq!(health.work_rate(), MIN_WORK_RATE);
+}
+
+#[test]
+fn test_starvation_wears_health_down_as_a_single_injury() {
+    let mut health = Health::default();
+    health.starve(0.1);
+    health.starve(0.2);
+
+    assert!((health.level - 0.7).abs() < 1e-6);
+    assert_eq!(health.injuries.len(), 1);
+    assert_eq!(health.injuries[0].kind, InjuryKind::Starvation);
+    assert!((health.injuries[0].severity - 0.3).abs() < 1e-6);
+
+    health.starve(1.0);
+    assert!(health.is_dead());
+}
+
+#[test]
+fn test_dead_crew_are_struck_from_the_roster() {
+    let mut app = App::new();
+    app.add_event::<CrewDied>()
+        .add_systems(Update, remove_dead_crew);
+
+    let mut wounded = Health::default();
+    wounded.injure(InjuryKind::Shot, 0.5);
+    let mut dead = Health::default();
+    dead.injure(InjuryKind::Shot, 1.0);
+    let survivor = app.world.spawn(wounded).id();
+    let casualty = app.world.spawn(dead).id();
+    let ship = app
+        .world
+        .spawn(ShipCrew {
+            complement: 2,
+            members: vec![survivor, casualty],
+        })
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        app.world.get::<ShipCrew>(ship).unwrap().members,
+        vec![survivor]
+    );
+    assert!(app.world.get_entity(casualty).is_none());
+    assert!(app.world.get_entity(survivor).is_some());
+
+    let events = app.world.resource::<Events<CrewDied>>();
+    let died: Vec<&CrewDied> = events.iter_current_update_events().collect();
+    assert_eq!(died.len(), 1);
+    assert_eq!(died[0].crew_member, casualty);
+    assert_eq!(died[0].ship, Some(ship));
+    assert_eq!(died[0].cause, Some(InjuryKind::Shot));
+}
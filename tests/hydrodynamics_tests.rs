@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+use empire_of_wind::hydrodynamics::{
+    calculate_hull_drag_velocity, calculate_keel_force, calculate_rudder_force,
+    calculate_slamming_impulse, calculate_voxel_drag, Keel, Rudder,
+};
+use empire_of_wind::ship::{SHIP_FORWARD, SHIP_STARBOARD};
+
+fn keel() -> Keel {
+    Keel::new(50.0, 2000.0, Vec3::ZERO)
+}
+
+fn rudder(angle: f32) -> Rudder {
+    Rudder {
+        angle,
+        ..Rudder::new(35.0f32.to_radians(), 0.5, 400.0, Vec3::new(-10.0, 0.0, 0.0))
+    }
+}
+
+#[test]
+fn test_keel_opposes_the_motion_of_the_hull() {
+    let keel = keel();
+
+    assert_eq!(calculate_keel_force(&keel, Vec3::ZERO), Vec3::ZERO);
+
+    let ahead = calculate_keel_force(&keel, SHIP_FORWARD * 3.0);
+    assert!(ahead.dot(SHIP_FORWARD) < 0.0);
+    assert_eq!(ahead.dot(SHIP_STARBOARD), 0.0);
+
+    let astern = calculate_keel_force(&keel, -SHIP_FORWARD * 3.0);
+    assert!(astern.dot(SHIP_FORWARD) > 0.0);
+
+    let leeway = calculate_keel_force(&keel, SHIP_STARBOARD * 3.0);
+    assert!(leeway.dot(SHIP_STARBOARD) < 0.0);
+}
+
+#[test]
+fn test_keel_drag_grows_with_the_square_of_the_speed() {
+    let keel = keel();
+
+    for speed in [1.0, 2.0, 5.0] {
+        let force = calculate_keel_force(&keel, SHIP_FORWARD * speed);
+        assert!((force.length() - keel.forward_drag * speed * speed).abs() < 1e-2);
+    }
+}
+
+#[test]
+fn test_keel_resists_leeway_more_than_headway() {
+    let keel = keel();
+
+    let headway = calculate_keel_force(&keel, SHIP_FORWARD * 2.0).length();
+    let leeway = calculate_keel_force(&keel, SHIP_STARBOARD * 2.0).length();
+    assert!(leeway > headway * 10.0);
+}
+
+#[test]
+fn test_rudder_has_no_effect_when_stopped_or_amidships() {
+    assert_eq!(calculate_rudder_force(&rudder(0.3), Vec3::ZERO), Vec3::ZERO);
+    assert_eq!(
+        calculate_rudder_force(&rudder(0.0), SHIP_FORWARD * 4.0),
+        Vec3::ZERO
+    );
+}
+
+#[test]
+fn test_rudder_pushes_the_stern_to_port_under_starboard_helm() {
+    let force = calculate_rudder_force(&rudder(0.3), SHIP_FORWARD * 4.0);
+    assert!(force.dot(SHIP_STARBOARD) < 0.0);
+
+    let opposite = calculate_rudder_force(&rudder(-0.3), SHIP_FORWARD * 4.0);
+    assert!((opposite + force).length() < 1e-3);
+}
+
+#[test]
+fn test_rudder_reverses_when_going_astern() {
+    let ahead = calculate_rudder_force(&rudder(0.3), SHIP_FORWARD * 4.0);
+    let astern = calculate_rudder_force(&rudder(0.3), -SHIP_FORWARD * 4.0);
+
+    assert!((ahead + astern).length() < 1e-3);
+}
+
+#[test]
+fn test_rudder_lift_grows_with_speed_and_peaks_at_45_degrees() {
+    let slow = calculate_rudder_force(&rudder(0.3), SHIP_FORWARD * 2.0).length();
+    let fast = calculate_rudder_force(&rudder(0.3), SHIP_FORWARD * 4.0).length();
+    assert!((fast / slow - 4.0).abs() < 1e-3);
+
+    let peak = calculate_rudder_force(&rudder(45.0f32.to_radians()), SHIP_FORWARD * 4.0).length();
+    let small = calculate_rudder_force(&rudder(20.0f32.to_radians()), SHIP_FORWARD * 4.0).length();
+    let hard_over =
+        calculate_rudder_force(&rudder(70.0f32.to_radians()), SHIP_FORWARD * 4.0).length();
+    assert!(peak > small);
+    assert!(peak > hard_over);
+    assert!((peak - 400.0 * 16.0).abs() < 1e-2);
+}
+
+/// Every face of a lone voxel is exposed.
+const ALL_FACES: u8 = 0b11_1111;
+
+/// Only the bottom (-Y) face of a voxel is exposed.
+const BOTTOM_FACE: u8 = 0b00_1000;
+
+#[test]
+fn test_voxel_drag_opposes_motion_and_vanishes_when_dry() {
+    let velocity = Vec3::new(0.0, -2.0, 0.0);
+
+    let drag = calculate_voxel_drag(ALL_FACES, velocity, 1.0, 2.0, 1025.0);
+    assert!(drag.y > 0.0);
+    assert_eq!(drag.x, 0.0);
+    assert_eq!(drag.z, 0.0);
+
+    assert_eq!(
+        calculate_voxel_drag(ALL_FACES, velocity, 0.0, 2.0, 1025.0),
+        Vec3::ZERO
+    );
+}
+
+#[test]
+fn test_voxel_pressure_drag_grows_with_the_square_of_the_speed_and_submersion() {
+    let slow = calculate_voxel_drag(BOTTOM_FACE, Vec3::NEG_Y * 2.0, 1.0, 2.0, 1025.0);
+    let fast = calculate_voxel_drag(BOTTOM_FACE, Vec3::NEG_Y * 4.0, 1.0, 2.0, 1025.0);
+    let half_wet = calculate_voxel_drag(BOTTOM_FACE, Vec3::NEG_Y * 2.0, 0.5, 2.0, 1025.0);
+
+    // Pressure drag dominates, so doubling the speed roughly quadruples the drag.
+    assert!(fast.y / slow.y > 3.9 && fast.y / slow.y < 4.0);
+    assert!((half_wet.y - slow.y / 2.0).abs() < 1e-2);
+}
+
+#[test]
+fn test_voxel_drag_only_pushes_on_faces_leading_into_the_flow() {
+    let rising = calculate_voxel_drag(BOTTOM_FACE, Vec3::Y * 2.0, 1.0, 2.0, 1025.0);
+    let sinking = calculate_voxel_drag(BOTTOM_FACE, Vec3::NEG_Y * 2.0, 1.0, 2.0, 1025.0);
+
+    // Rising, the bottom face trails the flow and only skin friction acts on it.
+    assert!(rising.y < 0.0);
+    assert!(rising.length() * 100.0 < sinking.length());
+}
+
+#[test]
+fn test_slamming_needs_a_fast_entry_into_the_water() {
+    let slow = calculate_slamming_impulse(BOTTOM_FACE, Quat::IDENTITY, -1.0, 0.2, 2.0, 1025.0);
+    let leaving = calculate_slamming_impulse(BOTTOM_FACE, Quat::IDENTITY, -3.0, -0.2, 2.0, 1025.0);
+    let fast = calculate_slamming_impulse(BOTTOM_FACE, Quat::IDENTITY, -3.0, 0.2, 2.0, 1025.0);
+    let faster = calculate_slamming_impulse(BOTTOM_FACE, Quat::IDENTITY, -6.0, 0.2, 2.0, 1025.0);
+
+    assert_eq!(slow, Vec3::ZERO);
+    assert_eq!(leaving, Vec3::ZERO);
+    assert!(fast.y > 0.0);
+    assert_eq!(fast.x, 0.0);
+    assert!((faster.y - fast.y * 2.0).abs() < 1e-2);
+}
+
+#[test]
+fn test_slamming_only_strikes_downward_faces() {
+    let top_face = 0b00_0100;
+    let upright = calculate_slamming_impulse(top_face, Quat::IDENTITY, -3.0, 0.2, 2.0, 1025.0);
+    let capsized = calculate_slamming_impulse(
+        top_face,
+        Quat::from_rotation_x(std::f32::consts::PI),
+        -3.0,
+        0.2,
+        2.0,
+        1025.0,
+    );
+
+    assert_eq!(upright, Vec3::ZERO);
+    assert!(capsized.y > 0.0);
+}
+
+#[test]
+fn test_hull_drag_leaves_surge_and_sway_to_the_keel() {
+    let offset = Vec3::new(5.0, -1.0, 2.0);
+
+    let sailing = calculate_hull_drag_velocity(
+        Quat::IDENTITY,
+        SHIP_FORWARD * 4.0 + SHIP_STARBOARD * 1.0,
+        Vec3::ZERO,
+        offset,
+        0.0,
+    );
+    assert_eq!(sailing, Vec3::ZERO);
+
+    let heaving =
+        calculate_hull_drag_velocity(Quat::IDENTITY, Vec3::Y * -2.0, Vec3::ZERO, offset, 0.5);
+    assert_eq!(heaving, Vec3::Y * -2.5);
+
+    let yawing = calculate_hull_drag_velocity(
+        Quat::IDENTITY,
+        Vec3::ZERO,
+        Vec3::Y * 0.1,
+        SHIP_FORWARD * 10.0,
+        0.0,
+    );
+    assert!(yawing.dot(SHIP_STARBOARD).abs() > 0.5);
+}
@@ -0,0 +1,34 @@
+use empire_of_wind::crew_morale::{
+    calculate_morale_change, calculate_morale_work_rate, Morale, CONTENT_MORALE,
+    LOW_MORALE_WORK_RATE, VOYAGE_MORALE_DECAY,
+};
+
+#[test]
+fn test_morale_wears_down_with_unmet_needs() {
+    let voyage = calculate_morale_change(Some((10.0, false)), Some((10.0, false)));
+    assert_eq!(voyage, -VOYAGE_MORALE_DECAY);
+
+    let starving = calculate_morale_change(Some((95.0, false)), Some((10.0, false)));
+    let starving_and_exhausted = calculate_morale_change(Some((95.0, false)), Some((95.0, false)));
+    assert!(starving < voyage);
+    assert!(starving_and_exhausted < starving);
+}
+
+#[test]
+fn test_meals_and_rest_restore_morale() {
+    assert!(calculate_morale_change(Some((95.0, true)), Some((10.0, false))) > 0.0);
+    assert!(calculate_morale_change(Some((10.0, false)), Some((95.0, true))) > 0.0);
+}
+
+#[test]
+fn test_low_morale_slows_work() {
+    assert_eq!(calculate_morale_work_rate(CONTENT_MORALE), 1.0);
+    assert_eq!(calculate_morale_work_rate(1.0), 1.0);
+    assert_eq!(calculate_morale_work_rate(0.0), LOW_MORALE_WORK_RATE);
+
+    let mut morale = Morale::default();
+    morale.lower(2.0);
+    assert_eq!(morale.level, 0.0);
+    morale.raise(2.0);
+    assert_eq!(morale.level, 1.0);
+}
@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use big_brain::prelude::{ActionSpan, ActionState, Actor, Score, ScorerSpan};
+use empire_of_wind::ai_need_behavior::{
+    calculate_need_score, satisfy_need_action, Eat, Fatigue, Hunger, HungerScorer, Need,
+    MAX_NEED_LEVEL,
+};
+use empire_of_wind::crew_watches::{WatchSchedule, WATCH_HOURS};
+use empire_of_wind::utils::ScoreHysteresis;
+
+#[test]
+fn test_needs_grow_up_to_the_maximum() {
+    let mut hunger = Need::<Hunger>::new(4.0);
+    hunger.grow(10.0);
+    assert_eq!(hunger.level, 40.0);
+
+    hunger.grow(100.0);
+    assert_eq!(hunger.level, MAX_NEED_LEVEL);
+}
+
+#[test]
+fn test_needs_are_relieved_down_to_nothing() {
+    let mut fatigue = Need::<Fatigue>::new(4.0);
+    fatigue.grow(20.0);
+    fatigue.relieve(30.0);
+    assert_eq!(fatigue.level, 50.0);
+
+    fatigue.relieve(100.0);
+    assert_eq!(fatigue.level, 0.0);
+}
+
+#[test]
+fn test_score_is_held_between_enter_and_exit_thresholds() {
+    let mut hysteresis = ScoreHysteresis::new(0.6, 0.1);
+
+    assert_eq!(hysteresis.apply(0.5), 0.5);
+    assert!(!hysteresis.is_holding());
+
+    assert_eq!(hysteresis.apply(0.7), 0.7);
+    assert_eq!(hysteresis.apply(0.3), 0.7);
+    assert_eq!(hysteresis.apply(0.8), 0.8);
+    assert!(hysteresis.is_holding());
+
+    assert_eq!(hysteresis.apply(0.1), 0.1);
+    assert!(!hysteresis.is_holding());
+}
+
+#[test]
+fn test_each_crew_member_keeps_their_own_score_state() {
+    let mut crew: Vec<(Need<Hunger>, HungerScorer)> = (0..8)
+        .map(|_| (Need::new(4.0), HungerScorer::new(0.6, 0.1)))
+        .collect();
+    let score = |(need, scorer): &mut (Need<Hunger>, HungerScorer)| {
+        scorer.hysteresis.apply(need.level / MAX_NEED_LEVEL)
+    };
+
+    // The first crew member grows hungry and starts eating while the others are still fed
+    crew[0].0.grow(20.0);
+    crew[1].0.grow(10.0);
+    let hungry_score = score(&mut crew[0]);
+    assert_eq!(hungry_score, 0.8);
+    assert_eq!(score(&mut crew[1]), 0.4);
+
+    // The second crew member grows hungry in turn, while the first is part way through eating
+    crew[0].0.relieve(50.0);
+    crew[1].0.grow(10.0);
+    assert_eq!(score(&mut crew[0]), hungry_score);
+    assert_eq!(score(&mut crew[1]), 0.8);
+
+    // The first finishes eating without the second losing the score they are held at
+    crew[0].0.relieve(30.0);
+    crew[1].0.relieve(50.0);
+    assert_eq!(score(&mut crew[0]), 0.0);
+    assert_eq!(score(&mut crew[1]), 0.8);
+    assert!(!crew[0].1.hysteresis.is_holding());
+    assert!(crew[1].1.hysteresis.is_holding());
+
+    for member in crew.iter_mut().skip(2) {
+        assert_eq!(score(member), 0.0);
+        assert!(!member.1.hysteresis.is_holding());
+    }
+}
+
+#[test]
+fn test_each_scorer_keeps_its_own_state_when_scored_together() {
+    let mut app = App::new();
+    app.insert_resource(WatchSchedule::new(WATCH_HOURS));
+    app.add_systems(Update, calculate_need_score::<Hunger>);
+
+    let crew: Vec<(Entity, Entity)> = (0..4)
+        .map(|_| {
+            let actor = app.world.spawn(Need::<Hunger>::new(4.0)).id();
+            let scorer = app.world.spawn_empty().id();
+            app.world.entity_mut(scorer).insert((
+                Actor(actor),
+                Score::default(),
+                HungerScorer::new(0.6, 0.1),
+                ScorerSpan::new(scorer, None),
+            ));
+            (actor, scorer)
+        })
+        .collect();
+
+    let set_hunger = |app: &mut App, member: usize, level: f32| {
+        app.world
+            .get_mut::<Need<Hunger>>(crew[member].0)
+            .unwrap()
+            .level = level;
+    };
+    let score = |app: &App, member: usize| app.world.get::<Score>(crew[member].1).unwrap().get();
+    let is_holding = |app: &App, member: usize| {
+        app.world
+            .get::<HungerScorer>(crew[member].1)
+            .unwrap()
+            .hysteresis
+            .is_holding()
+    };
+
+    // The first crew member is hungry, the second peckish and the rest are fed
+    set_hunger(&mut app, 0, 80.0);
+    set_hunger(&mut app, 1, 40.0);
+    app.update();
+    assert_eq!(score(&app, 0), 0.8);
+    assert_eq!(score(&app, 1), 0.4);
+    assert!(is_holding(&app, 0));
+    assert!(!is_holding(&app, 1));
+
+    // The first is part way through eating as the second grows hungry in turn
+    set_hunger(&mut app, 0, 30.0);
+    set_hunger(&mut app, 1, 70.0);
+    app.update();
+    assert_eq!(score(&app, 0), 0.8);
+    assert_eq!(score(&app, 1), 0.7);
+
+    // The first finishes eating without the second losing the score they are held at
+    set_hunger(&mut app, 0, 0.0);
+    set_hunger(&mut app, 1, 30.0);
+    app.update();
+    assert_eq!(score(&app, 0), 0.0);
+    assert_eq!(score(&app, 1), 0.7);
+    assert!(!is_holding(&app, 0));
+    assert!(is_holding(&app, 1));
+
+    for member in 2..crew.len() {
+        assert_eq!(score(&app, member), 0.0);
+        assert!(!is_holding(&app, member));
+    }
+}
+
+#[test]
+fn test_need_is_relieved_at_the_rate_of_the_action() {
+    let mut app = App::new();
+    app.insert_resource(Time::<()>::default());
+    app.add_systems(Update, satisfy_need_action::<Hunger>);
+
+    // One crew member eats rations, the other a meal twice as nourishing
+    let mut eat = |rate: f32| {
+        let mut hunger = Need::<Hunger>::new(4.0);
+        hunger.level = 25.0;
+        hunger.is_satisfying = true;
+        let crew_member = app.world.spawn(hunger).id();
+        let action = app.world.spawn_empty().id();
+        app.world.entity_mut(action).insert((
+            Actor(crew_member),
+            ActionState::Executing,
+            Eat {
+                rate,
+                ..Eat::new(10.0, 10.0)
+            },
+            ActionSpan::new(action, None),
+        ));
+        (crew_member, action)
+    };
+    let (rations_eater, rations) = eat(1.0);
+    let (meal_eater, meal) = eat(2.0);
+
+    app.world
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs(1));
+    app.update();
+
+    // The meal is satisfied on the same frame its hunger is relieved
+    assert_eq!(
+        app.world.get::<Need<Hunger>>(meal_eater).unwrap().level,
+        5.0
+    );
+    assert_eq!(
+        *app.world.get::<ActionState>(meal).unwrap(),
+        ActionState::Success
+    );
+    assert_eq!(
+        app.world.get::<Need<Hunger>>(rations_eater).unwrap().level,
+        15.0
+    );
+    assert_eq!(
+        *app.world.get::<ActionState>(rations).unwrap(),
+        ActionState::Executing
+    );
+}
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+use empire_of_wind::sailing::{calculate_sail_force, Sail};
+use empire_of_wind::ship::{SHIP_FORWARD, SHIP_STARBOARD};
+
+#[test]
+fn test_sail_has_no_force_without_wind_or_canvas() {
+    let sail = Sail::new(100.0);
+    assert_eq!(calculate_sail_force(&sail, Vec3::ZERO), Vec3::ZERO);
+
+    // A vertical draught is ignored, as the force only acts in the deck plane
+    assert_eq!(calculate_sail_force(&sail, Vec3::Y * 10.0), Vec3::ZERO);
+
+    let furled = Sail {
+        furl: 1.0,
+        ..Sail::new(100.0)
+    };
+    assert_eq!(
+        calculate_sail_force(&furled, SHIP_FORWARD * 10.0),
+        Vec3::ZERO
+    );
+}
+
+#[test]
+fn test_wind_from_astern_drives_the_ship_forward() {
+    let sail = Sail::new(100.0);
+    let force = calculate_sail_force(&sail, SHIP_FORWARD * 10.0);
+
+    assert!(force.dot(SHIP_FORWARD) > 0.0);
+    assert_eq!(force.y, 0.0);
+}
+
+#[test]
+fn test_wind_on_the_beam_heels_the_ship_to_leeward() {
+    let sail = Sail::new(100.0);
+
+    // A wind from port blows towards starboard, pushing the ship forward and to starboard
+    let from_port = calculate_sail_force(&sail, SHIP_STARBOARD * 10.0);
+    assert!(from_port.dot(SHIP_FORWARD) > 0.0);
+    assert!(from_port.dot(SHIP_STARBOARD) > 0.0);
+
+    // The same wind from starboard mirrors the force across the centreline
+    let from_starboard = calculate_sail_force(&sail, -SHIP_STARBOARD * 10.0);
+    assert!((from_starboard.dot(SHIP_FORWARD) - from_port.dot(SHIP_FORWARD)).abs() < 1e-3);
+    assert!((from_starboard.dot(SHIP_STARBOARD) + from_port.dot(SHIP_STARBOARD)).abs() < 1e-3);
+}
+
+#[test]
+fn test_head_to_wind_the_sail_is_taken_aback() {
+    let sail = Sail::new(100.0);
+    let force = calculate_sail_force(&sail, -SHIP_FORWARD * 10.0);
+
+    assert!(force.dot(SHIP_FORWARD) < 0.0);
+}
+
+#[test]
+fn test_sail_force_grows_with_the_square_of_the_wind_speed() {
+    let sail = Sail::new(100.0);
+    let wind = (SHIP_FORWARD + SHIP_STARBOARD).normalize();
+
+    let light = calculate_sail_force(&sail, wind * 5.0).length();
+    let strong = calculate_sail_force(&sail, wind * 10.0).length();
+    assert!((strong / light - 4.0).abs() < 1e-3);
+
+    let reefed = Sail {
+        furl: 0.5,
+        ..Sail::new(100.0)
+    };
+    let half = calculate_sail_force(&reefed, wind * 10.0).length();
+    assert!((strong / half - 2.0).abs() < 1e-3);
+}
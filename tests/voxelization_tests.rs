@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+use empire_of_wind::buoyancy_physics::{
+    calculate_voxel_cache_key, calculate_voxel_solidity, calculate_winding_number,
+    extract_triangles, generate_voxel_grid, is_point_inside_mesh, load_cached_voxels,
+    save_cached_voxels, VoxelCache,
+};
+
+fn hull_mesh() -> Mesh {
+    Mesh::from(Cuboid::new(8.0, 4.0, 4.0))
+}
+
+#[test]
+fn test_cuboid_voxels_are_all_solid() {
+    let mesh = hull_mesh();
+    let transform = Transform::default();
+
+    let mut voxels = generate_voxel_grid(&mesh, &transform, 2.0);
+    calculate_voxel_solidity(&mesh, &transform, &mut voxels);
+
+    assert_eq!(voxels.len(), 4 * 2 * 2);
+    assert!(voxels.iter().all(|voxel| voxel.is_solid));
+}
+
+#[test]
+fn test_small_sphere_is_voxelized_with_fine_voxels() {
+    let mesh = Mesh::from(Sphere::new(0.2));
+    let transform = Transform::default();
+
+    let mut coarse = generate_voxel_grid(&mesh, &transform, 2.0);
+    calculate_voxel_solidity(&mesh, &transform, &mut coarse);
+    let mut fine = generate_voxel_grid(&mesh, &transform, 0.1);
+    calculate_voxel_solidity(&mesh, &transform, &mut fine);
+
+    assert_eq!(coarse.len(), 1);
+    assert_eq!(coarse[0].position, Vec3::ZERO);
+    assert_eq!(fine.len(), 4 * 4 * 4);
+    assert_eq!(fine.iter().filter(|voxel| voxel.is_solid).count(), 32);
+}
+
+#[test]
+fn test_points_inside_and_outside_mesh() {
+    let triangles = extract_triangles(&Mesh::from(Sphere::new(3.0)));
+
+    assert!(is_point_inside_mesh(Vec3::ZERO, &triangles));
+    assert!(is_point_inside_mesh(Vec3::new(2.0, 0.5, -1.0), &triangles));
+    assert!(!is_point_inside_mesh(Vec3::new(3.5, 0.0, 0.0), &triangles));
+    assert!(!is_point_inside_mesh(
+        Vec3::new(0.0, -10.0, 0.0),
+        &triangles
+    ));
+}
+
+#[test]
+fn test_winding_order_and_indexing_do_not_matter() {
+    let indexed = hull_mesh();
+    let non_indexed = hull_mesh().with_duplicated_vertices();
+
+    let mut reversed = extract_triangles(&indexed);
+    for triangle in reversed.iter_mut() {
+        triangle.swap(1, 2);
+    }
+
+    let point = Vec3::new(1.0, 0.5, 0.5);
+    assert_eq!(extract_triangles(&non_indexed).len(), reversed.len());
+    assert!(calculate_winding_number(point, &extract_triangles(&indexed)) > 0.5);
+    assert!(calculate_winding_number(point, &reversed) < -0.5);
+    assert!(is_point_inside_mesh(point, &reversed));
+    assert!(is_point_inside_mesh(
+        point,
+        &extract_triangles(&non_indexed)
+    ));
+}
+
+#[test]
+fn test_solidity_is_computed_in_mesh_space() {
+    let mesh = hull_mesh();
+    let origin = Transform::default();
+    let offset = Transform::from_xyz(10.0, -3.0, 2.0);
+
+    let mut expected = generate_voxel_grid(&mesh, &origin, 2.0);
+    calculate_voxel_solidity(&mesh, &origin, &mut expected);
+    let mut voxels = generate_voxel_grid(&mesh, &offset, 2.0);
+    calculate_voxel_solidity(&mesh, &offset, &mut voxels);
+
+    for (voxel, expected) in voxels.iter().zip(expected.iter()) {
+        assert_eq!(voxel.position, expected.position + offset.translation);
+        assert_eq!(voxel.is_solid, expected.is_solid);
+    }
+}
+
+#[test]
+fn test_voxel_cache_round_trip() {
+    let mesh = hull_mesh();
+    let transform = Transform::default();
+    let directory = std::env::temp_dir().join(format!("voxel_cache_{}", std::process::id()));
+
+    let voxels = VoxelCache::with_directory(&directory).voxelize(&mesh, &transform, 2.0);
+    let key = calculate_voxel_cache_key(&mesh, &transform, 2.0);
+    let cached = load_cached_voxels(&directory, key).expect("voxels should be cached on disk");
+
+    assert_eq!(cached.len(), voxels.len());
+    assert!(cached
+        .iter()
+        .zip(voxels.iter())
+        .all(|(a, b)| a.position == b.position && a.is_solid == b.is_solid));
+    assert_ne!(
+        key,
+        calculate_voxel_cache_key(&mesh, &Transform::from_xyz(1.0, 0.0, 0.0), 2.0)
+    );
+
+    save_cached_voxels(&directory, key, &[]).unwrap();
+    assert!(load_cached_voxels(&directory, key).unwrap().is_empty());
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn test_rotated_mesh_is_voxelized_along_the_body_axes() {
+    let mesh = hull_mesh();
+    let transform = Transform::from_rotation(Quat::from_rotation_y(std::f32::consts::FRAC_PI_2));
+
+    let mut voxels = generate_voxel_grid(&mesh, &transform, 2.0);
+    calculate_voxel_solidity(&mesh, &transform, &mut voxels);
+
+    assert_eq!(voxels.len(), 2 * 2 * 4);
+    assert!(voxels.iter().all(|voxel| voxel.is_solid));
+    assert!(voxels.iter().all(|voxel| voxel.position.x.abs() < 2.0));
+    assert!(voxels.iter().any(|voxel| voxel.position.z.abs() > 2.0));
+}
+
+#[test]
+fn test_scaled_mesh_is_voxelized_at_its_scaled_size() {
+    let mesh = Mesh::from(Cuboid::new(4.0, 2.0, 2.0));
+    let transform = Transform::from_scale(Vec3::splat(2.0));
+
+    let mut voxels = generate_voxel_grid(&mesh, &transform, 2.0);
+    calculate_voxel_solidity(&mesh, &transform, &mut voxels);
+
+    assert_eq!(voxels.len(), 4 * 2 * 2);
+    assert!(voxels.iter().all(|voxel| voxel.is_solid));
+}
+
+#[test]
+fn test_voxel_cache_key_is_stable_and_covers_the_full_transform() {
+    let mesh = hull_mesh();
+    let key = calculate_voxel_cache_key(&mesh, &Transform::default(), 2.0);
+
+    assert_eq!(
+        key,
+        calculate_voxel_cache_key(&mesh, &Transform::default(), 2.0)
+    );
+    // Cache files on disk are named by this key, so it must not change between toolchains.
+    assert_eq!(format!("{:016x}", key), "36134470325814d5");
+    assert_ne!(
+        key,
+        calculate_voxel_cache_key(
+            &mesh,
+            &Transform::from_rotation(Quat::from_rotation_y(0.5)),
+            2.0
+        )
+    );
+    assert_ne!(
+        key,
+        calculate_voxel_cache_key(&mesh, &Transform::from_scale(Vec3::splat(1.5)), 2.0)
+    );
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use empire_of_wind::crew_management::{draw_up_duty_roster, CrewRole, WATCH_BILL};
+use empire_of_wind::crew_watches::{assign_watches, Watch, WatchSchedule};
+
+#[test]
+fn test_watches_take_turns_on_deck() {
+    let schedule = WatchSchedule::new(4.0);
+
+    assert_eq!(schedule.watch_at(0.0), Watch::Starboard);
+    assert_eq!(schedule.watch_at(3.9), Watch::Starboard);
+    assert_eq!(schedule.watch_at(4.0), Watch::Larboard);
+    assert_eq!(schedule.watch_at(8.5), Watch::Starboard);
+    assert_eq!(schedule.watch_at(23.0), Watch::Larboard);
+}
+
+#[test]
+fn test_crew_are_split_evenly_between_watches() {
+    let crew: Vec<Watch> = assign_watches(&WATCH_BILL);
+    let starboard = crew
+        .iter()
+        .filter(|watch| **watch == Watch::Starboard)
+        .count();
+
+    assert_eq!(starboard, 6);
+    assert_eq!(crew.len() - starboard, 3);
+
+    // Each watch has its own hand of every role the watch bill has two of
+    for role in [CrewRole::Helmsman, CrewRole::Gunner, CrewRole::SailHandler] {
+        let watches: Vec<Watch> = WATCH_BILL
+            .iter()
+            .zip(crew.iter())
+            .filter(|(crew_role, _)| **crew_role == role)
+            .map(|(_, watch)| *watch)
+            .collect();
+        assert_eq!(watches, vec![Watch::Starboard, Watch::Larboard]);
+    }
+}
+
+#[test]
+fn test_helm_is_manned_in_both_watches() {
+    let helm = Entity::from_raw(1);
+    let crew_members: Vec<Entity> = (0..WATCH_BILL.len() as u32)
+        .map(|index| Entity::from_raw(10 + index))
+        .collect();
+    let watches = assign_watches(&WATCH_BILL);
+
+    let mut schedule = WatchSchedule::new(4.0);
+    let mut helmsman = None;
+
+    for on_duty in [Watch::Starboard, Watch::Larboard, Watch::Starboard] {
+        schedule.on_duty = on_duty;
+        let crew: Vec<(Entity, CrewRole, bool)> = crew_members
+            .iter()
+            .zip(WATCH_BILL.iter().zip(watches.iter()))
+            .map(|(crew_member, (role, watch))| {
+                (*crew_member, *role, schedule.is_on_duty(Some(watch)))
+            })
+            .collect();
+
+        // The helmsman of the watch coming on deck takes over the helm at the change of watch
+        let roster = draw_up_duty_roster(&[(helm, CrewRole::Helmsman, helmsman)], &crew);
+        let (_, new_helmsman) = roster[0];
+        let (_, role, is_on_duty) = crew
+            .iter()
+            .find(|(crew_member, _, _)| *crew_member == new_helmsman)
+            .unwrap();
+        assert_eq!(*role, CrewRole::Helmsman);
+        assert!(is_on_duty);
+        assert_ne!(helmsman, Some(new_helmsman));
+
+        helmsman = Some(new_helmsman);
+    }
+}
+
+#[test]
+fn test_watch_below_feels_its_needs_more() {
+    let schedule = WatchSchedule::new(4.0);
+    let on_duty = schedule.on_duty;
+    let below = Watch::nth(1);
+
+    assert!(schedule.is_on_duty(Some(&on_duty)));
+    assert!(!schedule.is_on_duty(Some(&below)));
+    assert!(schedule.need_bias(Some(&below)) > schedule.need_bias(Some(&on_duty)));
+
+    // Crew who stand no watch are always on duty, with their needs left as they are
+    assert!(schedule.is_on_duty(None));
+    assert_eq!(schedule.need_bias(None), 1.0);
+}